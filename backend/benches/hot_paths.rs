@@ -0,0 +1,192 @@
+//! Hot-path micro benchmarks.
+//!
+//! Run with:
+//!     cargo bench --features bench
+//!
+//! A single benchmark can be selected by passing a substring of its name:
+//!     cargo bench --features bench -- movegen
+//!
+//! Every benchmark runs `WARMUP_ITERS` untimed iterations followed by
+//! `SAMPLE_ITERS` timed samples and reports the mean, median and fastest sample.
+//! The full search is too slow for that and takes `SEARCH_WARMUP_ITERS` and
+//! `SEARCH_SAMPLE_ITERS` instead.
+
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use vantage::board::Board;
+use vantage::moves::execute::{
+    generate_legal, generate_legal_make_undo, make_move_basic, undo_move_basic,
+};
+use vantage::moves::magic::MagicTables;
+use vantage::moves::magic::loader::load_magic_tables;
+use vantage::moves::movegen::generate_pseudo_legal;
+use vantage::moves::types::Move;
+use vantage::search::context::SearchContext;
+use vantage::search::eval::{evaluate_pawn_structure, pesto_eval, static_eval};
+use vantage::search::search::{TimeManager, quiescence, search};
+use vantage::search::tt::TranspositionTable;
+use vantage::status::position_status;
+
+const WARMUP_ITERS: usize = 100;
+const SAMPLE_ITERS: usize = 1000;
+const SEARCH_WARMUP_ITERS: usize = 1;
+const SEARCH_SAMPLE_ITERS: usize = 10;
+
+const STARTPOS: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+// "Kiwipete": castling, pins, en passant and promotions all in one position
+const TACTICAL: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+const ENDGAME: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+const STALEMATE: &str = "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1";
+
+fn bench<F: FnMut()>(name: &str, filter: Option<&str>, f: F) {
+    bench_iters(name, filter, WARMUP_ITERS, SAMPLE_ITERS, f);
+}
+
+fn bench_iters<F: FnMut()>(
+    name: &str,
+    filter: Option<&str>,
+    warmup_iters: usize,
+    sample_iters: usize,
+    mut f: F,
+) {
+    if filter.is_some_and(|pat| !name.contains(pat)) {
+        return;
+    }
+
+    for _ in 0..warmup_iters {
+        f();
+    }
+
+    let mut samples: Vec<Duration> = Vec::with_capacity(sample_iters);
+    for _ in 0..sample_iters {
+        let start = Instant::now();
+        f();
+        samples.push(start.elapsed());
+    }
+    samples.sort_unstable();
+
+    let total: Duration = samples.iter().sum();
+    let mean = total / sample_iters as u32;
+    let median = samples[sample_iters / 2];
+    let fastest = samples[0];
+
+    println!(
+        "{:<40} mean {:>12?}  median {:>12?}  min {:>12?}",
+        name, mean, median, fastest
+    );
+}
+
+fn board(fen: &str) -> Board {
+    Board::from_str(fen).expect("benchmark FEN must be valid")
+}
+
+fn first_legal(board: &mut Board, tables: &MagicTables) -> Move {
+    let mut moves = Vec::new();
+    let mut scratch = Vec::new();
+    generate_legal(board, tables, &mut moves, &mut scratch);
+    moves[0]
+}
+
+fn main() {
+    // `cargo bench` passes `--bench`; anything else is treated as a name filter
+    let filter = std::env::args().skip(1).find(|a| !a.starts_with("--"));
+    let filter = filter.as_deref();
+
+    let tables = load_magic_tables();
+
+    bench("board_new", filter, || {
+        black_box(Board::new());
+    });
+
+    for (label, fen) in [("startpos", STARTPOS), ("tactical", TACTICAL)] {
+        let b = board(fen);
+        let mut moves = Vec::with_capacity(256);
+        bench(&format!("movegen_pseudo_legal/{label}"), filter, || {
+            generate_pseudo_legal(black_box(&b), &tables, &mut moves);
+            black_box(moves.len());
+        });
+
+        let mut b = board(fen);
+        let mut moves = Vec::with_capacity(256);
+        let mut scratch = Vec::with_capacity(256);
+        bench(&format!("movegen_legal/{label}"), filter, || {
+            generate_legal(&mut b, &tables, &mut moves, &mut scratch);
+            black_box(moves.len());
+        });
+        bench(&format!("movegen_legal_make_undo/{label}"), filter, || {
+            generate_legal_make_undo(&mut b, &tables, &mut moves, &mut scratch);
+            black_box(moves.len());
+        });
+
+        let mut b = board(fen);
+        let mv = first_legal(&mut b, &tables);
+        bench(&format!("make_undo/{label}"), filter, || {
+            let undo = make_move_basic(&mut b, black_box(mv));
+            undo_move_basic(&mut b, undo);
+        });
+    }
+
+    for (label, fen) in [
+        ("startpos", STARTPOS),
+        ("tactical", TACTICAL),
+        ("endgame", ENDGAME),
+    ] {
+        let b = board(fen);
+        bench(&format!("static_eval/{label}"), filter, || {
+            black_box(static_eval(black_box(&b), &tables, -32000, 32000));
+        });
+        bench(&format!("pesto_eval/{label}"), filter, || {
+            black_box(pesto_eval(black_box(&b)));
+        });
+        bench(&format!("evaluate_fast/{label}"), filter, || {
+            black_box(black_box(&b).evaluate_fast());
+        });
+        bench(&format!("pawn_structure/{label}"), filter, || {
+            black_box(evaluate_pawn_structure(black_box(&b)));
+        });
+    }
+
+    for (label, fen) in [("startpos", STARTPOS), ("stalemate", STALEMATE)] {
+        let mut b = board(fen);
+        bench(&format!("legal_move_exists/{label}"), filter, || {
+            black_box(b.legal_move_exists(&tables));
+        });
+        bench(&format!("has_legal_moves/{label}"), filter, || {
+            black_box(b.has_legal_moves(&tables));
+        });
+        bench(&format!("position_status/{label}"), filter, || {
+            black_box(position_status(&mut b, &tables));
+        });
+    }
+
+    // Quiescence probes the TT first, so the table has to be emptied between
+    // samples; a 1MB table keeps the cost of `clear()` small next to the search.
+    let mut b = board(TACTICAL);
+    let mut ctx = SearchContext::new();
+    let mut tt = TranspositionTable::new(1);
+    bench("quiescence/tactical", filter, || {
+        tt.clear();
+        let mut nodes = 0;
+        let mut time = TimeManager::new(None);
+        black_box(quiescence(
+            &mut b, &tables, &mut ctx, &mut tt, 0, -32000, 32000, &mut nodes, &mut time,
+        ));
+    });
+
+    // A full search is far slower than the others, so keep the table small and
+    // clear it on every sample to measure a cold search.
+    let mut tt = TranspositionTable::new(16);
+    bench_iters(
+        "search_depth_6/startpos",
+        filter,
+        SEARCH_WARMUP_ITERS,
+        SEARCH_SAMPLE_ITERS,
+        || {
+            let mut b = board(STARTPOS);
+            tt.clear();
+            black_box(search(&mut b, &tables, &mut tt, 6, None));
+        },
+    );
+}
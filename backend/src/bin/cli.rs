@@ -1,22 +1,35 @@
+use std::collections::VecDeque;
 use std::fs::File;
 use std::io::{self, BufRead, BufReader};
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
 use std::time::Duration;
 use vantage::board::{Board, Color, Piece};
 use vantage::book::PolyglotBook;
-use vantage::moves::execute::{generate_legal, make_move_basic};
+use vantage::logger::StdoutLogger;
+use vantage::moves::execute::{MAX_PLY, generate_legal};
 use vantage::moves::magic::MagicTables;
 use vantage::moves::magic::loader::load_magic_tables;
 use vantage::moves::types::Move;
-use vantage::search::search::search;
+use vantage::search::SearchResult;
+use vantage::search::contempt::DEFAULT_CONTEMPT;
+use vantage::search::context::SearchContext;
+use vantage::search::search::{TimeManager, search, search_with_context};
 use vantage::search::tt::TranspositionTable;
+use vantage::search::tuning::TuningParams;
+use vantage::uci::xboard::XBoardEngine;
 
 fn main() {
     // Load magic tables once at startup
     let magic_tables = load_magic_tables();
 
     let mut board = Board::new(); // Start position
-    let mut tt = TranspositionTable::new(512);
+    let mut tt = TranspositionTable::new(DEFAULT_HASH_MB);
+    let mut ctx = SearchContext::with_params(tune_params_from_args());
+    ctx.logger = Box::new(StdoutLogger);
 
     let book = PolyglotBook::load("book.bin").ok();
     if book.is_some() {
@@ -26,13 +39,9 @@ fn main() {
     }
 
     // Main UCI loop
-    let stdin = io::stdin();
-    for line in stdin.lock().lines() {
-        let line = match line {
-            Ok(l) => l.trim().to_string(),
-            Err(_) => break,
-        };
-
+    let mut input = UciInput::from_stdin();
+    while let Some(line) = input.next_line() {
+        let line = line.trim().to_string();
         if line.is_empty() {
             continue;
         }
@@ -43,9 +52,11 @@ fn main() {
         match command {
             "uci" => handle_uci(),
             "isready" => println!("readyok"),
+            "setoption" => handle_setoption(&parts, &mut tt, &mut ctx),
             "ucinewgame" => {
                 board = Board::new();
                 tt.clear();
+                ctx.reset_for_new_game();
             }
             "position" => {
                 if let Some(new_board) = handle_position(&parts, &magic_tables) {
@@ -53,12 +64,35 @@ fn main() {
                 }
             }
             "go" => {
-                handle_go(&parts, &mut board, &magic_tables, &book, &mut tt);
+                let quit = handle_go(
+                    &parts,
+                    &mut board,
+                    &magic_tables,
+                    &book,
+                    &mut tt,
+                    &mut ctx,
+                    &mut input,
+                );
+                if quit {
+                    break;
+                }
             }
             "fen" => {
                 println!("{}", board.to_fen());
             }
             "quit" => break,
+            "xboard" => {
+                // The GUI speaks XBoard, not UCI: hand the rest of stdin over
+                let mut xboard = XBoardEngine::new(magic_tables);
+                let mut stdout = io::stdout();
+                while let Some(line) = input.next_line() {
+                    match xboard.handle_command(&line, &mut stdout) {
+                        Ok(true) => {}
+                        Ok(false) | Err(_) => break,
+                    }
+                }
+                return;
+            }
             "d" | "display" => {
                 println!("{}", board);
             }
@@ -70,95 +104,178 @@ fn main() {
     }
 }
 
-fn handle_uci() {
-    println!("id name Vantage 1.0");
-    println!("id author Vaishak Menon");
-    println!("uciok");
+/// Parameters from `--tune-params file.spsa`, or the defaults. A bad file is
+/// fatal: silently playing with default values would skew a tuning run.
+fn tune_params_from_args() -> TuningParams {
+    let args: Vec<String> = std::env::args().collect();
+    let Some(pos) = args.iter().position(|a| a == "--tune-params") else {
+        return TuningParams::default();
+    };
+    let Some(path) = args.get(pos + 1) else {
+        eprintln!("--tune-params needs a file argument");
+        std::process::exit(2);
+    };
+    match TuningParams::from_spsa_file(path) {
+        Ok(params) => {
+            println!("info string Loaded tuning parameters from {}", path);
+            params
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    }
 }
 
-fn handle_position(parts: &[&str], tables: &MagicTables) -> Option<Board> {
-    let mut board = if parts.len() > 1 && parts[1] == "startpos" {
-        Board::new()
-    } else if parts.len() > 1 && parts[1] == "fen" {
-        let fen_start = 2;
-        let mut fen_end = parts.len();
-        for (i, &part) in parts.iter().enumerate().skip(fen_start) {
-            if part == "moves" {
-                fen_end = i;
-                break;
-            }
-        }
+const DEFAULT_HASH_MB: usize = 512;
+const MAX_CONTEMPT: i32 = 200;
+const MAX_RATING_ADV: i32 = 4000;
+const MAX_HASH_MB: usize = 4096;
 
-        let fen_string = parts[fen_start..fen_end].join(" ");
-        Board::from_str(&fen_string).ok()?
-    } else {
-        Board::new()
-    };
+enum UciEvent {
+    Line(String),
+    Eof,
+    SearchDone,
+}
 
-    // Apply moves if any
-    if let Some(moves_idx) = parts.iter().position(|&p| p == "moves") {
-        for move_str in &parts[moves_idx + 1..] {
-            if let Some(mv) = parse_uci_move(&board, move_str, tables) {
-                make_move_basic(&mut board, mv);
-            } else {
-                eprintln!("Invalid move: {}", move_str);
-                return None;
+/// Commands from stdin, read on a background thread so `stop` and `isready`
+/// are still answered while a search is running.
+struct UciInput {
+    tx: Sender<UciEvent>,
+    rx: Receiver<UciEvent>,
+    /// Commands that arrived during a search, replayed once it finishes.
+    pending: VecDeque<String>,
+}
+
+impl UciInput {
+    fn from_stdin() -> Self {
+        let (tx, rx) = mpsc::channel();
+        let reader_tx = tx.clone();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if reader_tx.send(UciEvent::Line(line)).is_err() {
+                    return;
+                }
             }
+            let _ = reader_tx.send(UciEvent::Eof);
+        });
+
+        Self {
+            tx,
+            rx,
+            pending: VecDeque::new(),
         }
     }
 
-    Some(board)
-}
-
-fn parse_uci_move(board: &Board, move_str: &str, tables: &MagicTables) -> Option<Move> {
-    if move_str.len() < 4 {
-        return None;
+    /// Next command to process, or None once stdin is closed.
+    fn next_line(&mut self) -> Option<String> {
+        if let Some(line) = self.pending.pop_front() {
+            return Some(line);
+        }
+        loop {
+            match self.rx.recv() {
+                Ok(UciEvent::Line(line)) => return Some(line),
+                Ok(UciEvent::SearchDone) => continue,
+                Ok(UciEvent::Eof) | Err(_) => return None,
+            }
+        }
     }
+}
 
-    let chars: Vec<char> = move_str.chars().collect();
+fn handle_uci() {
+    println!("id name Vantage 1.0");
+    println!("id author Vaishak Menon");
+    println!(
+        "option name Hash type spin default {} min 1 max {}",
+        DEFAULT_HASH_MB, MAX_HASH_MB
+    );
+    println!(
+        "option name Contempt type spin default {} min 0 max {}",
+        DEFAULT_CONTEMPT, MAX_CONTEMPT
+    );
+    // Ratings only decide whether contempt applies, see `ContemptConfig`
+    println!("option name UCI_Opponent type string default none");
+    println!(
+        "option name UCI_RatingAdv type spin default 0 min -{} max {}",
+        MAX_RATING_ADV, MAX_RATING_ADV
+    );
+    println!("uciok");
+}
 
-    let from_file = (chars[0] as u8).wrapping_sub(b'a');
-    let from_rank = (chars[1] as u8).wrapping_sub(b'1');
-    let to_file = (chars[2] as u8).wrapping_sub(b'a');
-    let to_rank = (chars[3] as u8).wrapping_sub(b'1');
+// Parses "setoption name <id> [value <x>]". Search is synchronous, so no search
+// can be running while this is handled.
+fn handle_setoption(parts: &[&str], tt: &mut TranspositionTable, ctx: &mut SearchContext) {
+    let name_idx = parts.iter().position(|&p| p == "name");
+    let value_idx = parts.iter().position(|&p| p == "value");
 
-    if from_file > 7 || from_rank > 7 || to_file > 7 || to_rank > 7 {
-        return None;
+    let (Some(n), Some(v)) = (name_idx, value_idx) else {
+        return;
+    };
+    if n >= v {
+        return;
     }
+    let name = parts[n + 1..v].join(" ");
+    let value = parts[v + 1..].join(" ");
+
+    if name.eq_ignore_ascii_case("Hash") {
+        match value.parse::<usize>() {
+            Ok(mb) => {
+                let mb = mb.clamp(1, MAX_HASH_MB);
+                tt.resize(mb);
+                println!(
+                    "info string Hash set to {} MB ({} entries)",
+                    mb,
+                    tt.capacity_entries()
+                );
+            }
+            Err(_) => println!("info string Invalid Hash value: {}", value),
+        }
+    } else if name.eq_ignore_ascii_case("Contempt") {
+        match value.parse::<i32>() {
+            Ok(cp) => ctx.contempt.contempt = cp.clamp(0, MAX_CONTEMPT),
+            Err(_) => println!("info string Invalid Contempt value: {}", value),
+        }
+    } else if name.eq_ignore_ascii_case("UCI_Opponent") {
+        ctx.contempt.set_uci_opponent(&value);
+    } else if name.eq_ignore_ascii_case("UCI_Elo") {
+        match value.parse::<u32>() {
+            Ok(elo) => ctx.contempt.our_elo = Some(elo),
+            Err(_) => println!("info string Invalid UCI_Elo value: {}", value),
+        }
+    } else if name.eq_ignore_ascii_case("UCI_RatingAdv") {
+        match value.parse::<i32>() {
+            Ok(adv) => {
+                ctx.contempt.rating_advantage = Some(adv.clamp(-MAX_RATING_ADV, MAX_RATING_ADV))
+            }
+            Err(_) => println!("info string Invalid UCI_RatingAdv value: {}", value),
+        }
+    }
+}
 
-    let from_square = (from_rank * 8 + from_file) as usize;
-    let to_square = (to_rank * 8 + to_file) as usize;
+fn handle_position(parts: &[&str], tables: &MagicTables) -> Option<Board> {
+    // The FEN runs up to the `moves` token, even when no moves follow it
+    let moves_token = parts.iter().position(|&p| p == "moves");
+    let moves: &[&str] = match moves_token {
+        Some(idx) => &parts[idx + 1..],
+        None => &[],
+    };
 
-    let promo_piece = if move_str.len() >= 5 {
-        match chars[4] {
-            'q' => Some(vantage::board::Piece::Queen),
-            'r' => Some(vantage::board::Piece::Rook),
-            'b' => Some(vantage::board::Piece::Bishop),
-            'n' => Some(vantage::board::Piece::Knight),
-            _ => None,
-        }
+    let result = if parts.len() > 1 && parts[1] == "fen" {
+        let fen_end = moves_token.unwrap_or(parts.len());
+        let fen_string = parts[2..fen_end].join(" ");
+        Board::from_fen_and_moves(&fen_string, moves, tables)
     } else {
-        None
+        Board::from_moves(moves, tables)
     };
 
-    let mut moves = Vec::with_capacity(256);
-    let mut scratch = Vec::with_capacity(256);
-    let mut board_copy = board.clone();
-    generate_legal(&mut board_copy, tables, &mut moves, &mut scratch);
-
-    for mv in moves {
-        // FIXED: Cast index() to usize for comparison
-        if (mv.from.index() as usize) == from_square && (mv.to.index() as usize) == to_square {
-            if promo_piece.is_some() {
-                if mv.promotion == promo_piece {
-                    return Some(mv);
-                }
-            } else if mv.promotion.is_none() {
-                return Some(mv);
-            }
+    match result {
+        Ok(board) => Some(board),
+        Err(e) => {
+            eprintln!("{}", e);
+            None
         }
     }
-    None
 }
 
 fn handle_go(
@@ -167,7 +284,9 @@ fn handle_go(
     tables: &MagicTables,
     book: &Option<PolyglotBook>,
     tt: &mut TranspositionTable,
-) {
+    ctx: &mut SearchContext,
+    input: &mut UciInput,
+) -> bool {
     // --- STEP A: Check Opening Book First ---
     // If we have a book, and the board position is in it, play immediately.
     if let Some(b) = book
@@ -175,19 +294,22 @@ fn handle_go(
     {
         println!("info string Book move found");
         println!("bestmove {}", book_move.to_uci());
-        return; // EXIT IMMEDIATELY - Do not search!
+        return false; // EXIT IMMEDIATELY - Do not search!
     }
     // ----------------------------------------
     let mut depth = 64;
     let mut time_limit = None;
+    let mut clock: Option<TimeManager> = None;
+    let mut infinite = false;
 
     // Time Control Variables
     let mut wtime: Option<u64> = None;
     let mut btime: Option<u64> = None;
     let mut winc: u64 = 0;
     let mut binc: u64 = 0;
-    let mut movestogo: Option<u64> = None;
+    let mut movestogo: Option<u32> = None;
     let mut movetime: Option<u64> = None;
+    let mut nodes_limit: Option<u64> = None;
 
     let mut i = 1;
     while i < parts.len() {
@@ -228,6 +350,12 @@ fn handle_go(
                 }
                 i += 2;
             }
+            "nodes" => {
+                if i + 1 < parts.len() {
+                    nodes_limit = parts[i + 1].parse().ok();
+                }
+                i += 2;
+            }
             "movestogo" => {
                 if i + 1 < parts.len() {
                     movestogo = parts[i + 1].parse().ok();
@@ -235,8 +363,7 @@ fn handle_go(
                 i += 2;
             }
             "infinite" => {
-                depth = 100;
-                time_limit = None;
+                infinite = true;
                 i += 1;
             }
             _ => {
@@ -245,7 +372,9 @@ fn handle_go(
         }
     }
 
-    if let Some(ms) = movetime {
+    if infinite {
+        depth = MAX_PLY as i32;
+    } else if let Some(ms) = movetime {
         time_limit = Some(Duration::from_millis(ms));
     } else {
         let (my_time, my_inc) = if board.side_to_move == Color::White {
@@ -261,57 +390,75 @@ fn handle_go(
             let safety_buffer = (t * 15 / 100).min(500);
             let usable_time = t.saturating_sub(safety_buffer);
 
-            let mut alloc: u64;
+            clock = Some(TimeManager::from_game_clock(
+                Duration::from_millis(usable_time),
+                Duration::from_millis(my_inc),
+                movestogo,
+            ));
+        }
+    }
 
-            if let Some(mtg) = movestogo {
-                let moves_to_plan = mtg.max(2);
-                alloc = usable_time / moves_to_plan;
-                alloc += (my_inc * 3) / 4;
-            } else {
-                // VERY CONSERVATIVE tiered allocation
-                // Assume ~40 moves remaining in a typical game
-                if usable_time > 5000 {
-                    // Normal game: 1/40th of time + most of increment
-                    alloc = usable_time / 40 + (my_inc * 9) / 10;
-                } else if usable_time > 2000 {
-                    // Low time: 1/30th
-                    alloc = usable_time / 30 + (my_inc * 3) / 4;
-                } else if usable_time > 500 {
-                    // Very low: 1/20th
-                    alloc = usable_time / 20 + my_inc / 2;
-                } else {
-                    // CRITICAL: Just use increment + tiny bit
-                    alloc = my_inc / 2 + 20;
+    let time = clock.unwrap_or_else(|| TimeManager::new(time_limit));
+    if let (Some(soft), Some(hard)) = (time.soft_time(), time.allocated_time()) {
+        println!(
+            "info string Target time: {}ms (max {}ms)",
+            soft.as_millis(),
+            hard.as_millis()
+        );
+    }
+    ctx.reset_for_new_search();
+    let stop = Arc::new(AtomicBool::new(false));
+    let time = time
+        .with_nodes_limit(nodes_limit)
+        .with_stop_flag(stop.clone());
+
+    // Search on a worker thread while this one keeps listening for `stop`.
+    // `go infinite` must not report a move before `stop`, even if the search
+    // ends on its own (e.g. it found a mate).
+    let (result, quit) = thread::scope(|scope| {
+        let done = input.tx.clone();
+        let worker = scope.spawn(move || {
+            let result = search_with_context(board, tables, tt, ctx, depth, time);
+            let _ = done.send(UciEvent::SearchDone);
+            result
+        });
+
+        let mut finished = false;
+        let mut stopped = false;
+        let mut quit = false;
+        while !finished || (infinite && !stopped) {
+            let event = input.rx.recv().unwrap_or(UciEvent::Eof);
+            match event {
+                UciEvent::SearchDone => finished = true,
+                UciEvent::Line(line) => match line.trim() {
+                    "stop" => stopped = true,
+                    "quit" => {
+                        stopped = true;
+                        quit = true;
+                    }
+                    "isready" => println!("readyok"),
+                    _ => input.pending.push_back(line),
+                },
+                UciEvent::Eof => {
+                    stopped = true;
+                    quit = true;
                 }
             }
-
-            // HARD CAP: Never use more than 20% of remaining time on one move
-            // This prevents catastrophic time loss
-            let hard_cap = usable_time / 5;
-            alloc = alloc.min(hard_cap);
-
-            // Also cap at usable time
-            alloc = alloc.min(usable_time);
-
-            // Minimum: Ensure we at least calculate for a tiny bit (10ms)
-            if alloc < 10 && usable_time >= 10 {
-                alloc = 10;
+            if stopped {
+                stop.store(true, Ordering::Relaxed);
             }
-
-            time_limit = Some(Duration::from_millis(alloc));
         }
-    }
 
-    if let Some(limit) = time_limit {
-        println!("info string Target time: {}ms", limit.as_millis());
-    }
-    let (_score, best_move) = search(board, tables, tt, depth, time_limit);
+        // Each completed iteration already sent its info line through ctx.logger
+        (worker.join().expect("search thread panicked"), quit)
+    });
 
-    if let Some(m) = best_move {
+    if let Some(m) = result.best_move {
         println!("bestmove {}", m.to_uci());
     } else {
         println!("bestmove 0000");
     }
+    quit
 }
 
 // --- EPD Test Runner ---
@@ -364,7 +511,8 @@ fn run_epd_tests(path: &str, tables: &MagicTables) {
             let depth = 64;
             let mut tt = TranspositionTable::new(512);
 
-            let (_score, best_move) = search(&mut board, tables, &mut tt, depth, time_limit);
+            let SearchResult { best_move, .. } =
+                search(&mut board, tables, &mut tt, depth, time_limit);
 
             let result_str = match best_move {
                 Some(m) => m.to_uci(),
@@ -0,0 +1,64 @@
+//! Perft divide tool for comparing move generation against reference engines.
+//!
+//! Usage:
+//!     vantage-perft "<fen>" <depth>
+//!
+//! Prints the node count below every root move (sorted by UCI string), the
+//! total, and the elapsed time, in the same `move: count` format as Stockfish's
+//! `go perft`.
+
+use std::process;
+use std::str::FromStr;
+use std::time::Instant;
+use vantage::board::Board;
+use vantage::moves::magic::loader::load_magic_tables;
+use vantage::moves::perft::{MAX_PERFT_DEPTH, perft_divide_counts};
+
+fn usage() -> ! {
+    eprintln!("Usage: vantage-perft \"<fen>\" <depth>");
+    process::exit(1);
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() != 2 {
+        usage();
+    }
+
+    let mut board = match Board::from_str(&args[0]) {
+        Ok(board) => board,
+        Err(e) => {
+            eprintln!("Invalid FEN '{}': {}", args[0], e);
+            process::exit(1);
+        }
+    };
+    let depth: u32 = match args[1].parse() {
+        Ok(d) if (1..=MAX_PERFT_DEPTH as u32).contains(&d) => d,
+        _ => {
+            eprintln!(
+                "Invalid depth '{}': expected an integer from 1 to {}",
+                args[1], MAX_PERFT_DEPTH
+            );
+            process::exit(1);
+        }
+    };
+
+    let tables = load_magic_tables();
+
+    let start = Instant::now();
+    let mut counts: Vec<(String, u64)> = perft_divide_counts(&mut board, &tables, depth)
+        .into_iter()
+        .map(|(mv, count)| (mv.to_uci(), count))
+        .collect();
+    let elapsed = start.elapsed();
+    counts.sort();
+
+    let mut total = 0;
+    for (uci, count) in &counts {
+        println!("{}: {}", uci, count);
+        total += count;
+    }
+    println!();
+    println!("Total: {}", total);
+    println!("Time: {:.3}s", elapsed.as_secs_f64());
+}
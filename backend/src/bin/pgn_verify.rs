@@ -0,0 +1,39 @@
+//! PGN verification tool for checking the SAN parser and move executor
+//! against real game databases.
+//!
+//! Usage:
+//!     vantage-pgn-verify <file.pgn>
+//!
+//! Replays every game and prints one line per move that fails to parse or
+//! play. Exits with status 1 if any error was found.
+
+use std::process;
+use vantage::moves::magic::loader::load_magic_tables;
+use vantage::utils::pgn_verify::verify_pgn_file;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() != 1 {
+        eprintln!("Usage: vantage-pgn-verify <file.pgn>");
+        process::exit(1);
+    }
+
+    let tables = load_magic_tables();
+    let errors = verify_pgn_file(&args[0], &tables);
+
+    for e in &errors {
+        println!(
+            "game {} move {} '{}': {}",
+            e.game_index + 1,
+            e.move_number,
+            e.san,
+            e.error
+        );
+    }
+    if errors.is_empty() {
+        println!("No errors");
+    } else {
+        println!("{} error(s)", errors.len());
+        process::exit(1);
+    }
+}
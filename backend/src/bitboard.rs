@@ -1,5 +1,9 @@
+use crate::square::Square;
+
 pub trait BitboardExt {
     fn lsb(self) -> u8;
+    /// Print the bitboard as an 8x8 grid (see `to_bitboard_string`).
+    fn print(&self);
 }
 
 impl BitboardExt for u64 {
@@ -8,4 +12,52 @@ impl BitboardExt for u64 {
         debug_assert!(self != 0, "Called lsb() on empty bitboard");
         self.trailing_zeros() as u8
     }
+
+    fn print(&self) {
+        println!("{}", to_bitboard_string(*self));
+    }
+}
+
+/// Render a bitboard as an 8x8 grid of `1` and `.`, rank 8 at the top and
+/// the a-file on the left.
+pub fn to_bitboard_string(bb: u64) -> String {
+    let mut s = String::with_capacity(72);
+    for rank in (0..8).rev() {
+        for file in 0..8 {
+            let idx = rank * 8 + file;
+            s.push(if (bb >> idx) & 1 == 1 { '1' } else { '.' });
+        }
+        s.push('\n');
+    }
+    s
+}
+
+/// Bitboard with exactly the given squares set.
+pub fn bb_from_squares(squares: &[Square]) -> u64 {
+    squares.iter().fold(0, |bb, sq| bb | 1u64 << sq.index())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bb_from_squares() {
+        assert_eq!(bb_from_squares(&[Square::from_index(0)]), 1u64);
+        assert_eq!(
+            bb_from_squares(&[Square::from_index(0), Square::from_index(63)]),
+            1 | 1 << 63
+        );
+        assert_eq!(bb_from_squares(&[]), 0);
+    }
+
+    #[test]
+    fn test_to_bitboard_string_a1_is_bottom_left() {
+        let s = to_bitboard_string(1);
+        let rows: Vec<&str> = s.lines().collect();
+        assert_eq!(rows.len(), 8);
+        assert_eq!(rows[7], "1.......");
+        assert!(rows[..7].iter().all(|row| *row == "........"));
+        assert_eq!(to_bitboard_string(1 << 63).lines().next(), Some(".......1"));
+    }
 }
@@ -0,0 +1,63 @@
+//! Incrementally maintained PeSTO terms (`incremental_eval` feature).
+//!
+//! The accumulator is updated from `Board::set_bb`, so every piece placement
+//! or removal, including make/undo, keeps it in sync with the bitboards.
+
+use super::{Board, Color, Piece};
+use crate::search::eval::{pesto_piece_value, piece_phase, taper};
+
+/// Running middlegame/endgame sums of material + PST, from White's view.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EvalAccumulator {
+    pub mg_score: i32,
+    pub eg_score: i32,
+    /// Unclamped phase material (knights/bishops 1, rooks 2, queens 4).
+    pub phase: i32,
+}
+
+impl EvalAccumulator {
+    #[inline(always)]
+    pub(crate) fn add(&mut self, color: Color, piece: Piece, sq: u8) {
+        let (mg, eg) = pesto_piece_value(color, piece, sq);
+        self.mg_score += mg;
+        self.eg_score += eg;
+        self.phase += piece_phase(piece);
+    }
+
+    #[inline(always)]
+    pub(crate) fn remove(&mut self, color: Color, piece: Piece, sq: u8) {
+        let (mg, eg) = pesto_piece_value(color, piece, sq);
+        self.mg_score -= mg;
+        self.eg_score -= eg;
+        self.phase -= piece_phase(piece);
+    }
+
+    /// Tapered score, White's point of view.
+    #[inline(always)]
+    pub fn score(&self) -> i32 {
+        taper(self.mg_score, self.eg_score, self.phase)
+    }
+}
+
+impl Board {
+    /// Tapered PeSTO score from the accumulator (White's point of view).
+    /// Equal to `pesto_eval(self)` without scanning the bitboards.
+    #[inline(always)]
+    pub fn incremental_eval(&self) -> i32 {
+        self.eval_accumulator.score()
+    }
+
+    /// Debug check that the accumulator matches a from-scratch `pesto_eval`.
+    #[cfg(debug_assertions)]
+    pub fn assert_eval(&self) {
+        let full = crate::search::eval::pesto_eval(self);
+        assert_eq!(
+            self.incremental_eval(),
+            full,
+            "Eval accumulator mismatch: incremental={}, full={} ({:?})",
+            self.incremental_eval(),
+            full,
+            self.eval_accumulator
+        );
+    }
+}
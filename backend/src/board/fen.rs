@@ -1,7 +1,15 @@
 use super::castle_bits::*;
-use super::fen_tables::{CHAR_TO_PC, PC_TO_CHAR};
-use super::{Board, Color, Piece};
+use super::{ALL_PIECES, BACK_RANKS_MASK, Board, Color, Piece};
+use crate::error::VantageError;
+use crate::moves::execute::{generate_legal, make_move_basic};
+use crate::moves::king::KING_ATTACKS;
+use crate::moves::knight::KNIGHT_ATTACKS;
+use crate::moves::magic::{MagicTables, bishop_attacks_per_square, rook_attacks_per_square};
+use crate::moves::pawn::{BLACK_PAWN_ATTACKS, WHITE_PAWN_ATTACKS};
+use crate::moves::square_control::is_square_attacked;
+use crate::moves::types::Move;
 use crate::square::Square;
+use crate::utils::pgn_verify::san_moves;
 
 impl Board {
     /// Generate the piece‐placement portion of FEN (e.g., "rnbqkbnr/pppppppp/8/...").
@@ -19,33 +27,14 @@ impl Board {
                     continue;
                 }
 
-                let symbol = if self.bb(Color::White, Piece::Pawn) & bit != 0 {
-                    PC_TO_CHAR[(Color::White as usize) * 6 + (Piece::Pawn as usize)]
-                } else if self.bb(Color::White, Piece::Knight) & bit != 0 {
-                    PC_TO_CHAR[(Color::White as usize) * 6 + (Piece::Knight as usize)]
-                } else if self.bb(Color::White, Piece::Bishop) & bit != 0 {
-                    PC_TO_CHAR[(Color::White as usize) * 6 + (Piece::Bishop as usize)]
-                } else if self.bb(Color::White, Piece::Rook) & bit != 0 {
-                    PC_TO_CHAR[(Color::White as usize) * 6 + (Piece::Rook as usize)]
-                } else if self.bb(Color::White, Piece::Queen) & bit != 0 {
-                    PC_TO_CHAR[(Color::White as usize) * 6 + (Piece::Queen as usize)]
-                } else if self.bb(Color::White, Piece::King) & bit != 0 {
-                    PC_TO_CHAR[(Color::White as usize) * 6 + (Piece::King as usize)]
-                } else if self.bb(Color::Black, Piece::Pawn) & bit != 0 {
-                    PC_TO_CHAR[(Color::Black as usize) * 6 + (Piece::Pawn as usize)]
-                } else if self.bb(Color::Black, Piece::Knight) & bit != 0 {
-                    PC_TO_CHAR[(Color::Black as usize) * 6 + (Piece::Knight as usize)]
-                } else if self.bb(Color::Black, Piece::Bishop) & bit != 0 {
-                    PC_TO_CHAR[(Color::Black as usize) * 6 + (Piece::Bishop as usize)]
-                } else if self.bb(Color::Black, Piece::Rook) & bit != 0 {
-                    PC_TO_CHAR[(Color::Black as usize) * 6 + (Piece::Rook as usize)]
-                } else if self.bb(Color::Black, Piece::Queen) & bit != 0 {
-                    PC_TO_CHAR[(Color::Black as usize) * 6 + (Piece::Queen as usize)]
-                } else if self.bb(Color::Black, Piece::King) & bit != 0 {
-                    PC_TO_CHAR[(Color::Black as usize) * 6 + (Piece::King as usize)]
-                } else {
-                    '\0' // sentinel: means empty at this square
-                };
+                let mut symbol = '\0'; // sentinel: means empty at this square
+                for color in [Color::White, Color::Black] {
+                    for piece in ALL_PIECES {
+                        if self.bb(color, piece) & bit != 0 {
+                            symbol = piece.to_char(color);
+                        }
+                    }
+                }
 
                 // find the piece symbol, if any
                 if symbol != '\0' {
@@ -101,7 +90,16 @@ impl Board {
         }
     }
 
+    /// FEN for this position. The en passant square is written only when a
+    /// pawn of the side to move could capture onto it (the same rule used for
+    /// Zobrist hashing), so transpositions produce identical FENs.
     pub fn to_fen(&self) -> String {
+        let en_passant = if self.en_passant_capturable() {
+            self.en_passant_fen()
+        } else {
+            "-".to_string()
+        };
+
         format!(
             "{} {} {} {} {} {}",
             self.placement_fen(),
@@ -111,12 +109,22 @@ impl Board {
                 'b'
             },
             self.castling_fen(),
-            self.en_passant_fen(),
+            en_passant,
             self.halfmove_clock,
             self.fullmove_number,
         )
     }
 
+    /// Canonical FEN, for use as a position key in databases and EPD files.
+    ///
+    /// `to_fen` already writes the en passant square only when it is
+    /// capturable and `-` for no castling rights, and the clocks are
+    /// unsigned with a fullmove number of at least 1, so the two agree; this
+    /// name is the one to depend on when the exact string matters.
+    pub fn normalize_fen(&self) -> String {
+        self.to_fen()
+    }
+
     pub(crate) fn parse_placement(&mut self, placement: &str) -> Result<(), String> {
         *self = Board::new_empty(); // reset the board
         let ranks: Vec<&str> = placement.split('/').collect();
@@ -165,25 +173,20 @@ impl Board {
     /// Place a piece on `idx` (0–63) based on its FEN character.
     fn set_piece_at(&mut self, ch: char, idx: usize) -> Result<(), String> {
         let mask = 1u64 << idx;
-        // New: O(1) table lookup instead of a big match
-        if ch.is_ascii()
-            && let Some((piece, color)) = CHAR_TO_PC[ch as usize]
-        {
-            let old_bb = self.bb(color, piece);
-            self.set_bb(color, piece, old_bb | mask);
-            return Ok(());
-        }
-
-        // Preserve your old error behavior for unknown/non-ASCII glyphs
-        Err(format!("Invalid piece char '{}'", ch))
+        let (piece, color) =
+            Piece::from_fen_char(ch).map_err(|_| format!("Invalid piece char '{}'", ch))?;
+        let old_bb = self.bb(color, piece);
+        self.set_bb(color, piece, old_bb | mask);
+        Ok(())
     }
 
     pub(crate) fn parse_active_color(&mut self, field: &str) -> Result<(), String> {
-        self.side_to_move = match field {
-            "w" => Color::White,
-            "b" => Color::Black,
-            _ => return Err(format!("Invalid active-color in FEN: `{}`", field)),
-        };
+        let mut chars = field.chars();
+        self.side_to_move = match (chars.next(), chars.next()) {
+            (Some(c), None) => Color::from_char(c).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| format!("Invalid active-color in FEN: `{}`", field))?;
         Ok(())
     }
 
@@ -261,11 +264,160 @@ impl Board {
         self.parse_en_passant(ep)?;
         self.parse_clocks(hm, fm)?;
         self.zobrist = self.compute_zobrist_full();
-        self.zobrist = self.compute_zobrist_full();
-        self.history.clear();
+        self.truncate_history_at_irreversible();
         Ok(())
     }
 
+    /// Parse `fen` and reject positions that can't arise in a real game,
+    /// which `set_fen` lets through: beyond `validate`, there must be exactly
+    /// one king per side, the side that just moved must not be in check, the
+    /// en passant square must be on rank 6 (White to move) or rank 3 (Black
+    /// to move), the halfmove clock can't pass 150 (the 75-move rule), and
+    /// each castling right needs its king and rook on their home squares.
+    pub fn set_fen_strict(fen: &str) -> Result<Self, VantageError> {
+        let mut board = Board::new_empty();
+        board.set_fen(fen).map_err(VantageError::FenParseError)?;
+        board
+            .validate_strict()
+            .map_err(VantageError::FenParseError)?;
+        Ok(board)
+    }
+
+    fn validate_strict(&self) -> Result<(), String> {
+        self.validate()?;
+        if let Some(err) = self
+            .legality_errors(|sq, by| self.attacked_without_tables(sq, by))
+            .into_iter()
+            .next()
+        {
+            return Err(err);
+        }
+
+        if self.halfmove_clock > 150 {
+            return Err(format!(
+                "Halfmove clock {} is past the 75-move limit of 150",
+                self.halfmove_clock
+            ));
+        }
+
+        match self.castling_errors().into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the position could come from a game, as far as cheap checks
+    /// can tell. See `is_legal_position_errors` for what is checked.
+    pub fn is_legal_position(&self, tables: &MagicTables) -> bool {
+        self.is_legal_position_errors(tables).is_empty()
+    }
+
+    /// Every reason the position can't arise from legal play: some side
+    /// without exactly one king, pawns on the back ranks, the side not to
+    /// move in check, castling rights without the king and rook on their
+    /// home squares, or an en passant square on the wrong rank. Empty for
+    /// a legal position. The back-rank pawn check repeats one in `validate`,
+    /// which `set_fen_strict` has already run.
+    pub fn is_legal_position_errors(&self, tables: &MagicTables) -> Vec<String> {
+        let mut errors = self.legality_errors(|sq, by| {
+            is_square_attacked(self, Square::from_index(sq as u8), by, tables)
+        });
+        errors.extend(self.castling_errors());
+        errors
+    }
+
+    /// The checks behind `is_legal_position_errors` other than castling,
+    /// with the attack test passed in so `set_fen_strict` can run them
+    /// without magic tables.
+    fn legality_errors(&self, attacked: impl Fn(usize, Color) -> bool) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        let mut kings_ok = true;
+        for color in [Color::White, Color::Black] {
+            let kings = self.bb(color, Piece::King).count_ones();
+            if kings != 1 {
+                errors.push(format!("Expected one {:?} king, found {}", color, kings));
+                kings_ok = false;
+            }
+        }
+
+        let pawns = self.bb(Color::White, Piece::Pawn) | self.bb(Color::Black, Piece::Pawn);
+        if pawns & BACK_RANKS_MASK != 0 {
+            errors.push("Pawn found on rank 1 or rank 8".to_string());
+        }
+
+        let mover = self.side_to_move.opposite();
+        if kings_ok {
+            let king_sq = self.bb(mover, Piece::King).trailing_zeros() as usize;
+            if attacked(king_sq, self.side_to_move) {
+                errors.push(format!(
+                    "{:?} is in check but it is {:?} to move",
+                    mover, self.side_to_move
+                ));
+            }
+        }
+
+        if let Some(ep) = self.en_passant {
+            let expected_rank = match self.side_to_move {
+                Color::White => 5,
+                Color::Black => 2,
+            };
+            if ep.rank() != expected_rank {
+                errors.push(format!(
+                    "En passant square {} is not on rank {} with {:?} to move",
+                    ep,
+                    expected_rank + 1,
+                    self.side_to_move
+                ));
+            }
+        }
+        errors
+    }
+
+    /// Castling rights whose king or rook is off its home square. Kept
+    /// apart from `legality_errors` so `set_fen_strict` can check the
+    /// halfmove clock before the castling rights.
+    fn castling_errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        // (right, FEN letter, color, king square, rook square)
+        let homes = [
+            (CASTLE_WK, 'K', Color::White, 4, 7),
+            (CASTLE_WQ, 'Q', Color::White, 4, 0),
+            (CASTLE_BK, 'k', Color::Black, 60, 63),
+            (CASTLE_BQ, 'q', Color::Black, 60, 56),
+        ];
+        for (right, letter, color, king, rook) in homes {
+            if self.has_castling(right)
+                && (self.bb(color, Piece::King) & (1u64 << king) == 0
+                    || self.bb(color, Piece::Rook) & (1u64 << rook) == 0)
+            {
+                errors.push(format!(
+                    "Castling right `{}` without king on {} and rook on {}",
+                    letter,
+                    Square::from_index(king),
+                    Square::from_index(rook)
+                ));
+            }
+        }
+        errors
+    }
+
+    /// Whether `by` attacks `sq`, using ray scans instead of magic tables,
+    /// so FEN validation doesn't need `MagicTables` loaded.
+    fn attacked_without_tables(&self, sq: usize, by: Color) -> bool {
+        let occ = self.occupied();
+        let pawn_attackers = match by {
+            Color::White => BLACK_PAWN_ATTACKS[sq],
+            Color::Black => WHITE_PAWN_ATTACKS[sq],
+        };
+        let queens = self.bb(by, Piece::Queen);
+        pawn_attackers & self.bb(by, Piece::Pawn) != 0
+            || KNIGHT_ATTACKS[sq] & self.bb(by, Piece::Knight) != 0
+            || KING_ATTACKS[sq] & self.bb(by, Piece::King) != 0
+            || bishop_attacks_per_square(sq, occ) & (self.bb(by, Piece::Bishop) | queens) != 0
+            || rook_attacks_per_square(sq, occ) & (self.bb(by, Piece::Rook) | queens) != 0
+    }
+
     /// Split into six fields, validate count
     pub(crate) fn split_fen(fen: &str) -> Result<(&str, &str, &str, &str, &str, &str), String> {
         let p: Vec<&str> = fen.split_whitespace().collect();
@@ -277,3 +429,94 @@ impl Board {
         Ok((p[0], p[1], p[2], p[3], halfmove, fullmove))
     }
 }
+
+impl Board {
+    /// The position reached by playing UCI `moves` from the starting position.
+    pub fn from_moves(moves: &[&str], tables: &MagicTables) -> Result<Board, VantageError> {
+        let mut board = Board::new();
+        board.apply_uci_moves(moves, tables)?;
+        Ok(board)
+    }
+
+    /// The position reached by playing UCI `moves` from `fen`, as sent by
+    /// `position fen <fen> moves ...`.
+    pub fn from_fen_and_moves(
+        fen: &str,
+        moves: &[&str],
+        tables: &MagicTables,
+    ) -> Result<Board, VantageError> {
+        let mut board = Board::new_empty();
+        board.set_fen(fen).map_err(VantageError::FenParseError)?;
+        board.apply_uci_moves(moves, tables)?;
+        Ok(board)
+    }
+
+    /// Resolve PGN move text ("1. e4 e5 2. Nf3 Nc6 *") against this
+    /// position and return the moves in order. Move numbers, results, NAGs,
+    /// comments and variations are skipped; the board itself is unchanged.
+    pub fn pgn_to_moves(
+        &self,
+        pgn_text: &str,
+        tables: &MagicTables,
+    ) -> Result<Vec<Move>, VantageError> {
+        let mut board = self.clone();
+        san_moves(pgn_text)
+            .iter()
+            .map(|san| {
+                let mv = Move::from_san(san, &board, tables)?;
+                make_move_basic(&mut board, mv);
+                Ok(mv)
+            })
+            .collect()
+    }
+
+    /// Play `moves` from this position and return the board after each one
+    /// (`moves.len()` boards, not including `self`). Fails with
+    /// `InvalidMove`, naming the move's index, at the first illegal move.
+    pub fn apply_move_sequence(
+        &self,
+        moves: &[Move],
+        tables: &MagicTables,
+    ) -> Result<Vec<Board>, VantageError> {
+        let mut board = self.clone();
+        let mut boards = Vec::with_capacity(moves.len());
+        let mut legal = Vec::with_capacity(64);
+        let mut scratch = Vec::with_capacity(256);
+        for (i, &mv) in moves.iter().enumerate() {
+            generate_legal(&mut board, tables, &mut legal, &mut scratch);
+            if !legal.contains(&mv) {
+                return Err(VantageError::InvalidMove(format!(
+                    "move {} ({}) is not legal in {}",
+                    i,
+                    mv.to_uci(),
+                    board.to_fen()
+                )));
+            }
+            make_move_basic(&mut board, mv);
+            boards.push(board.clone());
+        }
+        Ok(boards)
+    }
+
+    fn apply_uci_moves(
+        &mut self,
+        moves: &[&str],
+        tables: &MagicTables,
+    ) -> Result<(), VantageError> {
+        let mut legal = Vec::with_capacity(64);
+        let mut scratch = Vec::with_capacity(256);
+        for uci in moves {
+            let mv = Move::from_uci(uci, self)?;
+            generate_legal(self, tables, &mut legal, &mut scratch);
+            if !legal.contains(&mv) {
+                return Err(VantageError::InvalidMove(format!(
+                    "{} is not legal in {}",
+                    uci,
+                    self.to_fen()
+                )));
+            }
+            make_move_basic(self, mv);
+        }
+        Ok(())
+    }
+}
@@ -27,7 +27,7 @@ pub(super) const CHAR_TO_PC: [Option<(Piece, Color)>; 128] = {
 
 #[inline]
 const fn pc_index(piece: Piece, color: Color) -> usize {
-    (color as usize) * 6 + (piece as usize)
+    color.index() * 6 + piece.index()
 }
 
 pub(super) const PC_TO_CHAR: [char; 12] = {
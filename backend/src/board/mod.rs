@@ -1,13 +1,22 @@
 mod fen;
 
 use crate::bitboard::BitboardExt;
+use crate::error::VantageError;
+use crate::moves::pawn::{BLACK_PAWN_ATTACKS, WHITE_PAWN_ATTACKS};
 use crate::square::Square;
 use std::fmt;
 use std::str::FromStr;
 
 pub mod castle_bits;
+#[cfg(feature = "incremental_eval")]
+pub mod eval_accumulator;
 mod fen_tables;
+pub mod serial;
+
 pub use castle_bits::*;
+#[cfg(feature = "incremental_eval")]
+pub use eval_accumulator::EvalAccumulator;
+use fen_tables::{CHAR_TO_PC, PC_TO_CHAR};
 
 /// Starting position constants
 // ———————— White side (ranks 1 & 2) ————————
@@ -40,9 +49,16 @@ const BLACK_QUEEN_MASK: u64 = 1 << 59; // 0x0800_0000_0000_0000
 // King on e8 (bit 60)
 const BLACK_KING_MASK: u64 = 1 << 60; // 0x1000_0000_0000_0000
 
+// Ranks 1 and 8, where no pawn may ever stand
+const BACK_RANKS_MASK: u64 = 0xFF00_0000_0000_00FF;
+
 // Empty square value, no piece 0-11 will coincide with 255
 pub(crate) const EMPTY_SQ: u8 = 0xFF;
 
+/// Endgames with this many knights, bishops, rooks and queens or fewer (both
+/// sides together) are treated as zugzwang-prone by `is_zugzwang_candidate`.
+pub const ZUGZWANG_MAX_PIECES: u32 = 2;
+
 /// Which side is to move.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -63,6 +79,36 @@ pub enum Piece {
     King,
 }
 
+/// Every piece type, in `Piece` discriminant order.
+pub(crate) const ALL_PIECES: [Piece; 6] = [
+    Piece::Pawn,
+    Piece::Knight,
+    Piece::Bishop,
+    Piece::Rook,
+    Piece::Queen,
+    Piece::King,
+];
+
+/// Material signature used to route evaluation to endgame-specific code.
+/// The strong side may be either color; `Kpsk` is two or more pawns against
+/// a bare king.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndgameType {
+    Kpk,
+    Krk,
+    Kqk,
+    Kbnk,
+    Kbbk,
+    Krpkr,
+    Kpsk,
+    GenericEndgame,
+    Middlegame,
+}
+
+/// Positions whose game phase (see `Board::phase`, 24 = all pieces) is
+/// above this count as `EndgameType::Middlegame`.
+pub const ENDGAME_MAX_PHASE: i32 = 12;
+
 /// Core board representation using bitboards.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Board {
@@ -86,8 +132,13 @@ pub struct Board {
     pub fullmove_number: u32,
     // Zobrist hash for each board.
     pub zobrist: u64,
-    // History for zobrist hashing
+    /// Zobrist keys of the positions before the current one, oldest first,
+    /// back to (and including) the position the last irreversible move was
+    /// played from. The current position is never in it.
     pub history: Vec<u64>,
+    /// Incrementally updated PeSTO terms, maintained by `set_bb`.
+    #[cfg(feature = "incremental_eval")]
+    pub eval_accumulator: EvalAccumulator,
 }
 
 impl Board {
@@ -99,14 +150,14 @@ impl Board {
 
     #[inline(always)]
     pub(crate) fn bb(&self, color: Color, piece: Piece) -> u64 {
-        self.piece_bb[color as usize][piece as usize]
+        self.piece_bb[color.index()][piece.index()]
     }
 
     #[inline(always)]
     pub(crate) fn set_bb(&mut self, color: Color, piece: Piece, new_bb: u64) {
         use crate::hash::zobrist::zobrist_keys;
-        let ci = color as usize;
-        let pi = piece as usize;
+        let ci = color.index();
+        let pi = piece.index();
 
         let old_bb = self.piece_bb[ci][pi];
         let delta = old_bb ^ new_bb;
@@ -137,8 +188,12 @@ impl Board {
             // update piece_on_sq table
             if new_bb & single_bit != 0 {
                 self.place_piece_at_sq(color, piece, Square::from_index(sq_idx as u8));
+                #[cfg(feature = "incremental_eval")]
+                self.eval_accumulator.add(color, piece, sq_idx as u8);
             } else {
                 self.clear_square(Square::from_index(sq_idx as u8));
+                #[cfg(feature = "incremental_eval")]
+                self.eval_accumulator.remove(color, piece, sq_idx as u8);
             }
 
             // Zobrist: XOR the piece key (works for both add and remove)
@@ -160,6 +215,78 @@ impl Board {
         self.piece_on_sq[i] = (color as u8) << 3 | (piece as u8);
     }
 
+    /// Place `piece` of `color` on `sq`, replacing whatever was there.
+    /// Occupancy, `piece_on_sq` and the Zobrist hash are updated incrementally.
+    pub fn set_piece(&mut self, sq: Square, color: Color, piece: Piece) {
+        self.remove_piece(sq);
+        let bit = 1u64 << sq.index();
+        self.set_bb(color, piece, self.bb(color, piece) | bit);
+    }
+
+    /// Remove the piece on `sq` (if any) and return it.
+    pub fn remove_piece(&mut self, sq: Square) -> Option<(Color, Piece)> {
+        let (color, piece) = self.piece_at(sq)?;
+        let bit = 1u64 << sq.index();
+        self.set_bb(color, piece, self.bb(color, piece) & !bit);
+        Some((color, piece))
+    }
+
+    /// Color-flipped copy of the position: every piece changes color and is
+    /// reflected across the board's horizontal midline (rank 1 ↔ rank 8).
+    /// Side to move, castling rights and the en passant square are mirrored too,
+    /// so the result is the same position seen from the other side.
+    ///
+    /// Repetition history is dropped since the old hashes no longer apply.
+    pub fn mirror_colors(&self) -> Board {
+        let mut b = Board::new_empty();
+        for color in [Color::White, Color::Black] {
+            for piece in ALL_PIECES {
+                b.set_bb(!color, piece, self.bb(color, piece).swap_bytes());
+            }
+        }
+
+        // K,Q (bits 0–1) ↔ k,q (bits 2–3)
+        let rights = self.castling_rights;
+        b.castling_rights =
+            ((rights & (CASTLE_WK | CASTLE_WQ)) << 2) | ((rights & (CASTLE_BK | CASTLE_BQ)) >> 2);
+        b.side_to_move = !self.side_to_move;
+        b.en_passant = self
+            .en_passant
+            .map(|sq| Square::from_index(sq.index() ^ 56));
+        b.halfmove_clock = self.halfmove_clock;
+        b.fullmove_number = self.fullmove_number;
+        b.refresh_zobrist();
+        b
+    }
+
+    /// Left-right mirrored copy of the position (a-file ↔ h-file).
+    ///
+    /// Castling rights are cleared: with the king on the d-file the mirrored
+    /// position can no longer castle under standard rules. Repetition history
+    /// is dropped as in [`Board::mirror_colors`].
+    pub fn mirror_horizontal(&self) -> Board {
+        let mut b = Board::new_empty();
+        for color in [Color::White, Color::Black] {
+            for piece in ALL_PIECES {
+                let mut bb = self.bb(color, piece);
+                let mut mirrored = 0u64;
+                while bb != 0 {
+                    let sq = bb.trailing_zeros();
+                    mirrored |= 1u64 << (sq ^ 7);
+                    bb &= bb - 1;
+                }
+                b.set_bb(color, piece, mirrored);
+            }
+        }
+
+        b.side_to_move = self.side_to_move;
+        b.en_passant = self.en_passant.map(|sq| Square::from_index(sq.index() ^ 7));
+        b.halfmove_clock = self.halfmove_clock;
+        b.fullmove_number = self.fullmove_number;
+        b.refresh_zobrist();
+        b
+    }
+
     /// Create an empty board (all bitboards zero, White to move).
     pub fn new_empty() -> Self {
         let mut b = Board {
@@ -175,6 +302,8 @@ impl Board {
             fullmove_number: 1,
             zobrist: 0,
             history: Vec::new(),
+            #[cfg(feature = "incremental_eval")]
+            eval_accumulator: EvalAccumulator::default(),
         };
         b.refresh_zobrist();
         b
@@ -205,35 +334,76 @@ impl Board {
         b.halfmove_clock = 0;
         b.fullmove_number = 1;
         b.refresh_zobrist();
-        b.history.clear();
-        // With the new logic we don't necessarily push the initial state here if we consider make_move pushes PRE-move.
-        // BUT for consistency with fen.rs and standard practice:
-        // If we treat history as "previous positions", initially there are none.
-        // However, if we want `is_repetition` to work for positions reached via transposition,
-        // we usually just scan the history stack.
-        // The user request says: "Before you update... push the current board.zobrist".
-        // So `new()` should probably start empty or consistent with fen.
-        // Let's leave it empty here? Or push it?
-        // Wait, standard `new()` is the start of the game.
-        // If we make a move from here, we push THIS position.
-        // So initially `history` should be empty or contain previous game states (none).
-        // BUT `fen.rs` pushes it.
-        // Let's see what I did in `fen.rs` planning... I said "initialize correctly".
-        // If make_move pushes the *current* (pre-move) hash, then after 1 move, history has 1 entry (start pos).
-        // If we revert to start pos, we pop. History empty.
-        // So `new()` should imply empty history or unrelated to current pos.
-        // BUT wait, `repetition_count` uses it.
-        // Let's stick to: history contains *previous* positions since last irreversible.
-        // So `new()` starts effectively empty or with just itself if we consider it "visited".
-        // Actually, if we are at root, and we haven't made moves, history is empty.
-        // If we reach this position again via search, we compare against history.
-        // The User said: "In search, finding the current position *once* in the history means we have reached a cycle".
-        // So `history` should contain the ancestors.
-        // If `new()` creates a board, it has no ancestors (unless FEN says so).
-        // So `b.history.clear()` is correct. remove the push.
+        // A fresh game has no ancestors
+        b.truncate_history_at_irreversible();
         b
     }
 
+    /// Fischer Random starting position number `n` (0–959) in the standard
+    /// Scharnagl numbering: 518 is the normal start, 0 is `BBQNNRKR`.
+    ///
+    /// Castling needs the king on e1 with rooks on a1/h1 until Chess960
+    /// castling is supported, so only those arrangements get castling rights.
+    pub fn from_960_index(n: u32) -> Result<Board, VantageError> {
+        if n >= 960 {
+            return Err(VantageError::FenParseError(format!(
+                "Chess960 index {} out of range (0-959)",
+                n
+            )));
+        }
+        // Knight pair placements among the five squares left after
+        // the bishops and queen are placed
+        const KNIGHTS: [(usize, usize); 10] = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+        ];
+
+        let mut rank: [Option<Piece>; 8] = [None; 8];
+        let mut n = n as usize;
+        rank[2 * (n % 4) + 1] = Some(Piece::Bishop); // light square: b, d, f, h
+        n /= 4;
+        rank[2 * (n % 4)] = Some(Piece::Bishop); // dark square: a, c, e, g
+        n /= 4;
+
+        let empty = |rank: &[Option<Piece>; 8]| -> Vec<usize> {
+            (0..8).filter(|&f| rank[f].is_none()).collect()
+        };
+        rank[empty(&rank)[n % 6]] = Some(Piece::Queen);
+        n /= 6;
+        let free = empty(&rank);
+        let (k1, k2) = KNIGHTS[n];
+        rank[free[k1]] = Some(Piece::Knight);
+        rank[free[k2]] = Some(Piece::Knight);
+        // The king always sits between the two rooks
+        let free = empty(&rank);
+        for (&file, piece) in free.iter().zip([Piece::Rook, Piece::King, Piece::Rook]) {
+            rank[file] = Some(piece);
+        }
+
+        let mut b = Board::new_empty();
+        for (file, piece) in rank.iter().enumerate() {
+            let piece = piece.expect("every back-rank file is filled");
+            let file = file as u8;
+            b.set_piece(Square::from_index(file), Color::White, piece);
+            b.set_piece(Square::from_index(56 + file), Color::Black, piece);
+            b.set_piece(Square::from_index(8 + file), Color::White, Piece::Pawn);
+            b.set_piece(Square::from_index(48 + file), Color::Black, Piece::Pawn);
+        }
+        if free == [0, 4, 7] {
+            b.castling_rights = CASTLE_WK | CASTLE_WQ | CASTLE_BK | CASTLE_BQ;
+        }
+        b.refresh_zobrist();
+        Ok(b)
+    }
+
     #[inline(always)]
     /// Bitboard of all pieces (both colors).
     pub fn occupied(&self) -> u64 {
@@ -270,9 +440,43 @@ impl Board {
             }
             seen |= bb;
         }
+
+        // Pawns can never stand on the first or last rank
+        let pawns = self.bb(Color::White, Piece::Pawn) | self.bb(Color::Black, Piece::Pawn);
+        if pawns & BACK_RANKS_MASK != 0 {
+            return Err("Pawn found on rank 1 or rank 8".to_string());
+        }
+
+        // Legal but usually a sign of a hand-written FEN
+        if let Some(ep) = self.en_passant
+            && !self.en_passant_capturable()
+        {
+            tracing::warn!("en passant square {} cannot be captured", ep);
+        }
         Ok(())
     }
 
+    /// True if a pawn of the side to move attacks the en passant square
+    /// (pins are ignored). Only then does the square affect the hash and FEN.
+    pub fn en_passant_capturable(&self) -> bool {
+        let Some(ep) = self.en_passant else {
+            return false;
+        };
+        let expected_rank = match self.side_to_move {
+            Color::White => 5,
+            Color::Black => 2,
+        };
+        if ep.rank() != expected_rank {
+            return false;
+        }
+        // Our pawns that attack `ep` are the ones an enemy pawn on `ep` would attack
+        let attackers = match self.side_to_move {
+            Color::White => BLACK_PAWN_ATTACKS[ep.index() as usize],
+            Color::Black => WHITE_PAWN_ATTACKS[ep.index() as usize],
+        };
+        attackers & self.bb(self.side_to_move, Piece::Pawn) != 0
+    }
+
     #[inline(always)]
     /// Bitboard of all pieces for one side.
     pub fn occupancy(&self, color: Color) -> u64 {
@@ -340,6 +544,135 @@ impl Board {
         }
     }
 
+    /// Number of `piece`s of `color` on the board.
+    #[inline(always)]
+    pub fn count_pieces(&self, color: Color, piece: Piece) -> u32 {
+        self.bb(color, piece).count_ones()
+    }
+
+    /// Number of pieces on the board, both colors, kings and pawns included.
+    #[inline(always)]
+    pub fn total_piece_count(&self) -> u32 {
+        self.occ_all.count_ones()
+    }
+
+    /// Number of knights, bishops, rooks and queens `color` has.
+    #[inline(always)]
+    pub fn non_pawn_piece_count(&self, color: Color) -> u32 {
+        self.count_pieces(color, Piece::Knight)
+            + self.count_pieces(color, Piece::Bishop)
+            + self.count_pieces(color, Piece::Rook)
+            + self.count_pieces(color, Piece::Queen)
+    }
+
+    /// True if `color` alone cannot deliver mate: no pawns, rooks or queens, and
+    /// at most a single minor piece or two knights.
+    pub fn insufficient_material(&self, color: Color) -> bool {
+        if self.bb(color, Piece::Pawn) | self.bb(color, Piece::Rook) | self.bb(color, Piece::Queen)
+            != 0
+        {
+            return false;
+        }
+        let knights = self.count_pieces(color, Piece::Knight);
+        let bishops = self.count_pieces(color, Piece::Bishop);
+        knights + bishops <= 1 || (knights == 2 && bishops == 0)
+    }
+
+    /// True if neither side has mating material: KvK, KBvK, KNvK, KNNvK, or
+    /// one minor each, except bishops on opposite colors (a corner mate is
+    /// still possible there).
+    pub fn is_draw_by_material(&self) -> bool {
+        if !self.insufficient_material(Color::White) || !self.insufficient_material(Color::Black) {
+            return false;
+        }
+
+        let white_minors = self.non_pawn_piece_count(Color::White);
+        let black_minors = self.non_pawn_piece_count(Color::Black);
+        // Bare king against a lone minor or two knights: KNN can't force mate
+        if white_minors == 0 || black_minors == 0 {
+            return true;
+        }
+        // KNN vs a minor can mate
+        if white_minors + black_minors > 2 {
+            return false;
+        }
+
+        // KBvKB: neither bishop can ever cover the other's squares when both
+        // sit on the same color
+        let wb = self.bb(Color::White, Piece::Bishop);
+        let bb = self.bb(Color::Black, Piece::Bishop);
+        if wb != 0 && bb != 0 {
+            let w_light = Square::from_index(wb.trailing_zeros() as u8).is_light();
+            let b_light = Square::from_index(bb.trailing_zeros() as u8).is_light();
+            return w_light == b_light;
+        }
+        true
+    }
+
+    /// True if neither side can force mate with best play, so a draw could be
+    /// claimed or adjudicated: KvK, KNvK, KBvK, KNNvK and one minor each
+    /// (KNvKN, KNvKB, KBvKB on either color). This is a claim check, not an
+    /// automatic rule: unlike `is_draw_by_material` it includes positions where
+    /// mate is still possible if one side blunders.
+    pub fn material_draw_claim(&self) -> bool {
+        self.insufficient_material(Color::White)
+            && self.insufficient_material(Color::Black)
+            && self.non_pawn_piece_count(Color::White) + self.non_pawn_piece_count(Color::Black)
+                <= 2
+    }
+
+    /// The first board in `positions` whose Zobrist key is `key`, for
+    /// matching a TT entry back to a position while debugging. A linear
+    /// scan; `utils::registry::PositionRegistry` does the same lookup in
+    /// O(1) during search.
+    pub fn from_position_key(key: u64, positions: &[Board]) -> Option<&Board> {
+        positions.iter().find(|b| b.zobrist == key)
+    }
+
+    /// Classify the position by the pieces left on the board.
+    pub fn endgame_type(&self) -> EndgameType {
+        if self.phase() > ENDGAME_MAX_PHASE {
+            return EndgameType::Middlegame;
+        }
+        let material = |color| {
+            [
+                Piece::Pawn,
+                Piece::Knight,
+                Piece::Bishop,
+                Piece::Rook,
+                Piece::Queen,
+            ]
+            .map(|piece| self.count_pieces(color, piece))
+        };
+        let (white, black) = (material(Color::White), material(Color::Black));
+
+        // [pawns, knights, bishops, rooks, queens]
+        for (strong, weak) in [(white, black), (black, white)] {
+            let endgame = match (strong, weak) {
+                ([1, 0, 0, 0, 0], [0, 0, 0, 0, 0]) => EndgameType::Kpk,
+                ([0, 0, 0, 1, 0], [0, 0, 0, 0, 0]) => EndgameType::Krk,
+                ([0, 0, 0, 0, 1], [0, 0, 0, 0, 0]) => EndgameType::Kqk,
+                ([0, 1, 1, 0, 0], [0, 0, 0, 0, 0]) => EndgameType::Kbnk,
+                ([0, 0, 2, 0, 0], [0, 0, 0, 0, 0]) => EndgameType::Kbbk,
+                ([1, 0, 0, 1, 0], [0, 0, 0, 1, 0]) => EndgameType::Krpkr,
+                ([pawns, 0, 0, 0, 0], [0, 0, 0, 0, 0]) if pawns >= 2 => EndgameType::Kpsk,
+                _ => continue,
+            };
+            return endgame;
+        }
+        EndgameType::GenericEndgame
+    }
+
+    /// True when passing might be the best move, so null-move pruning is
+    /// unsafe: the side to move has only king and pawns, or so few pieces
+    /// remain on the board (at most `ZUGZWANG_MAX_PIECES` N/B/R/Q in total)
+    /// that zugzwang is common.
+    pub fn is_zugzwang_candidate(&self) -> bool {
+        let us = self.non_pawn_piece_count(self.side_to_move);
+        let them = self.non_pawn_piece_count(self.side_to_move.opposite());
+        us == 0 || us + them <= ZUGZWANG_MAX_PIECES
+    }
+
     /// Checks if a side has any non-pawn material (N, B, R, Q).
     /// Used for Null Move Pruning to avoid Zugzwang in pawn-only endgames.
     #[inline(always)]
@@ -442,6 +775,22 @@ impl Board {
         board_hash
     }
 
+    /// Number of ancestor positions kept for repetition detection.
+    pub fn repetition_history_len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Forget all ancestors: nothing before an irreversible move (or a newly
+    /// set position) can recur.
+    pub fn truncate_history_at_irreversible(&mut self) {
+        self.history.clear();
+    }
+
+    /// Record the current position as an ancestor, before leaving it.
+    pub fn push_to_history(&mut self) {
+        self.history.push(self.zobrist);
+    }
+
     /// Counts occurrences of the *current* Zobrist in the history window.
     pub fn repetition_count(&self) -> u8 {
         let mut count: u8 = 0;
@@ -481,6 +830,27 @@ impl Board {
         self.repetition_count() >= 3
     }
 
+    /// Pass the move to the other side: the primitive behind null moves.
+    ///
+    /// Clears the en passant square (XOR-ing out its key if it was hashed)
+    /// and toggles the side-to-move key. Clocks, castling rights and history
+    /// are left untouched.
+    pub fn flip_side_to_move(&mut self) {
+        use crate::hash::zobrist::{ep_file_to_hash, zobrist_keys};
+
+        // EP hashing depends on the side to move, so remove it before flipping
+        if let Some(f) = ep_file_to_hash(self) {
+            self.zobrist ^= zobrist_keys().ep_file[f as usize];
+        }
+        self.en_passant = None;
+
+        self.side_to_move = self.side_to_move.opposite();
+        self.zobrist ^= zobrist_keys().side_to_move;
+
+        #[cfg(debug_assertions)]
+        self.assert_hash();
+    }
+
     #[cfg(debug_assertions)]
     #[inline]
     pub fn assert_hash(&self) {
@@ -494,7 +864,24 @@ impl Board {
     }
 }
 
+// `index()` relies on these discriminants; fail the build if they change.
+const _: () = assert!(Color::White as usize == 0 && Color::Black as usize == 1);
+const _: () = assert!(
+    Piece::Pawn as usize == 0
+        && Piece::Knight as usize == 1
+        && Piece::Bishop as usize == 2
+        && Piece::Rook as usize == 3
+        && Piece::Queen as usize == 4
+        && Piece::King as usize == 5
+);
+
 impl Color {
+    /// Array index for per-color tables: 0 for White, 1 for Black.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
     pub fn opposite(self) -> Self {
         match self {
             Color::White => Color::Black,
@@ -502,6 +889,18 @@ impl Color {
         }
     }
 
+    /// Parse a FEN active-color character (`'w'` or `'b'`).
+    pub fn from_char(c: char) -> Result<Color, VantageError> {
+        match c {
+            'w' => Ok(Color::White),
+            'b' => Ok(Color::Black),
+            _ => Err(VantageError::FenParseError(format!(
+                "Invalid color char '{}'",
+                c
+            ))),
+        }
+    }
+
     /// Decode a 0/1 value into a Color.
     #[inline(always)]
     pub(crate) fn from_u8(v: u8) -> Self {
@@ -513,6 +912,21 @@ impl Color {
     }
 }
 
+/// The uppercase (White) letter, as used in SAN.
+impl From<Piece> for char {
+    fn from(piece: Piece) -> char {
+        piece.to_char(Color::White)
+    }
+}
+
+impl TryFrom<char> for Piece {
+    type Error = VantageError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Piece::from_char(c)
+    }
+}
+
 impl std::ops::Not for Color {
     type Output = Self;
 
@@ -522,6 +936,13 @@ impl std::ops::Not for Color {
 }
 
 impl Piece {
+    /// Array index for per-piece tables: 0 (pawn) to 5 (king), in
+    /// declaration order.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
     /// Decode a 0–5 value into a Piece.
     #[inline(always)]
     pub(crate) fn from_u8(v: u8) -> Self {
@@ -536,7 +957,58 @@ impl Piece {
         }
     }
 
-    pub fn value(&self) -> i32 {
+    /// Parse a piece letter in either case (`'N'` and `'n'` are both knights).
+    pub fn from_char(c: char) -> Result<Piece, VantageError> {
+        Self::from_fen_char(c).map(|(piece, _)| piece)
+    }
+
+    /// Parse a FEN piece character; the case gives the color.
+    pub fn from_fen_char(c: char) -> Result<(Piece, Color), VantageError> {
+        let piece = c
+            .is_ascii()
+            .then(|| CHAR_TO_PC[c as usize])
+            .flatten()
+            .map(|(piece, _)| piece)
+            .ok_or_else(|| VantageError::FenParseError(format!("Invalid piece char '{}'", c)))?;
+        let color = Color::from_char(if c.is_ascii_uppercase() { 'w' } else { 'b' })?;
+        Ok((piece, color))
+    }
+
+    /// The FEN character for this piece: uppercase for White, lowercase for Black.
+    pub fn to_char(self, color: Color) -> char {
+        PC_TO_CHAR[color.index() * 6 + self.index()]
+    }
+
+    /// Bishops, rooks and queens: attacks depend on occupancy.
+    #[inline(always)]
+    pub fn sliding(&self) -> bool {
+        matches!(self, Piece::Bishop | Piece::Rook | Piece::Queen)
+    }
+
+    /// Kings, knights and pawns: fixed attack patterns per square.
+    #[inline(always)]
+    pub fn leaper(&self) -> bool {
+        !self.sliding()
+    }
+
+    /// Slides along diagonals (bishop, queen).
+    #[inline(always)]
+    pub fn diagonal_slider(&self) -> bool {
+        matches!(self, Piece::Bishop | Piece::Queen)
+    }
+
+    /// Slides along ranks and files (rook, queen).
+    #[inline(always)]
+    pub fn orthogonal_slider(&self) -> bool {
+        matches!(self, Piece::Rook | Piece::Queen)
+    }
+
+    /// The pieces a pawn may promote to, in generation order.
+    pub fn promotable() -> &'static [Piece] {
+        &[Piece::Queen, Piece::Rook, Piece::Bishop, Piece::Knight]
+    }
+
+    pub const fn value(&self) -> i32 {
         match self {
             Piece::Pawn => 100,
             Piece::Knight => 320,
@@ -547,7 +1019,7 @@ impl Piece {
         }
     }
 
-    pub fn attacker_value(&self) -> i32 {
+    pub const fn attacker_value(&self) -> i32 {
         match self {
             Piece::Pawn => 1,
             Piece::Knight => 2,
@@ -576,6 +1048,27 @@ impl FromStr for Board {
     }
 }
 
+impl Board {
+    /// The board as XBoard prints it: eight `|r|n|b|q|k|b|n|r|` lines from
+    /// rank 8 down to rank 1, with a space for each empty square.
+    pub fn to_xboard_display(&self) -> String {
+        let mut out = String::with_capacity(8 * 18);
+        for rank in (0..8).rev() {
+            out.push('|');
+            for file in 0..8 {
+                let c = match self.piece_at(Square::from_file_rank(file, rank)) {
+                    Some((color, piece)) => piece.to_char(color),
+                    None => ' ',
+                };
+                out.push(c);
+                out.push('|');
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
 impl fmt::Display for Board {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let fen = self.to_fen();
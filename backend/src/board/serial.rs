@@ -0,0 +1,200 @@
+//! Fixed-size binary encoding of a position, for passing boards between
+//! processes without formatting and parsing FEN.
+//!
+//! Layout (32 bytes, integers little-endian):
+//!
+//! | bytes  | contents                                                      |
+//! |--------|---------------------------------------------------------------|
+//! | 0..8   | occupancy bitboard                                            |
+//! | 8..24  | one nibble per occupied square, low square first, low nibble first: `color * 6 + piece` |
+//! | 24     | bit 0: side to move (1 = Black), bits 1..5: castling rights   |
+//! | 25     | en passant square index, `0xFF` for none                      |
+//! | 26..28 | halfmove clock (saturated at `u16::MAX`)                      |
+//! | 28..30 | fullmove number (saturated at `u16::MAX`)                     |
+//! | 30..32 | reserved, zero                                                |
+//!
+//! The 16 nibble bytes hold at most 32 pieces, which every reachable
+//! position fits in.
+
+use super::{ALL_PIECES, Board, Color};
+use crate::error::VantageError;
+use crate::square::Square;
+
+/// Size of the encoding produced by `Board::to_binary`.
+pub const BINARY_BOARD_LEN: usize = 32;
+
+/// Most pieces the encoding has room for.
+pub const BINARY_MAX_PIECES: u32 = 32;
+
+const NO_EN_PASSANT: u8 = 0xFF;
+
+impl Board {
+    /// Encode the position in the 32-byte format described in `board::serial`.
+    /// Move history (for repetitions) is not included.
+    ///
+    /// # Panics
+    /// If more than 32 pieces are on the board.
+    pub fn to_binary(&self) -> [u8; BINARY_BOARD_LEN] {
+        let occupied = self.occupied();
+        assert!(
+            occupied.count_ones() <= BINARY_MAX_PIECES,
+            "{} pieces do not fit in the binary board format",
+            occupied.count_ones()
+        );
+
+        let mut out = [0u8; BINARY_BOARD_LEN];
+        out[0..8].copy_from_slice(&occupied.to_le_bytes());
+
+        let mut occ = occupied;
+        let mut i = 0;
+        while occ != 0 {
+            let sq = Square::from_index(occ.trailing_zeros() as u8);
+            let (color, piece) = self.piece_at(sq).expect("occupied square has a piece");
+            let code = (color.index() * 6 + piece.index()) as u8;
+            out[8 + i / 2] |= code << (4 * (i % 2));
+            occ &= occ - 1;
+            i += 1;
+        }
+
+        out[24] = (self.side_to_move == Color::Black) as u8 | (self.castling_rights << 1);
+        out[25] = self.en_passant.map_or(NO_EN_PASSANT, |sq| sq.index());
+        let clamp = |n: u32| n.min(u16::MAX as u32) as u16;
+        out[26..28].copy_from_slice(&clamp(self.halfmove_clock).to_le_bytes());
+        out[28..30].copy_from_slice(&clamp(self.fullmove_number).to_le_bytes());
+        out
+    }
+
+    /// Decode a board written by `to_binary`. Only the layout is checked
+    /// (piece codes, castling bits, square indices), not whether the
+    /// position is legal; a bad layout is an `InvalidBinaryBoard` error.
+    pub fn from_binary(bytes: &[u8]) -> Result<Board, VantageError> {
+        let invalid = VantageError::InvalidBinaryBoard;
+
+        let bytes: &[u8; BINARY_BOARD_LEN] = bytes.try_into().map_err(|_| {
+            invalid(format!(
+                "expected {} bytes, got {}",
+                BINARY_BOARD_LEN,
+                bytes.len()
+            ))
+        })?;
+
+        let mut occ = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        if occ.count_ones() > BINARY_MAX_PIECES {
+            return Err(invalid(format!("{} pieces", occ.count_ones())));
+        }
+
+        let mut board = Board::new_empty();
+        let mut i = 0;
+        while occ != 0 {
+            let sq = Square::from_index(occ.trailing_zeros() as u8);
+            let code = (bytes[8 + i / 2] >> (4 * (i % 2))) & 0x0F;
+            if code >= 12 {
+                return Err(invalid(format!("piece code {} on {}", code, sq)));
+            }
+            let color = if code < 6 { Color::White } else { Color::Black };
+            board.set_piece(sq, color, ALL_PIECES[code as usize % 6]);
+            occ &= occ - 1;
+            i += 1;
+        }
+
+        let flags = bytes[24];
+        if flags >> 5 != 0 {
+            return Err(invalid(format!("unknown flag bits {:#04x}", flags)));
+        }
+        board.side_to_move = if flags & 1 == 0 {
+            Color::White
+        } else {
+            Color::Black
+        };
+        board.castling_rights = flags >> 1;
+
+        board.en_passant = match bytes[25] {
+            NO_EN_PASSANT => None,
+            sq if sq < 64 => Some(Square::from_index(sq)),
+            sq => return Err(invalid(format!("en passant square index {}", sq))),
+        };
+
+        board.halfmove_clock = u16::from_le_bytes([bytes[26], bytes[27]]) as u32;
+        board.fullmove_number = u16::from_le_bytes([bytes[28], bytes[29]]) as u32;
+        if board.fullmove_number == 0 {
+            return Err(invalid("fullmove number 0".to_string()));
+        }
+        if bytes[30..32] != [0, 0] {
+            return Err(invalid("reserved bytes are not zero".to_string()));
+        }
+
+        board.refresh_zobrist();
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::moves::execute::{generate_legal, make_move_basic};
+    use crate::moves::magic::loader::load_magic_tables;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_binary_roundtrip_random_positions() {
+        let tables = load_magic_tables();
+        let mut rng = StdRng::seed_from_u64(0xB0A4D);
+        let mut moves = Vec::new();
+        let mut scratch = Vec::new();
+
+        for _ in 0..100 {
+            let mut board = Board::new();
+            for _ in 0..rng.random_range(0..80) {
+                generate_legal(&mut board, &tables, &mut moves, &mut scratch);
+                if moves.is_empty() {
+                    break;
+                }
+                make_move_basic(&mut board, moves[rng.random_range(0..moves.len())]);
+            }
+
+            let bytes = board.to_binary();
+            let decoded = Board::from_binary(&bytes).unwrap();
+            assert_eq!(decoded.to_fen(), board.to_fen());
+            assert_eq!(decoded.zobrist, board.zobrist);
+        }
+    }
+
+    #[test]
+    fn test_binary_keeps_uncapturable_en_passant() {
+        // to_fen drops an en passant square no pawn can use; the encoding doesn't
+        let board =
+            Board::from_str("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1").unwrap();
+        let decoded = Board::from_binary(&board.to_binary()).unwrap();
+        assert_eq!(decoded.en_passant, board.en_passant);
+        assert_eq!(decoded.castling_rights, board.castling_rights);
+    }
+
+    #[test]
+    fn test_from_binary_rejects_bad_input() {
+        let good = Board::new().to_binary();
+        assert_eq!(
+            Board::from_binary(&good[..31]),
+            Err(VantageError::InvalidBinaryBoard(
+                "expected 32 bytes, got 31".to_string()
+            ))
+        );
+
+        let mut bad_piece = good;
+        bad_piece[8] = 0x0F;
+        assert!(Board::from_binary(&bad_piece).is_err());
+
+        let mut bad_ep = good;
+        bad_ep[25] = 64;
+        assert!(Board::from_binary(&bad_ep).is_err());
+
+        let mut bad_flags = good;
+        bad_flags[24] |= 0x80;
+        assert!(Board::from_binary(&bad_flags).is_err());
+
+        let mut reserved = good;
+        reserved[31] = 1;
+        assert!(Board::from_binary(&reserved).is_err());
+    }
+}
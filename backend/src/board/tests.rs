@@ -214,6 +214,31 @@ fn test_en_passant_fen_helper() {
     assert_eq!(b.en_passant_fen(), "h6");
 }
 
+#[test]
+fn test_piece_char_round_trip() {
+    for color in [Color::White, Color::Black] {
+        for piece in ALL_PIECES {
+            let c = piece.to_char(color);
+            assert_eq!(Piece::from_fen_char(c), Ok((piece, color)));
+            assert_eq!(Piece::from_char(c), Ok(piece));
+            assert_eq!(Piece::try_from(c), Ok(piece));
+        }
+        assert_eq!(char::from(Piece::Knight), 'N');
+    }
+    assert_eq!(Color::from_char('w'), Ok(Color::White));
+    assert_eq!(Color::from_char('b'), Ok(Color::Black));
+}
+
+#[test]
+fn test_piece_char_invalid() {
+    for c in ['x', 'W', '1', ' ', 'é'] {
+        assert!(Piece::from_char(c).is_err(), "{:?}", c);
+        assert!(Piece::from_fen_char(c).is_err(), "{:?}", c);
+    }
+    assert!(Color::from_char('W').is_err());
+    assert!(Color::from_char('x').is_err());
+}
+
 #[test]
 fn test_to_fen_starting_position() {
     // The full FEN for a fresh new board:
@@ -228,6 +253,80 @@ fn test_to_fen_empty_board() {
     assert_eq!(Board::new_empty().to_fen(), expected);
 }
 
+#[test]
+fn test_en_passant_capturable() {
+    let mut b: Board = "rnbqkbnr/pppp1ppp/8/8/3pP3/8/PPP2PPP/RNBQKBNR b KQkq e3 0 3"
+        .parse()
+        .unwrap();
+    assert!(b.en_passant_capturable());
+
+    // No black pawn next to e4
+    let mut quiet: Board = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"
+        .parse()
+        .unwrap();
+    quiet.en_passant = Some("e3".parse::<Square>().unwrap());
+    assert!(!quiet.en_passant_capturable());
+
+    // Rank 6 is never an en passant target for Black
+    b.en_passant = Some("e6".parse::<Square>().unwrap());
+    assert!(!b.en_passant_capturable());
+
+    b.en_passant = None;
+    assert!(!b.en_passant_capturable());
+}
+
+#[test]
+fn test_normalize_fen_en_passant() {
+    // No black pawn can take on e3: with or without the square, same key
+    let with_ep: Board = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+        .parse()
+        .unwrap();
+    let without_ep: Board = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"
+        .parse()
+        .unwrap();
+    assert_eq!(with_ep.normalize_fen(), without_ep.normalize_fen());
+
+    // With a pawn on d4 the square is capturable and part of the key
+    let capturable: Board = "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3"
+        .parse()
+        .unwrap();
+    let no_ep: Board = "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 3"
+        .parse()
+        .unwrap();
+    assert_ne!(capturable.normalize_fen(), no_ep.normalize_fen());
+    assert!(capturable.normalize_fen().contains(" e3 "));
+
+    let bare: Board = "4k3/8/8/8/8/8/8/4K3 w - - 0 1".parse().unwrap();
+    assert_eq!(bare.normalize_fen(), "4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+}
+
+#[test]
+fn test_to_fen_en_passant_only_when_capturable() {
+    use crate::moves::execute::make_move_basic;
+    use crate::moves::types::Move;
+
+    // e2e4 with no black pawn on d4/f4: nothing can capture, so no EP square
+    let mut b = Board::new();
+    let mv = Move::from_uci("e2e4", &b).unwrap();
+    make_move_basic(&mut b, mv);
+    assert_eq!(b.en_passant, Some("e3".parse::<Square>().unwrap()));
+    assert_eq!(
+        b.to_fen(),
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"
+    );
+
+    // Same push with a black pawn on d4 that can take en passant
+    let mut b: Board = "rnbqkbnr/ppp1pppp/8/8/3p4/8/PPPPPPPP/RNBQKBNR w KQkq - 0 3"
+        .parse()
+        .unwrap();
+    let mv = Move::from_uci("e2e4", &b).unwrap();
+    make_move_basic(&mut b, mv);
+    assert_eq!(
+        b.to_fen(),
+        "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3"
+    );
+}
+
 #[test]
 fn test_split_fen_valid() {
     // Exactly six fields
@@ -451,6 +550,85 @@ fn test_validate_with_overlap() {
     assert!(b.validate().is_err());
 }
 
+#[test]
+fn test_endgame_type() {
+    let kind = |fen: &str| Board::from_str(fen).unwrap().endgame_type();
+
+    assert_eq!(
+        kind("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"),
+        EndgameType::Middlegame
+    );
+    assert_eq!(kind("8/8/4k3/8/4P3/8/8/4K3 w - - 0 1"), EndgameType::Kpk);
+    assert_eq!(kind("8/8/4k3/8/4p3/8/8/4K3 w - - 0 1"), EndgameType::Kpk);
+    assert_eq!(kind("8/8/4k3/8/8/8/8/R3K3 w - - 0 1"), EndgameType::Krk);
+    assert_eq!(kind("8/8/4k3/8/8/8/8/4K2q w - - 0 1"), EndgameType::Kqk);
+    assert_eq!(kind("8/8/4k3/8/8/8/8/1N2KB2 w - - 0 1"), EndgameType::Kbnk);
+    assert_eq!(kind("8/8/4k3/8/8/8/8/2B1KB2 w - - 0 1"), EndgameType::Kbbk);
+    assert_eq!(
+        kind("8/8/4k3/r7/4P3/8/8/R3K3 w - - 0 1"),
+        EndgameType::Krpkr
+    );
+    assert_eq!(kind("8/8/4k3/8/3PP3/8/8/4K3 w - - 0 1"), EndgameType::Kpsk);
+
+    // Anything else with little material, including bare kings
+    assert_eq!(
+        kind("8/8/4k3/8/8/8/8/4K3 w - - 0 1"),
+        EndgameType::GenericEndgame
+    );
+    assert_eq!(
+        kind("8/8/4k3/8/4P3/4p3/8/4K3 w - - 0 1"),
+        EndgameType::GenericEndgame
+    );
+    assert_eq!(
+        kind("8/8/4k3/8/8/8/8/R3K2r w - - 0 1"),
+        EndgameType::GenericEndgame
+    );
+}
+
+#[test]
+fn test_set_fen_strict() {
+    use crate::error::VantageError;
+
+    let err = |fen: &str| match Board::set_fen_strict(fen) {
+        Err(VantageError::FenParseError(msg)) => msg,
+        other => panic!(
+            "{} should be rejected, got {:?}",
+            fen,
+            other.map(|b| b.to_fen())
+        ),
+    };
+
+    let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    assert_eq!(Board::set_fen_strict(start).unwrap().to_fen(), start);
+    let after_e4 = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1";
+    assert!(Board::set_fen_strict(after_e4).is_ok());
+
+    // Parse errors still come through
+    assert!(err("not a fen").contains("Expected at least 4 FEN fields"));
+
+    assert!(err("4k3/8/8/8/8/8/8/8 w - - 0 1").contains("Expected one White king, found 0"));
+    assert!(err("4k3/8/8/8/8/8/8/3KK3 w - - 0 1").contains("Expected one White king, found 2"));
+    assert!(err("P3k3/8/8/8/8/8/8/4K3 w - - 0 1").contains("Pawn found on rank 1 or rank 8"));
+    assert!(
+        err("4k3/8/8/8/8/8/8/4K2r b - - 0 1").contains("White is in check but it is Black to move")
+    );
+    assert!(
+        err("4k3/8/8/8/4P3/8/8/4K3 w - e3 0 1")
+            .contains("En passant square e3 is not on rank 6 with White to move")
+    );
+    assert!(err("4k3/8/8/8/8/8/8/4K3 w - - 151 90").contains("Halfmove clock 151"));
+    assert!(
+        err("4k3/8/8/8/8/8/8/4K3 w K - 0 1")
+            .contains("Castling right `K` without king on e1 and rook on h1")
+    );
+    assert!(
+        err("1r2k3/8/8/8/8/8/8/4K3 w q - 0 1")
+            .contains("Castling right `q` without king on e8 and rook on a8")
+    );
+    // The halfmove clock is checked before the castling rights
+    assert!(err("4k3/8/8/8/8/8/8/4K3 w K - 151 90").contains("Halfmove clock 151"));
+}
+
 #[test]
 fn test_valid_clocks() {
     let mut b = Board::new_empty();
@@ -491,3 +669,513 @@ fn test_display_fen() {
         "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
     );
 }
+
+#[test]
+fn test_set_piece_builds_starting_position() {
+    const BACK_RANK: [Piece; 8] = [
+        Piece::Rook,
+        Piece::Knight,
+        Piece::Bishop,
+        Piece::Queen,
+        Piece::King,
+        Piece::Bishop,
+        Piece::Knight,
+        Piece::Rook,
+    ];
+
+    let mut b = Board::new_empty();
+    for (file, &piece) in BACK_RANK.iter().enumerate() {
+        let file = file as u8;
+        b.set_piece(Square::from_file_rank(file, 0), Color::White, piece);
+        b.set_piece(Square::from_file_rank(file, 1), Color::White, Piece::Pawn);
+        b.set_piece(Square::from_file_rank(file, 6), Color::Black, Piece::Pawn);
+        b.set_piece(Square::from_file_rank(file, 7), Color::Black, piece);
+    }
+    b.castling_rights = CASTLE_WK | CASTLE_WQ | CASTLE_BK | CASTLE_BQ;
+    b.refresh_zobrist();
+
+    assert!(b.validate().is_ok());
+    assert_eq!(b, Board::new());
+}
+
+#[test]
+fn test_set_piece_replaces_occupant() {
+    let mut b = Board::new();
+    let e2 = Square::from_file_rank(4, 1);
+    b.set_piece(e2, Color::Black, Piece::Queen);
+
+    assert_eq!(b.piece_at(e2), Some((Color::Black, Piece::Queen)));
+    assert_eq!(b.pieces(Piece::Pawn, Color::White).count_ones(), 7);
+    assert_eq!(b.occ_white & (1 << e2.index()), 0);
+    assert!(b.validate().is_ok());
+    assert_eq!(b.zobrist, b.compute_zobrist_full());
+}
+
+#[test]
+fn test_remove_piece() {
+    let mut b = Board::new();
+    let d1 = Square::from_file_rank(3, 0);
+
+    assert_eq!(b.remove_piece(d1), Some((Color::White, Piece::Queen)));
+    assert_eq!(b.piece_at(d1), None);
+    assert_eq!(b.pieces(Piece::Queen, Color::White), 0);
+    assert_eq!(b.occ_all & (1 << d1.index()), 0);
+    assert_eq!(b.zobrist, b.compute_zobrist_full());
+
+    // Removing from an empty square is a no-op
+    assert_eq!(b.remove_piece(d1), None);
+}
+
+#[test]
+fn test_validate_rejects_pawn_on_back_rank() {
+    use crate::moves::types::{Move, PROMOTION};
+
+    // A "promotion" to King is never valid
+    let mut b: Board = "8/4P3/8/8/8/8/8/k6K w - - 0 1".parse().unwrap();
+    let bad = Move {
+        from: Square::from_file_rank(4, 6),
+        to: Square::from_file_rank(4, 7),
+        piece: Piece::Pawn,
+        promotion: Some(Piece::King),
+        flags: PROMOTION,
+    };
+    assert!(!bad.is_valid_promotion());
+    assert!(
+        Move {
+            promotion: Some(Piece::Knight),
+            ..bad
+        }
+        .is_valid_promotion()
+    );
+
+    // Leaving the pawn on the last rank (as a dropped promotion would) is caught by validate()
+    let from = b.remove_piece(bad.from).unwrap();
+    b.set_piece(bad.to, from.0, from.1);
+    let err = b.validate().unwrap_err();
+    assert!(err.contains("rank 1 or rank 8"));
+}
+
+#[test]
+fn test_promotable_pieces() {
+    assert_eq!(
+        Piece::promotable(),
+        &[Piece::Queen, Piece::Rook, Piece::Bishop, Piece::Knight]
+    );
+    assert!(!Piece::promotable().contains(&Piece::King));
+    assert!(!Piece::promotable().contains(&Piece::Pawn));
+}
+
+#[test]
+fn test_mirror_colors_startpos() {
+    let b = Board::new();
+    let m = b.mirror_colors();
+
+    // Same piece layout, Black to move
+    assert_eq!(m.piece_bb, b.piece_bb);
+    assert_eq!(m.side_to_move, Color::Black);
+    assert_eq!(m.castling_rights, b.castling_rights);
+    assert_eq!(m.zobrist, m.compute_zobrist_full());
+
+    // Mirroring twice is the identity
+    assert_eq!(m.mirror_colors(), b);
+}
+
+#[test]
+fn test_mirror_colors_custom() {
+    let b: Board = "r3k3/1p6/8/3pP3/8/8/8/4K2R w Kq d6 0 12".parse().unwrap();
+    let m = b.mirror_colors();
+
+    assert_eq!(m.to_fen(), "4k2r/8/8/8/3Pp3/8/1P6/R3K3 b Qk d3 0 12");
+    assert!(m.validate().is_ok());
+    assert_eq!(m.mirror_colors(), b);
+}
+
+#[test]
+fn test_mirror_horizontal() {
+    let b: Board = "r3k3/1p6/8/3pP3/8/8/8/4K2R w Kq d6 0 12".parse().unwrap();
+    let m = b.mirror_horizontal();
+
+    assert_eq!(m.to_fen(), "3k3r/6p1/8/3Pp3/8/8/8/R2K4 w - e6 0 12");
+    assert_eq!(m.zobrist, m.compute_zobrist_full());
+
+    // Flipping back restores everything except the dropped castling rights
+    let mut restored = m.mirror_horizontal();
+    restored.castling_rights = b.castling_rights;
+    restored.refresh_zobrist();
+    assert_eq!(restored, b);
+}
+
+#[test]
+fn test_piece_counts_startpos() {
+    let b = Board::new();
+    for color in [Color::White, Color::Black] {
+        assert_eq!(b.count_pieces(color, Piece::Pawn), 8);
+        assert_eq!(b.count_pieces(color, Piece::Knight), 2);
+        assert_eq!(b.count_pieces(color, Piece::Queen), 1);
+        assert_eq!(b.count_pieces(color, Piece::King), 1);
+        assert_eq!(b.non_pawn_piece_count(color), 7);
+    }
+    assert_eq!(b.total_piece_count(), 32);
+}
+
+#[test]
+fn test_color_and_piece_index() {
+    assert_eq!(Color::White.index(), 0);
+    assert_eq!(Color::Black.index(), 1);
+    let pieces = [
+        Piece::Pawn,
+        Piece::Knight,
+        Piece::Bishop,
+        Piece::Rook,
+        Piece::Queen,
+        Piece::King,
+    ];
+    for (i, piece) in pieces.into_iter().enumerate() {
+        assert_eq!(piece.index(), i);
+        assert_eq!(Piece::from_u8(i as u8), piece);
+    }
+}
+
+#[test]
+fn test_piece_counts_bare_kings() {
+    let b: Board = "4k3/8/8/8/8/8/8/4K3 w - - 0 1".parse().unwrap();
+    assert_eq!(b.total_piece_count(), 2);
+    assert_eq!(b.non_pawn_piece_count(Color::White), 0);
+    assert_eq!(b.non_pawn_piece_count(Color::Black), 0);
+}
+
+#[test]
+fn test_from_moves_ruy_lopez() {
+    let tables = crate::moves::magic::loader::load_magic_tables();
+    let b = Board::from_moves(&["e2e4", "e7e5", "g1f3", "b8c6", "f1b5"], &tables).unwrap();
+    assert_eq!(
+        b.to_fen(),
+        "r1bqkbnr/pppp1ppp/2n5/1B2p3/4P3/5N2/PPPP1PPP/RNBQK2R b KQkq - 3 3"
+    );
+}
+
+#[test]
+fn test_repetition_history_window() {
+    let tables = crate::moves::magic::loader::load_magic_tables();
+    let mut b = Board::new();
+    assert_eq!(b.repetition_history_len(), 0);
+
+    for (i, uci) in ["g1f3", "g8f6", "f3g1"].iter().enumerate() {
+        let mv = crate::moves::types::Move::from_uci(uci, &b).unwrap();
+        crate::moves::execute::make_move_basic(&mut b, mv);
+        assert_eq!(b.repetition_history_len(), i + 1);
+    }
+    assert!(!b.history.contains(&b.zobrist));
+
+    // A pawn move drops everything but the position it was played from
+    let before = b.zobrist;
+    let mv = crate::moves::types::Move::from_uci("e7e5", &b).unwrap();
+    crate::moves::execute::make_move_basic(&mut b, mv);
+    assert_eq!(b.history, [before]);
+
+    b.push_to_history();
+    assert_eq!(b.history, [before, b.zobrist]);
+    b.truncate_history_at_irreversible();
+    assert_eq!(b.repetition_history_len(), 0);
+
+    let fen = Board::from_moves(&["e2e4", "e7e5", "g1f3"], &tables)
+        .unwrap()
+        .to_fen();
+    let reparsed: Board = fen.parse().unwrap();
+    assert_eq!(reparsed.repetition_history_len(), 0);
+}
+
+#[test]
+fn test_pgn_to_moves_scholars_mate() {
+    let tables = crate::moves::magic::loader::load_magic_tables();
+    let b = Board::new();
+    let moves = b
+        .pgn_to_moves("1. e4 e5 2. Qh5 Nc6 3. Bc4 Nf6?? 4. Qxf7# 1-0", &tables)
+        .unwrap();
+    let uci: Vec<String> = moves.iter().map(|m| m.to_uci()).collect();
+    assert_eq!(
+        uci,
+        ["e2e4", "e7e5", "d1h5", "b8c6", "f1c4", "g8f6", "h5f7"]
+    );
+    assert!(moves[6].is_capture());
+    assert_eq!(b, Board::new(), "board must not be mutated");
+
+    // The resulting moves replay to the same position as from_moves
+    let replayed = Board::from_moves(&uci.iter().map(|s| s.as_str()).collect::<Vec<_>>(), &tables);
+    assert!(replayed.is_ok());
+
+    assert!(b.pgn_to_moves("1. e4 e5 2. Ke3", &tables).is_err());
+}
+
+#[test]
+fn test_apply_move_sequence_scholars_mate() {
+    use crate::error::VantageError;
+    let tables = crate::moves::magic::loader::load_magic_tables();
+    let start = Board::new();
+    let moves = start
+        .pgn_to_moves("1. e4 e5 2. Qh5 Nc6 3. Bc4 Nf6 4. Qxf7#", &tables)
+        .unwrap();
+
+    let boards = start.apply_move_sequence(&moves, &tables).unwrap();
+    let fens: Vec<String> = boards.iter().map(|b| b.to_fen()).collect();
+    assert_eq!(
+        fens,
+        [
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1",
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2",
+            "rnbqkbnr/pppp1ppp/8/4p2Q/4P3/8/PPPP1PPP/RNB1KBNR b KQkq - 1 2",
+            "r1bqkbnr/pppp1ppp/2n5/4p2Q/4P3/8/PPPP1PPP/RNB1KBNR w KQkq - 2 3",
+            "r1bqkbnr/pppp1ppp/2n5/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR b KQkq - 3 3",
+            "r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+            "r1bqkb1r/pppp1Qpp/2n2n2/4p3/2B1P3/8/PPPP1PPP/RNB1K1NR b KQkq - 0 4",
+        ]
+    );
+    assert_eq!(start, Board::new(), "board must not be mutated");
+
+    // Replaying the same line from the final position fails on the first move
+    let err = boards[6].apply_move_sequence(&moves, &tables).unwrap_err();
+    match err {
+        VantageError::InvalidMove(msg) => assert!(msg.starts_with("move 0 (e2e4)"), "{}", msg),
+        other => panic!("expected InvalidMove, got {:?}", other),
+    }
+    // ...and an illegal move later in the line is reported by index
+    let mut bad = moves.clone();
+    bad.swap(2, 3);
+    let err = start.apply_move_sequence(&bad, &tables).unwrap_err();
+    assert!(matches!(err, VantageError::InvalidMove(msg) if msg.starts_with("move 2 ")));
+}
+
+#[test]
+fn test_pgn_to_moves_promotion_notations() {
+    let tables = crate::moves::magic::loader::load_magic_tables();
+    let b: Board = "8/4P3/8/8/8/8/k7/7K w - - 0 1".parse().unwrap();
+    for text in ["1. e8=Q", "1. e8Q", "1. e8(Q)"] {
+        let moves = b.pgn_to_moves(text, &tables).unwrap();
+        assert_eq!(moves.len(), 1, "{}", text);
+        assert_eq!(moves[0].promotion, Some(Piece::Queen), "{}", text);
+    }
+    let knight = b.pgn_to_moves("1. e8=N+ *", &tables).unwrap();
+    assert_eq!(knight[0].promotion, Some(Piece::Knight));
+}
+
+#[test]
+fn test_from_fen_and_moves() {
+    let tables = crate::moves::magic::loader::load_magic_tables();
+    let kiwi = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    let b = Board::from_fen_and_moves(kiwi, &["e1g1", "e8c8"], &tables).unwrap();
+    assert_eq!(
+        b.to_fen(),
+        "2kr3r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R4RK1 w - - 2 2"
+    );
+
+    let no_moves = Board::from_fen_and_moves(kiwi, &[], &tables).unwrap();
+    assert_eq!(no_moves.to_fen(), kiwi);
+}
+
+#[test]
+fn test_from_moves_rejects_illegal_moves() {
+    use crate::error::VantageError;
+    let tables = crate::moves::magic::loader::load_magic_tables();
+
+    for moves in [
+        // not a pawn move
+        &["e2e5"][..],
+        // Black's piece with White to move
+        &["e7e5"][..],
+        // White is mated after fool's mate, so no further move is legal
+        &["f2f3", "e7e5", "g2g4", "d8h4", "a2a3"][..],
+    ] {
+        assert!(
+            matches!(
+                Board::from_moves(moves, &tables),
+                Err(VantageError::InvalidMove(_))
+            ),
+            "{:?} should be rejected",
+            moves
+        );
+    }
+
+    assert!(matches!(
+        Board::from_fen_and_moves("bad fen", &[], &tables),
+        Err(VantageError::FenParseError(_))
+    ));
+}
+
+#[test]
+fn test_flip_side_to_move_twice_is_identity() {
+    let kiwi = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    let original: Board = kiwi.parse().unwrap();
+    let mut b = original.clone();
+
+    b.flip_side_to_move();
+    assert_eq!(b.side_to_move, Color::Black);
+    assert_eq!(b.zobrist, b.compute_zobrist_full());
+    assert_ne!(b.zobrist, original.zobrist);
+
+    b.flip_side_to_move();
+    assert_eq!(b, original);
+    assert_eq!(b.zobrist, original.zobrist);
+}
+
+#[test]
+fn test_flip_side_to_move_clears_en_passant() {
+    // Black's f4 pawn could take on e3, so the EP file is hashed
+    let mut b: Board = "4k3/8/8/8/4Pp2/8/8/4K3 b - e3 0 1".parse().unwrap();
+    b.flip_side_to_move();
+
+    assert_eq!(b.en_passant, None);
+    assert_eq!(b.side_to_move, Color::White);
+    assert_eq!(b.zobrist, b.compute_zobrist_full());
+    assert_eq!(b.halfmove_clock, 0);
+}
+
+#[test]
+fn test_from_960_index() {
+    assert_eq!(Board::from_960_index(518).unwrap(), Board::new());
+
+    let b = Board::from_960_index(0).unwrap();
+    assert_eq!(
+        b.to_fen(),
+        "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w - - 0 1"
+    );
+
+    let mut seen = std::collections::HashSet::new();
+    for n in 0..960 {
+        let b = Board::from_960_index(n).unwrap();
+        b.validate().unwrap();
+        assert_eq!(b.zobrist, b.compute_zobrist_full());
+        assert!(
+            seen.insert(b.placement_fen()),
+            "index {} repeats a layout",
+            n
+        );
+    }
+    assert!(Board::from_960_index(960).is_err());
+}
+
+#[test]
+fn test_piece_movement_classes() {
+    // (piece, sliding, leaper, diagonal, orthogonal)
+    let cases = [
+        (Piece::Pawn, false, true, false, false),
+        (Piece::Knight, false, true, false, false),
+        (Piece::Bishop, true, false, true, false),
+        (Piece::Rook, true, false, false, true),
+        (Piece::Queen, true, false, true, true),
+        (Piece::King, false, true, false, false),
+    ];
+    for (piece, sliding, leaper, diagonal, orthogonal) in cases {
+        assert_eq!(piece.sliding(), sliding, "{:?}", piece);
+        assert_eq!(piece.leaper(), leaper, "{:?}", piece);
+        assert_eq!(piece.diagonal_slider(), diagonal, "{:?}", piece);
+        assert_eq!(piece.orthogonal_slider(), orthogonal, "{:?}", piece);
+    }
+}
+
+#[test]
+fn test_to_xboard_display() {
+    let board = Board::from_str("4k3/8/8/8/4P3/8/8/R3K3 b Q e3 0 1").unwrap();
+    assert_eq!(
+        board.to_xboard_display(),
+        "| | | | |k| | | |\n\
+         | | | | | | | | |\n\
+         | | | | | | | | |\n\
+         | | | | | | | | |\n\
+         | | | | |P| | | |\n\
+         | | | | | | | | |\n\
+         | | | | | | | | |\n\
+         |R| | | |K| | | |\n"
+    );
+}
+
+#[test]
+fn test_material_draw_claim() {
+    let claim = |fen: &str| Board::from_str(fen).unwrap().material_draw_claim();
+
+    // Opposite-colored bishops: not a dead position, but drawn with best play
+    let opposite_bishops = "4k3/8/8/8/8/8/8/2B1Kb2 w - - 0 1";
+    assert!(claim(opposite_bishops));
+    assert!(
+        !Board::from_str(opposite_bishops)
+            .unwrap()
+            .is_draw_by_material()
+    );
+
+    for fen in [
+        "4k3/8/8/8/8/8/8/4K3 w - - 0 1",
+        "4k3/8/8/8/8/8/8/1N2K3 w - - 0 1",
+        "4k3/8/8/8/8/8/8/2B1K3 w - - 0 1",
+        "4k3/8/8/8/8/8/8/1N2K1N1 w - - 0 1",
+        "1n2k3/8/8/8/8/8/8/1N2K3 w - - 0 1",
+        "2b1k3/8/8/8/8/8/8/1N2K3 w - - 0 1",
+        "2b1k3/8/8/8/8/8/8/2B1K3 w - - 0 1",
+    ] {
+        assert!(claim(fen), "{}", fen);
+    }
+
+    for fen in [
+        "4k3/8/8/8/8/8/8/R3K3 w - - 0 1",
+        "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1",
+        "4k3/8/8/8/8/8/8/2B1KB2 w - - 0 1",
+        "1n2k3/8/8/8/8/8/8/1N2K1N1 w - - 0 1",
+    ] {
+        assert!(!claim(fen), "{}", fen);
+    }
+}
+
+#[test]
+fn test_from_position_key() {
+    let tables = crate::moves::magic::loader::load_magic_tables();
+    let start = Board::new();
+    let moves = start.pgn_to_moves("1. e4 e5 2. Nf3 Nc6", &tables).unwrap();
+    let boards = start.apply_move_sequence(&moves, &tables).unwrap();
+
+    for b in &boards {
+        assert_eq!(Board::from_position_key(b.zobrist, &boards), Some(b));
+    }
+    assert_eq!(
+        Board::from_position_key(boards[2].zobrist ^ 1, &boards),
+        None
+    );
+    assert_eq!(Board::from_position_key(Board::new().zobrist, &[]), None);
+}
+
+#[test]
+fn test_is_legal_position() {
+    let tables = crate::moves::magic::loader::load_magic_tables();
+    assert!(Board::new().is_legal_position(&tables));
+    assert!(Board::new().is_legal_position_errors(&tables).is_empty());
+
+    let errors = |fen: &str| {
+        let board = Board::from_str(fen).unwrap();
+        assert!(
+            !board.is_legal_position(&tables),
+            "{} should be illegal",
+            fen
+        );
+        board.is_legal_position_errors(&tables)
+    };
+
+    assert_eq!(
+        errors("4k3/8/8/8/8/8/8/3QK2r b - - 0 1"),
+        ["White is in check but it is Black to move"]
+    );
+    assert_eq!(
+        errors("rnbqkbnr/pppppppp/8/8/8/4P3/PPPP1PPP/RNBQKBN1 w KQkq e3 0 1"),
+        [
+            "En passant square e3 is not on rank 6 with White to move",
+            "Castling right `K` without king on e1 and rook on h1",
+        ]
+    );
+
+    // Every problem is reported, not just the first
+    let board = Board::from_str("P3k3/8/8/8/8/8/8/4KK2 w q - 0 1").unwrap();
+    assert_eq!(
+        board.is_legal_position_errors(&tables),
+        [
+            "Expected one White king, found 2",
+            "Pawn found on rank 1 or rank 8",
+            "Castling right `q` without king on e8 and rook on a8",
+        ]
+    );
+}
@@ -0,0 +1,26 @@
+//! Online book learning: nudge book weights by game outcome.
+
+use crate::board::Board;
+use crate::error::VantageError;
+use crate::moves::types::Move;
+
+/// Outcome of a game from the point of view of the side that played the move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+/// Weight change per learned game.
+pub const LEARN_STEP: u16 = 8;
+
+pub trait LearnableBook {
+    /// Adjust the weight of `mv` in `board`'s entry after a game that ended
+    /// in `result`: up for a win, down for a loss, unchanged for a draw.
+    /// Weights stay within `1..=65535`; moves not in the book are ignored.
+    fn learn(&mut self, board: &Board, result: GameResult, mv: Move);
+
+    /// Write the learned weights to `path` as a Polyglot file.
+    fn save_learned(&self, path: &str) -> Result<(), VantageError>;
+}
@@ -1,6 +1,6 @@
+pub mod learning;
 pub mod polyglot_book;
 pub mod polyglot_entry;
-pub mod polyglot_hash;
-pub mod polyglot_keys;
 
+pub use learning::{GameResult, LEARN_STEP, LearnableBook};
 pub use polyglot_book::PolyglotBook;
@@ -3,27 +3,39 @@ use std::fs::File;
 use std::io::{self, BufReader, Read};
 use std::path::Path;
 
+use super::learning::{GameResult, LEARN_STEP, LearnableBook};
 use super::polyglot_entry::PolyglotEntry;
-use super::polyglot_hash::compute_polyglot_hash;
 use crate::board::Board;
+use crate::error::VantageError;
+use crate::hash::polyglot::polyglot_hash;
 use crate::moves::types::Move;
 
 pub struct PolyglotBook {
     entries: Vec<PolyglotEntry>,
+    /// Learned weights, sorted by key; these override `entries` on probe.
+    learned: Vec<PolyglotEntry>,
+}
+
+fn parse_entries(data: &[u8]) -> Vec<PolyglotEntry> {
+    data.chunks_exact(16)
+        .map(PolyglotEntry::from_bytes)
+        .collect()
+}
+
+/// The run of entries with `key` in a key-sorted slice.
+fn entries_for(entries: &[PolyglotEntry], key: u64) -> &[PolyglotEntry] {
+    let start = entries.partition_point(|e| e.key < key);
+    let end = entries.partition_point(|e| e.key <= key);
+    &entries[start..end]
 }
 
 impl PolyglotBook {
     /// Load from a byte slice (used by WASM via include_bytes!)
     pub fn from_bytes(data: &[u8]) -> Self {
-        let count = data.len() / 16;
-        let mut entries = Vec::with_capacity(count);
-
-        for i in 0..count {
-            let start = i * 16;
-            entries.push(PolyglotEntry::from_bytes(&data[start..start + 16]));
+        Self {
+            entries: parse_entries(data),
+            learned: Vec::new(),
         }
-
-        Self { entries }
     }
 
     pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
@@ -34,20 +46,49 @@ impl PolyglotBook {
         Ok(Self::from_bytes(&buffer))
     }
 
-    pub fn probe(&self, board: &Board) -> Option<Move> {
-        let hash = compute_polyglot_hash(board);
-
-        let idx = self.entries.partition_point(|e| e.key < hash);
+    /// Load learned weights saved by `save_learned`. A missing file is not
+    /// an error: nothing has been learned yet.
+    pub fn load_learned<P: AsRef<Path>>(&mut self, path: P) -> Result<(), VantageError> {
+        let path = path.as_ref();
+        match std::fs::read(path) {
+            Ok(data) => {
+                self.learned = parse_entries(&data);
+                self.learned.sort_by_key(|e| (e.key, e.move_poly));
+                Ok(())
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(VantageError::Io(format!("{}: {}", path.display(), e))),
+        }
+    }
 
-        if idx >= self.entries.len() || self.entries[idx].key != hash {
-            return None;
+    /// Book entries for `hash` with learned weights merged in.
+    fn candidates(&self, hash: u64) -> Vec<PolyglotEntry> {
+        let mut candidates = entries_for(&self.entries, hash).to_vec();
+        for learned in entries_for(&self.learned, hash) {
+            match candidates
+                .iter_mut()
+                .find(|e| e.move_poly == learned.move_poly)
+            {
+                Some(entry) => entry.weight = learned.weight,
+                None => candidates.push(*learned),
+            }
         }
+        candidates
+    }
+
+    /// Current weight of `mv` in `board`, including anything learned.
+    pub fn weight(&self, board: &Board, mv: Move) -> Option<u16> {
+        let move_poly = PolyglotEntry::encode_move(mv);
+        self.candidates(polyglot_hash(board))
+            .iter()
+            .find(|e| e.move_poly == move_poly)
+            .map(|e| e.weight)
+    }
 
-        let mut candidates = Vec::new();
-        let mut i = idx;
-        while i < self.entries.len() && self.entries[i].key == hash {
-            candidates.push(&self.entries[i]);
-            i += 1;
+    pub fn probe(&self, board: &Board) -> Option<Move> {
+        let candidates = self.candidates(polyglot_hash(board));
+        if candidates.is_empty() {
+            return None;
         }
 
         let total_weight: u32 = candidates.iter().map(|e| e.weight as u32).sum();
@@ -65,7 +106,7 @@ impl PolyglotBook {
         let mut rng = rand::rng();
         let mut pick = rng.random_range(0..total_weight);
 
-        for entry in candidates {
+        for entry in &candidates {
             let w = entry.weight as u32;
             if pick < w {
                 return entry.decode_move(board);
@@ -86,3 +127,39 @@ impl PolyglotBook {
         self.entries.is_empty()
     }
 }
+
+impl LearnableBook for PolyglotBook {
+    fn learn(&mut self, board: &Board, result: GameResult, mv: Move) {
+        let Some(weight) = self.weight(board, mv) else {
+            return;
+        };
+        let weight = match result {
+            GameResult::Win => weight.saturating_add(LEARN_STEP),
+            GameResult::Draw => weight,
+            GameResult::Loss => weight.saturating_sub(LEARN_STEP).max(1),
+        };
+
+        let key = polyglot_hash(board);
+        let move_poly = PolyglotEntry::encode_move(mv);
+        match self
+            .learned
+            .binary_search_by_key(&(key, move_poly), |e| (e.key, e.move_poly))
+        {
+            Ok(i) => self.learned[i].weight = weight,
+            Err(i) => self.learned.insert(
+                i,
+                PolyglotEntry {
+                    key,
+                    move_poly,
+                    weight,
+                    learn: 0,
+                },
+            ),
+        }
+    }
+
+    fn save_learned(&self, path: &str) -> Result<(), VantageError> {
+        let data: Vec<u8> = self.learned.iter().flat_map(|e| e.to_bytes()).collect();
+        std::fs::write(path, data).map_err(|e| VantageError::Io(format!("{}: {}", path, e)))
+    }
+}
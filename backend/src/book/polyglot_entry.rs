@@ -28,6 +28,39 @@ impl PolyglotEntry {
         }
     }
 
+    /// Big-endian 16-byte record, the inverse of `from_bytes`.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[0..8].copy_from_slice(&self.key.to_be_bytes());
+        bytes[8..10].copy_from_slice(&self.move_poly.to_be_bytes());
+        bytes[10..12].copy_from_slice(&self.weight.to_be_bytes());
+        bytes[12..16].copy_from_slice(&self.learn.to_be_bytes());
+        bytes
+    }
+
+    /// Polyglot encoding of an engine move (castling as king takes rook).
+    pub fn encode_move(mv: Move) -> u16 {
+        let to = if mv.is_kingside_castle() {
+            Square::from_index(mv.from.index() + 3)
+        } else if mv.is_queenside_castle() {
+            Square::from_index(mv.from.index() - 4)
+        } else {
+            mv.to
+        };
+        let promo = match mv.promotion {
+            Some(Piece::Knight) => 1,
+            Some(Piece::Bishop) => 2,
+            Some(Piece::Rook) => 3,
+            Some(Piece::Queen) => 4,
+            _ => 0,
+        };
+        (to.file() as u16)
+            | (to.rank() as u16) << 3
+            | (mv.from.file() as u16) << 6
+            | (mv.from.rank() as u16) << 9
+            | promo << 12
+    }
+
     /// Decode the Polyglot move encoding to engine Move.
     /// Requires the board to determine piece type, captures, etc.
     pub fn decode_move(&self, board: &Board) -> Option<Move> {
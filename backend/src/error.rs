@@ -0,0 +1,34 @@
+use crate::status::GameStatus;
+use std::fmt;
+
+/// Crate-wide error type for the public, embeddable API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VantageError {
+    /// A move that is malformed or not legal in the current position.
+    InvalidMove(String),
+    /// A FEN string that could not be parsed or describes an invalid position.
+    FenParseError(String),
+    /// A move was attempted after the game already ended.
+    GameOver(GameStatus),
+    /// A file could not be read.
+    Io(String),
+    /// A configuration file (e.g. SPSA tuning output) that could not be parsed.
+    InvalidConfig(String),
+    /// Bytes that are not a board in the `Board::to_binary` format.
+    InvalidBinaryBoard(String),
+}
+
+impl fmt::Display for VantageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VantageError::InvalidMove(msg) => write!(f, "Invalid move: {}", msg),
+            VantageError::FenParseError(msg) => write!(f, "Invalid FEN: {}", msg),
+            VantageError::GameOver(status) => write!(f, "Game is over: {:?}", status),
+            VantageError::Io(msg) => write!(f, "I/O error: {}", msg),
+            VantageError::InvalidConfig(msg) => write!(f, "Invalid config: {}", msg),
+            VantageError::InvalidBinaryBoard(msg) => write!(f, "Invalid binary board: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for VantageError {}
@@ -0,0 +1,131 @@
+//! Full-board snapshots of a game, for stepping back and forth in analysis.
+//!
+//! `Board::history` only keeps Zobrist keys for repetition detection; this
+//! keeps every position, which costs a `Board` clone per move. Fine for a
+//! GUI or analysis tool, far too slow for search.
+
+use crate::board::Board;
+use crate::moves::execute::make_move_basic;
+use crate::moves::types::Move;
+
+/// The positions of a game and the moves between them: `states[i]` is the
+/// board before `moves[i]`, and the last state is the current position.
+pub struct MoveHistory {
+    states: Vec<Board>,
+    moves: Vec<Move>,
+}
+
+impl MoveHistory {
+    /// An empty history starting at `start`.
+    pub fn new(start: Board) -> Self {
+        Self {
+            states: vec![start],
+            moves: Vec::new(),
+        }
+    }
+
+    /// Record `mv` played from `board`, normally `current()`. The move is
+    /// not checked for legality; `Game` is the place for that.
+    pub fn push(&mut self, board: &Board, mv: Move) {
+        self.states.truncate(self.moves.len());
+        self.states.push(board.clone());
+        let mut after = board.clone();
+        make_move_basic(&mut after, mv);
+        self.states.push(after);
+        self.moves.push(mv);
+    }
+
+    /// Take back the last move, returning the board it was played from
+    /// and the move itself.
+    pub fn pop(&mut self) -> Option<(Board, Move)> {
+        let mv = self.moves.pop()?;
+        self.states.pop();
+        Some((self.current().clone(), mv))
+    }
+
+    /// The position after the last recorded move.
+    pub fn current(&self) -> &Board {
+        self.states.last().expect("history always holds a position")
+    }
+
+    /// Take back the last move and set `board` to the position before it.
+    /// Returns the move, or `None` (leaving `board` alone) if there is none.
+    pub fn undo(&mut self, board: &mut Board) -> Option<Move> {
+        let (before, mv) = self.pop()?;
+        *board = before;
+        Some(mv)
+    }
+
+    /// Number of recorded moves.
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+}
+
+impl Default for MoveHistory {
+    fn default() -> Self {
+        Self::new(Board::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::moves::magic::loader::load_magic_tables;
+
+    const LINE: [&str; 10] = [
+        "e2e4", "e7e5", "g1f3", "b8c6", "f1b5", "a7a6", "b5a4", "g8f6", "e1g1", "f8e7",
+    ];
+
+    #[test]
+    fn test_push_pop_matches_replay() {
+        let tables = load_magic_tables();
+        let mut history = MoveHistory::default();
+        let mut board = Board::new();
+        for uci in LINE {
+            let mv = Move::from_uci(uci, &board).unwrap();
+            history.push(&board, mv);
+            make_move_basic(&mut board, mv);
+        }
+        assert_eq!(history.len(), 10);
+        assert_eq!(history.current(), &board);
+
+        // The last popped entry is the position before move 6 (...a6)
+        for _ in 0..4 {
+            history.pop().unwrap();
+        }
+        let (before, mv) = history.pop().unwrap();
+        assert_eq!(mv.to_uci(), "a7a6");
+
+        let replayed = Board::from_moves(&LINE[..5], &tables).unwrap();
+        assert_eq!(history.len(), 5);
+        assert_eq!(history.current(), &replayed);
+        assert_eq!(before, replayed);
+    }
+
+    #[test]
+    fn test_undo_restores_board() {
+        let mut history = MoveHistory::default();
+        let mut board = Board::new();
+        let e4 = Move::from_uci("e2e4", &board).unwrap();
+        history.push(&board, e4);
+        make_move_basic(&mut board, e4);
+
+        assert_eq!(history.undo(&mut board), Some(e4));
+        assert_eq!(board, Board::new());
+        assert!(history.is_empty());
+        assert_eq!(history.current(), &Board::new());
+
+        // Nothing left to undo
+        assert_eq!(history.undo(&mut board), None);
+        assert_eq!(board, Board::new());
+    }
+}
@@ -0,0 +1,212 @@
+pub mod history;
+
+use crate::board::Board;
+use crate::error::VantageError;
+use crate::moves::execute::{generate_legal, make_move_basic};
+use crate::moves::magic::MagicTables;
+use crate::moves::magic::loader::load_magic_tables;
+use crate::moves::types::Move;
+use crate::status::{GameStatus, position_status};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Magic tables shared by every `Game`, built by the first one created.
+static MAGIC_TABLES: OnceLock<MagicTables> = OnceLock::new();
+
+fn shared_tables() -> &'static MagicTables {
+    MAGIC_TABLES.get_or_init(load_magic_tables)
+}
+
+/// A game in progress: the board plus its status after every move.
+///
+/// `Game` owns the legality contract, so embedders (GUIs, servers) never call
+/// `make_move_basic` directly and can't play on after the game has ended.
+pub struct Game {
+    board: Board,
+    tables: &'static MagicTables,
+    status: GameStatus,
+}
+
+impl Game {
+    /// New game from the standard starting position.
+    pub fn new() -> Self {
+        Self::from_board(Board::new())
+    }
+
+    /// New game from a FEN string.
+    pub fn from_fen(fen: &str) -> Result<Self, VantageError> {
+        let board = Board::from_str(fen).map_err(VantageError::FenParseError)?;
+        Ok(Self::from_board(board))
+    }
+
+    fn from_board(mut board: Board) -> Self {
+        let tables = shared_tables();
+        let status = position_status(&mut board, tables);
+        Self {
+            board,
+            tables,
+            status,
+        }
+    }
+
+    /// Play `mv` and return the resulting status.
+    ///
+    /// Fails with `GameOver` if the game has already ended and `InvalidMove`
+    /// if `mv` is not legal here; the position is unchanged in both cases.
+    pub fn push_move(&mut self, mv: Move) -> Result<GameStatus, VantageError> {
+        if self.status != GameStatus::InPlay {
+            return Err(VantageError::GameOver(self.status));
+        }
+        if !self.legal_moves().contains(&mv) {
+            return Err(VantageError::InvalidMove(format!(
+                "{}: not legal in position {}",
+                mv.to_uci(),
+                self.board.to_fen()
+            )));
+        }
+
+        make_move_basic(&mut self.board, mv);
+        self.status = position_status(&mut self.board, self.tables);
+        Ok(self.status)
+    }
+
+    /// Parse `uci` (e.g. `"e2e4"`, `"e7e8q"`) and play it as `push_move` does.
+    pub fn push_uci(&mut self, uci: &str) -> Result<GameStatus, VantageError> {
+        let mv = Move::from_uci(uci, &self.board)?;
+        self.push_move(mv)
+    }
+
+    /// All legal moves for the side to move (empty once the game is over).
+    pub fn legal_moves(&self) -> Vec<Move> {
+        if self.status != GameStatus::InPlay {
+            return Vec::new();
+        }
+        // generate_legal needs `&mut` for its make/undo filter; work on a copy
+        let mut board = self.board.clone();
+        let mut moves = Vec::with_capacity(64);
+        let mut scratch = Vec::with_capacity(256);
+        generate_legal(&mut board, self.tables, &mut moves, &mut scratch);
+        moves
+    }
+
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn tables(&self) -> &MagicTables {
+        self.tables
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Numeric Annotation Glyph attached to a move (`!`, `?`, ... or `$n`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nag {
+    /// `!` ($1)
+    BestMove,
+    /// `?` ($2)
+    Mistake,
+    /// `!!` ($3)
+    BrilliantMove,
+    /// `??` ($4)
+    Blunder,
+    /// `!?` ($5)
+    Interesting,
+    /// `?!` ($6)
+    Dubious,
+    /// Any other `$n` glyph, kept by number.
+    Other(u8),
+}
+
+impl Nag {
+    /// Parse a move-suffix glyph such as `"!!"` or `"?!"`.
+    pub fn from_glyph(glyph: &str) -> Option<Nag> {
+        match glyph {
+            "!" => Some(Nag::BestMove),
+            "?" => Some(Nag::Mistake),
+            "!!" => Some(Nag::BrilliantMove),
+            "??" => Some(Nag::Blunder),
+            "!?" => Some(Nag::Interesting),
+            "?!" => Some(Nag::Dubious),
+            _ => None,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Nag {
+        match code {
+            1 => Nag::BestMove,
+            2 => Nag::Mistake,
+            3 => Nag::BrilliantMove,
+            4 => Nag::Blunder,
+            5 => Nag::Interesting,
+            6 => Nag::Dubious,
+            n => Nag::Other(n),
+        }
+    }
+
+    pub fn code(self) -> u8 {
+        match self {
+            Nag::BestMove => 1,
+            Nag::Mistake => 2,
+            Nag::BrilliantMove => 3,
+            Nag::Blunder => 4,
+            Nag::Interesting => 5,
+            Nag::Dubious => 6,
+            Nag::Other(n) => n,
+        }
+    }
+
+    /// The suffix glyph for $1–$6, `None` for the rest.
+    pub fn glyph(self) -> Option<&'static str> {
+        match self {
+            Nag::BestMove => Some("!"),
+            Nag::Mistake => Some("?"),
+            Nag::BrilliantMove => Some("!!"),
+            Nag::Blunder => Some("??"),
+            Nag::Interesting => Some("!?"),
+            Nag::Dubious => Some("?!"),
+            Nag::Other(_) => None,
+        }
+    }
+}
+
+/// A move from a replayed game together with its PGN annotations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedMove {
+    pub mv: Move,
+    pub nags: Vec<Nag>,
+    pub comment: Option<String>,
+}
+
+impl AnnotatedMove {
+    /// SAN followed by the NAGs and the comment in braces, e.g.
+    /// `Nxf7!! { Stunning sacrifice }`. `legal_moves` are the legal moves of
+    /// `board`, used for SAN disambiguation.
+    pub fn to_pgn_fragment(&self, board: &Board, legal_moves: &[Move]) -> String {
+        let mut out = self.mv.to_san(board, legal_moves);
+        // Only one glyph can be glued to the move; the rest are written as $n
+        let mut suffixed = false;
+        for nag in &self.nags {
+            match nag.glyph() {
+                Some(glyph) if !suffixed => {
+                    out.push_str(glyph);
+                    suffixed = true;
+                }
+                _ => out.push_str(&format!(" ${}", nag.code())),
+            }
+        }
+        if let Some(comment) = &self.comment {
+            out.push_str(&format!(" {{ {} }}", comment));
+        }
+        out
+    }
+}
@@ -1 +1,3 @@
+pub mod polyglot;
+mod polyglot_keys;
 pub mod zobrist;
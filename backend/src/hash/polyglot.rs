@@ -0,0 +1,144 @@
+//! Polyglot-compatible Zobrist hashing, for looking positions up in `.bin`
+//! opening books.
+//!
+//! The engine's own keys in `zobrist` are random per run (unless the
+//! `deterministic_zobrist` feature is on), so book keys need their own fixed
+//! table and Polyglot's piece numbering.
+
+use super::polyglot_keys::POLYGLOT_RANDOMS;
+use crate::board::castle_bits::{CASTLE_BK, CASTLE_BQ, CASTLE_WK, CASTLE_WQ};
+use crate::board::{ALL_PIECES, Board, Color};
+use crate::utils::pop_lsb;
+
+/// The Polyglot random table, split up the same way as `ZobristKeys`.
+pub struct PolyglotKeys {
+    /// [color][piece][square] with {White=0, Black=1} and {P,N,B,R,Q,K}={0..5}
+    pub piece: [[[u64; 64]; 6]; 2],
+    /// XORed in when White is to move
+    pub side_to_move: u64,
+    /// [0]=K, [1]=Q, [2]=k, [3]=q
+    pub castling: [u64; 4],
+    /// a..h => 0..7
+    pub ep_file: [u64; 8],
+}
+
+impl PolyglotKeys {
+    const fn from_randoms(randoms: &[u64; 781]) -> Self {
+        let mut piece = [[[0u64; 64]; 6]; 2];
+        let mut c = 0;
+        while c < 2 {
+            let mut p = 0;
+            while p < 6 {
+                // Polyglot's "kind of piece": black pawn 0, white pawn 1, black knight 2, ...
+                let kind = p * 2 + if c == Color::White.index() { 1 } else { 0 };
+                let mut sq = 0;
+                while sq < 64 {
+                    piece[c][p][sq] = randoms[64 * kind + sq];
+                    sq += 1;
+                }
+                p += 1;
+            }
+            c += 1;
+        }
+
+        let mut ep_file = [0u64; 8];
+        let mut f = 0;
+        while f < 8 {
+            ep_file[f] = randoms[772 + f];
+            f += 1;
+        }
+
+        PolyglotKeys {
+            piece,
+            side_to_move: randoms[780],
+            castling: [randoms[768], randoms[769], randoms[770], randoms[771]],
+            ep_file,
+        }
+    }
+}
+
+static POLYGLOT_KEYS: PolyglotKeys = PolyglotKeys::from_randoms(&POLYGLOT_RANDOMS);
+
+/// The fixed Polyglot keys. Unlike `zobrist_keys()` there is nothing to
+/// initialize: the table is built at compile time.
+#[inline]
+pub fn polyglot_keys() -> &'static PolyglotKeys {
+    &POLYGLOT_KEYS
+}
+
+/// The Polyglot book key for `board`.
+///
+/// En passant only counts when a pawn of the side to move could capture,
+/// as the format requires (see `Board::en_passant_capturable`).
+pub fn polyglot_hash(board: &Board) -> u64 {
+    let keys = polyglot_keys();
+    let mut hash = 0;
+
+    for color in [Color::White, Color::Black] {
+        for piece in ALL_PIECES {
+            let mut bb = board.pieces(piece, color);
+            while bb != 0 {
+                let sq = pop_lsb(&mut bb) as usize;
+                hash ^= keys.piece[color.index()][piece.index()][sq];
+            }
+        }
+    }
+
+    for (i, right) in [CASTLE_WK, CASTLE_WQ, CASTLE_BK, CASTLE_BQ]
+        .into_iter()
+        .enumerate()
+    {
+        if board.castling_rights & right != 0 {
+            hash ^= keys.castling[i];
+        }
+    }
+
+    if let Some(ep) = board.en_passant
+        && board.en_passant_capturable()
+    {
+        hash ^= keys.ep_file[ep.file() as usize];
+    }
+
+    if board.side_to_move == Color::White {
+        hash ^= keys.side_to_move;
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    // Reference keys from the Polyglot book format specification
+    #[test]
+    fn test_polyglot_hash_reference_positions() {
+        let cases = [
+            (
+                "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+                0x463b96181691fc9c,
+            ),
+            (
+                "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+                0x823c9b50fd114196,
+            ),
+            (
+                "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 2",
+                0x0756b94461c50fb0,
+            ),
+            (
+                "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+                0x22a48b5a8e47ff78,
+            ),
+            (
+                "rnbq1bnr/ppp1pkpp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR w - - 0 4",
+                0x00fdd303c946bdd9,
+            ),
+        ];
+        for (fen, key) in cases {
+            let board = Board::from_str(fen).unwrap();
+            assert_eq!(polyglot_hash(&board), key, "{}", fen);
+        }
+    }
+}
@@ -1,3 +1,6 @@
+//! The 781 published Polyglot random numbers, in file order: 768 piece-square
+//! keys, 4 castling keys, 8 en passant file keys and the side-to-move key.
+
 pub const POLYGLOT_RANDOMS: [u64; 781] = [
     0x9D39247E33776D41,
     0x2AF7398005AAA5C7,
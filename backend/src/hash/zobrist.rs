@@ -1,13 +1,10 @@
 // src/hash/zobrist.rs
 
+use crate::board::Board;
 use crate::board::castle_bits::*;
-use crate::board::{Board, Color, Piece};
 use once_cell::sync::OnceCell;
 use rand::{RngCore, SeedableRng, rngs::StdRng};
 
-const FILE_A: u64 = 0x0101_0101_0101_0101;
-const FILE_H: u64 = 0x8080_8080_8080_8080;
-
 #[cfg(feature = "deterministic_zobrist")]
 const ZOBRIST_SEED: u64 = 0x9E37_79B9_AAAC_5C87;
 
@@ -58,40 +55,76 @@ pub fn xor_castling_rights_delta(hash: &mut u64, keys: &ZobristKeys, old: u8, ne
 /// Pseudo-legal only (ignore pins/king safety).
 pub fn ep_file_to_hash(board: &Board) -> Option<u8> {
     let ep = board.en_passant?;
-    let s = ep.index();
+    board.en_passant_capturable().then_some(ep.file())
+}
+
+// Global keys, initialized on first use.
+pub fn zobrist_keys() -> &'static ZobristKeys {
+    static KEYS: OnceCell<ZobristKeys> = OnceCell::new();
+    KEYS.get_or_init(|| {
+        let keys = generate_zobrist_keys_with_rng(make_zobrist_rng());
+        debug_assert!(
+            verify_zobrist_distinct(&keys),
+            "degenerate Zobrist keys generated"
+        );
+        keys
+    })
+}
 
-    let r = s / 8;
-    if !(r == 2 || r == 5) {
-        // only rank 3 or 6 ever counts
-        return None;
+/// Sanity check for a key set: no zero keys, and the castling and EP file
+/// keys are pairwise distinct (equal keys would cancel out when XORed together).
+pub const fn verify_zobrist_distinct(keys: &ZobristKeys) -> bool {
+    if keys.side_to_move == 0 {
+        return false;
     }
 
-    let bb_s: u64 = 1u64 << s;
+    let mut i = 0;
+    while i < 4 {
+        if keys.castling[i] == 0 {
+            return false;
+        }
+        let mut j = i + 1;
+        while j < 4 {
+            if keys.castling[i] == keys.castling[j] {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
 
-    let has_capturing_pawn = match board.side_to_move {
-        Color::White => {
-            let src_ne = (bb_s >> 9) & !FILE_H;
-            let src_nw = (bb_s >> 7) & !FILE_A;
-            ((src_ne | src_nw) & board.bb(Color::White, Piece::Pawn)) != 0
+    let mut i = 0;
+    while i < 8 {
+        if keys.ep_file[i] == 0 {
+            return false;
         }
-        Color::Black => {
-            let src_se = (bb_s << 7) & !FILE_A;
-            let src_sw = (bb_s << 9) & !FILE_H;
-            ((src_se | src_sw) & board.bb(Color::Black, Piece::Pawn)) != 0
+        let mut j = i + 1;
+        while j < 8 {
+            if keys.ep_file[i] == keys.ep_file[j] {
+                return false;
+            }
+            j += 1;
         }
-    };
+        i += 1;
+    }
 
-    if has_capturing_pawn {
-        Some(s % 8)
-    } else {
-        None
+    let mut c = 0;
+    while c < 2 {
+        let mut p = 0;
+        while p < 6 {
+            let mut sq = 0;
+            while sq < 64 {
+                if keys.piece[c][p][sq] == 0 {
+                    return false;
+                }
+                sq += 1;
+            }
+            p += 1;
+        }
+        c += 1;
     }
-}
 
-// Global keys, initialized on first use.
-pub fn zobrist_keys() -> &'static ZobristKeys {
-    static KEYS: OnceCell<ZobristKeys> = OnceCell::new();
-    KEYS.get_or_init(|| generate_zobrist_keys_with_rng(make_zobrist_rng()))
+    true
 }
 
 // Stub for the next step (we’ll fill the arrays soon).
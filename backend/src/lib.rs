@@ -1,14 +1,16 @@
 pub mod bitboard;
 pub mod board;
 pub mod book;
+pub mod error;
+pub mod game;
 pub mod hash;
-#[cfg(feature = "cli")]
 pub mod logger;
 pub mod moves;
 pub(crate) mod output;
 pub mod search;
 pub mod square;
 pub mod status;
+pub mod uci;
 pub mod utils;
 
 #[cfg(target_arch = "wasm32")]
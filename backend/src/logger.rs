@@ -1,13 +1,91 @@
 // src/logging.rs
-use std::{path::Path, sync::OnceLock};
+use crate::error::VantageError;
+use crate::output::engine_println;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+#[cfg(feature = "cli")]
+use std::sync::OnceLock;
+#[cfg(feature = "cli")]
 use tracing_subscriber::{EnvFilter, fmt};
 
+/// Where the search sends its UCI `info` lines, one call per line.
+pub trait SearchLogger: Send {
+    fn log(&self, s: &str);
+
+    /// False if `log` discards its input, so callers can skip formatting.
+    fn enabled(&self) -> bool {
+        true
+    }
+}
+
+/// Writes to stdout: the UCI stream.
+pub struct StdoutLogger;
+
+impl SearchLogger for StdoutLogger {
+    fn log(&self, s: &str) {
+        engine_println!("{}", s);
+    }
+}
+
+/// Writes to stderr, keeping the output out of the UCI stream.
+pub struct StderrLogger;
+
+impl SearchLogger for StderrLogger {
+    fn log(&self, s: &str) {
+        eprintln!("{}", s);
+    }
+}
+
+/// Drops everything; the default for library use.
+pub struct NullLogger;
+
+impl SearchLogger for NullLogger {
+    fn log(&self, _s: &str) {}
+
+    fn enabled(&self) -> bool {
+        false
+    }
+}
+
+/// Appends each line to a file.
+pub struct FileLogger {
+    file: Mutex<std::fs::File>,
+}
+
+impl FileLogger {
+    /// Open `path` for appending, creating it if needed.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, VantageError> {
+        let path = path.as_ref();
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| VantageError::Io(format!("{}: {}", path.display(), e)))?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl SearchLogger for FileLogger {
+    fn log(&self, s: &str) {
+        // A failed write must not abort the search
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(file, "{}", s);
+        }
+    }
+}
+
+#[cfg(feature = "cli")]
 static GUARD: OnceLock<tracing_appender::non_blocking::WorkerGuard> = OnceLock::new();
+#[cfg(feature = "cli")]
 static INIT: OnceLock<()> = OnceLock::new();
 
 /// Initialize logging once for the whole process.
 /// - `path`: e.g., "logs/perft.log"
 /// - `filter`: e.g., "perft=trace,execute=debug"
+#[cfg(feature = "cli")]
 pub fn init_logging<P: AsRef<Path>>(path: P, filter: &str) {
     INIT.get_or_init(|| {
         let path = path.as_ref();
@@ -0,0 +1,264 @@
+//! All castling geometry in one table: which squares the king and rook
+//! use, which right the move needs, and which squares must be empty or safe.
+//!
+//! `CastleConfig::new` derives everything from the king and rook start
+//! squares, so a Chess960 setup only needs a different `[CastleConfig; 4]`.
+
+use crate::board::castle_bits::*;
+use crate::board::{Board, Color, Piece};
+use crate::moves::magic::MagicTables;
+use crate::moves::square_control::is_legal_castling;
+use crate::moves::types::{KINGSIDE_CASTLE, Move, QUEENSIDE_CASTLE};
+use crate::square::Square;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CastleConfig {
+    pub king_from: Square,
+    pub king_to: Square,
+    pub rook_from: Square,
+    pub rook_to: Square,
+    /// The castling right this move needs.
+    pub rights_mask: CastleBits,
+    /// Squares that must be empty (other than the king and rook themselves).
+    pub path_mask: u64,
+    /// Squares the king starts on, crosses or lands on; none may be attacked.
+    pub check_mask: u64,
+}
+
+/// Bits `lo..=hi` (in either order).
+const fn span(a: u8, b: u8) -> u64 {
+    let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+    (u64::MAX >> (63 - hi)) & (u64::MAX << lo)
+}
+
+impl CastleConfig {
+    /// Castle with the king on `king_from` and the rook on `rook_from`,
+    /// ending on the g/f files (rook right of the king) or c/d files.
+    pub const fn new(king_from: u8, rook_from: u8, rights_mask: CastleBits) -> Self {
+        let rank = king_from & !7;
+        let kingside = rook_from > king_from;
+        let (king_to, rook_to) = if kingside {
+            (rank + 6, rank + 5)
+        } else {
+            (rank + 2, rank + 3)
+        };
+        let movers = (1u64 << king_from) | (1u64 << rook_from);
+        Self {
+            king_from: Square::from_index(king_from),
+            king_to: Square::from_index(king_to),
+            rook_from: Square::from_index(rook_from),
+            rook_to: Square::from_index(rook_to),
+            rights_mask,
+            path_mask: (span(king_from, king_to) | span(rook_from, rook_to)) & !movers,
+            check_mask: span(king_from, king_to),
+        }
+    }
+
+    /// The king move that performs this castle.
+    pub fn to_move(&self) -> Move {
+        Move {
+            from: self.king_from,
+            to: self.king_to,
+            piece: Piece::King,
+            promotion: None,
+            flags: if self.rook_from.index() > self.king_from.index() {
+                KINGSIDE_CASTLE
+            } else {
+                QUEENSIDE_CASTLE
+            },
+        }
+    }
+}
+
+/// Standard chess, indexed by `config_index`: White O-O, White O-O-O,
+/// Black O-O, Black O-O-O.
+pub const STANDARD_CASTLES: [CastleConfig; 4] = [
+    CastleConfig::new(4, 7, CASTLE_WK),
+    CastleConfig::new(4, 0, CASTLE_WQ),
+    CastleConfig::new(60, 63, CASTLE_BK),
+    CastleConfig::new(60, 56, CASTLE_BQ),
+];
+
+#[inline(always)]
+const fn config_index(color: Color, kingside: bool) -> usize {
+    color.index() * 2 + if kingside { 0 } else { 1 }
+}
+
+/// The castle for `color` on the given wing.
+#[inline(always)]
+pub fn castle_config(color: Color, kingside: bool) -> &'static CastleConfig {
+    &STANDARD_CASTLES[config_index(color, kingside)]
+}
+
+/// The castle `mv` performs, if it is a castling move to the right square.
+#[inline(always)]
+pub fn castle_for_move(color: Color, mv: Move) -> Option<&'static CastleConfig> {
+    if !mv.is_castling() {
+        return None;
+    }
+    let config = castle_config(color, mv.is_kingside_castle());
+    (config.king_to == mv.to).then_some(config)
+}
+
+/// Both castling rights of `color` (lost when its king moves).
+#[inline(always)]
+pub fn color_rights(color: Color) -> CastleBits {
+    castle_config(color, true).rights_mask | castle_config(color, false).rights_mask
+}
+
+/// The right lost when `color`'s rook leaves or is captured on `sq`.
+#[inline(always)]
+pub fn rook_rights(color: Color, sq: u8) -> CastleBits {
+    [true, false]
+        .iter()
+        .map(|&kingside| castle_config(color, kingside))
+        .filter(|c| c.rook_from.index() == sq)
+        .fold(0, |mask, c| mask | c.rights_mask)
+}
+
+impl Board {
+    /// True if the side to move can castle kingside right now: it has the
+    /// right, nothing stands between king and rook, and the king is not in,
+    /// through or into check.
+    pub fn can_castle_kingside(&self, tables: &MagicTables) -> bool {
+        self.can_castle(castle_config(self.side_to_move, true), tables)
+    }
+
+    /// Queenside counterpart of `can_castle_kingside`.
+    pub fn can_castle_queenside(&self, tables: &MagicTables) -> bool {
+        self.can_castle(castle_config(self.side_to_move, false), tables)
+    }
+
+    fn can_castle(&self, castle: &CastleConfig, tables: &MagicTables) -> bool {
+        self.castling_rights & castle.rights_mask != 0
+            && self.occupied() & castle.path_mask == 0
+            && is_legal_castling(self, castle.to_move(), tables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sq(i: u8) -> Square {
+        Square::from_index(i)
+    }
+
+    #[test]
+    fn standard_configs_match_hardcoded_castling() {
+        // (king_from, king_to, rook_from, rook_to, right, must be empty, king path)
+        let expected = [
+            (
+                4,
+                6,
+                7,
+                5,
+                CASTLE_WK,
+                0x0000_0000_0000_0060,
+                0x0000_0000_0000_0070,
+            ),
+            (
+                4,
+                2,
+                0,
+                3,
+                CASTLE_WQ,
+                0x0000_0000_0000_000E,
+                0x0000_0000_0000_001C,
+            ),
+            (
+                60,
+                62,
+                63,
+                61,
+                CASTLE_BK,
+                0x6000_0000_0000_0000,
+                0x7000_0000_0000_0000,
+            ),
+            (
+                60,
+                58,
+                56,
+                59,
+                CASTLE_BQ,
+                0x0E00_0000_0000_0000,
+                0x1C00_0000_0000_0000,
+            ),
+        ];
+        for (config, &(kf, kt, rf, rt, right, path, check)) in
+            STANDARD_CASTLES.iter().zip(expected.iter())
+        {
+            assert_eq!(
+                *config,
+                CastleConfig {
+                    king_from: sq(kf),
+                    king_to: sq(kt),
+                    rook_from: sq(rf),
+                    rook_to: sq(rt),
+                    rights_mask: right,
+                    path_mask: path,
+                    check_mask: check,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn rights_lookups() {
+        assert_eq!(color_rights(Color::White), CASTLE_WK | CASTLE_WQ);
+        assert_eq!(color_rights(Color::Black), CASTLE_BK | CASTLE_BQ);
+        assert_eq!(rook_rights(Color::White, 0), CASTLE_WQ);
+        assert_eq!(rook_rights(Color::White, 7), CASTLE_WK);
+        assert_eq!(rook_rights(Color::Black, 56), CASTLE_BQ);
+        assert_eq!(rook_rights(Color::Black, 63), CASTLE_BK);
+        assert_eq!(rook_rights(Color::White, 63), 0);
+        assert_eq!(rook_rights(Color::Black, 3), 0);
+    }
+
+    #[test]
+    fn castle_for_move_checks_destination() {
+        use crate::board::Piece;
+        use crate::moves::types::{KINGSIDE_CASTLE, QUIET_MOVE};
+        let castle = Move {
+            from: sq(4),
+            to: sq(6),
+            piece: Piece::King,
+            promotion: None,
+            flags: KINGSIDE_CASTLE,
+        };
+        assert_eq!(
+            castle_for_move(Color::White, castle),
+            Some(&STANDARD_CASTLES[0])
+        );
+        assert_eq!(castle_for_move(Color::Black, castle), None);
+        let step = Move {
+            flags: QUIET_MOVE,
+            to: sq(5),
+            ..castle
+        };
+        assert_eq!(castle_for_move(Color::White, step), None);
+    }
+
+    #[test]
+    fn can_castle_needs_empty_path_and_safe_squares() {
+        let tables = crate::moves::magic::loader::load_magic_tables();
+        let blocked = Board::new();
+        assert!(!blocked.can_castle_kingside(&tables));
+        assert!(!blocked.can_castle_queenside(&tables));
+
+        let open: Board = "r3k2r/pppppppp/8/8/8/8/PPPPPPPP/R3K2R w KQkq - 0 1"
+            .parse()
+            .unwrap();
+        assert!(open.can_castle_kingside(&tables));
+        assert!(open.can_castle_queenside(&tables));
+
+        // Black rook on f8 covers f1 through the open f-file; b1 may be attacked
+        let attacked: Board = "1r2kr2/8/8/8/8/8/8/R3K2R w KQ - 0 1".parse().unwrap();
+        assert!(!attacked.can_castle_kingside(&tables));
+        assert!(attacked.can_castle_queenside(&tables));
+
+        let no_rights: Board = "r3k2r/8/8/8/8/8/8/R3K2R b - - 0 1".parse().unwrap();
+        assert!(!no_rights.can_castle_kingside(&tables));
+        assert!(!no_rights.can_castle_queenside(&tables));
+    }
+}
@@ -1,34 +1,13 @@
-use crate::board::castle_bits::*;
 use crate::board::{Board, Color, EMPTY_SQ, Piece};
 use crate::hash::zobrist::{ep_file_to_hash, xor_castling_rights_delta, zobrist_keys};
+use crate::moves::castle::{castle_for_move, color_rights, rook_rights};
 use crate::moves::magic::MagicTables;
-use crate::moves::movegen::generate_pseudo_legal;
-use crate::moves::square_control::{in_check, is_legal_castling};
+use crate::moves::movegen::{generate_pseudo_legal, generate_pseudo_legal_evasions};
+use crate::moves::pin::pinned_pieces;
+use crate::moves::square_control::{attacked_squares, checkers, in_check, is_legal_castling};
 use crate::moves::types::{Move, MoveBuffer, NullMoveUndo, Undo};
 use crate::square::Square;
-
-/// Precomputed castling rook moves by king destination index.
-#[inline(always)]
-fn rook_castle_squares(king_to_idx: u8) -> Option<(Square, Square)> {
-    match king_to_idx {
-        6 => Some((Square::from_index(7), Square::from_index(5))), // White O-O
-        2 => Some((Square::from_index(0), Square::from_index(3))), // White O-O-O
-        62 => Some((Square::from_index(63), Square::from_index(61))), // Black O-O
-        58 => Some((Square::from_index(56), Square::from_index(59))), // Black O-O-O
-        _ => None,
-    }
-}
-
-#[inline(always)]
-fn rights_mask_to_clear_for_rook(color: Color, rook_sq: u8) -> u8 {
-    match (color, rook_sq) {
-        (Color::White, 0) => CASTLE_WQ,  // a1
-        (Color::White, 7) => CASTLE_WK,  // h1
-        (Color::Black, 56) => CASTLE_BQ, // a8
-        (Color::Black, 63) => CASTLE_BK, // h8
-        _ => 0,
-    }
-}
+use arrayvec::ArrayVec;
 
 /// Helper: clear a piece bit and table entry at `idx`.
 #[inline(always)]
@@ -106,13 +85,7 @@ pub fn make_move_basic(board: &mut Board, mv: Move) -> Undo {
 
     let old_rights = board.castling_rights;
 
-    if mv.is_castling() {
-        if let Some((rf, rt)) = rook_castle_squares(to_idx as u8) {
-            undo.castling_rook = Some((rf, rt));
-        }
-    } else {
-        undo.castling_rook = None;
-    }
+    undo.castling_rook = castle_for_move(color, mv).map(|c| (c.rook_from, c.rook_to));
 
     if piece == Piece::Pawn {
         let from_rank = from_idx / 8;
@@ -146,22 +119,19 @@ pub fn make_move_basic(board: &mut Board, mv: Move) -> Undo {
 
     // (i) King moved → clear both for that color
     if piece == Piece::King {
-        mask_to_clear |= match color {
-            Color::White => CASTLE_WK | CASTLE_WQ,
-            Color::Black => CASTLE_BK | CASTLE_BQ,
-        };
+        mask_to_clear |= color_rights(color);
     }
 
     // (ii) Rook moved from a corner → clear that side's right
     if piece == Piece::Rook {
-        mask_to_clear |= rights_mask_to_clear_for_rook(color, mv.from.index());
+        mask_to_clear |= rook_rights(color, mv.from.index());
     }
 
     // (iii) Captured a rook on its original corner → clear that side's right
     if let Some((cap_color, cap_piece, cap_sq)) = capture
         && cap_piece == Piece::Rook
     {
-        mask_to_clear |= rights_mask_to_clear_for_rook(cap_color, cap_sq.index());
+        mask_to_clear |= rook_rights(cap_color, cap_sq.index());
     }
 
     // Apply rights change ONCE and update hash via delta
@@ -176,6 +146,11 @@ pub fn make_move_basic(board: &mut Board, mv: Move) -> Undo {
 
     if let Some(prom) = mv.promotion {
         debug_assert!(piece == Piece::Pawn, "Only pawns can promote");
+        debug_assert!(
+            Piece::promotable().contains(&prom),
+            "Illegal promotion target: {:?}",
+            prom
+        );
         place_piece(board, color, prom, to_idx);
         undo.promotion = Some(prom);
     } else {
@@ -223,7 +198,7 @@ pub fn make_move_basic(board: &mut Board, mv: Move) -> Undo {
 
     // If irreversible, we logically "reset" the history.
     if irreversible {
-        board.history.clear();
+        board.truncate_history_at_irreversible();
     }
 
     // Always push the PRE-MOVE (start_zobrist) key into history
@@ -266,6 +241,8 @@ pub fn make_move_basic(board: &mut Board, mv: Move) -> Undo {
 
     #[cfg(debug_assertions)]
     board.assert_hash();
+    #[cfg(all(debug_assertions, feature = "incremental_eval"))]
+    board.assert_eval();
 
     undo
 }
@@ -344,28 +321,22 @@ pub fn undo_move_basic(board: &mut Board, undo: Undo) {
 
     #[cfg(debug_assertions)]
     board.assert_hash();
+    #[cfg(all(debug_assertions, feature = "incremental_eval"))]
+    board.assert_eval();
 }
 
 pub fn make_null_move(board: &mut Board) -> NullMoveUndo {
     // Push current hash before null move
-    board.history.push(board.zobrist);
+    board.push_to_history();
     let undo = NullMoveUndo {
         prev_en_passant: board.en_passant,
         prev_halfmove_clock: board.halfmove_clock,
         prev_side: board.side_to_move,
+        prev_zobrist: board.zobrist,
     };
 
-    // If an EP file was in the hash, XOR it OUT now
-    if let Some(f) = ep_file_to_hash(board) {
-        board.zobrist ^= zobrist_keys().ep_file[f as usize];
-    }
-
-    board.en_passant = None;
-
-    // Switch side
-    let color = board.side_to_move;
-    board.side_to_move = color.opposite();
-    board.zobrist ^= zobrist_keys().side_to_move;
+    // Clears EP (and its hash key) and switches side
+    board.flip_side_to_move();
 
     // Although it's a null move, we might theoretically increase halfmove clock?
     // Stockfish does NOT increase halfmove clock for null move in search, usually,
@@ -381,28 +352,165 @@ pub fn make_null_move(board: &mut Board) -> NullMoveUndo {
 }
 
 pub fn undo_null_move(board: &mut Board, undo: NullMoveUndo) {
-    // Restore side
     board.side_to_move = undo.prev_side;
-    board.zobrist ^= zobrist_keys().side_to_move;
-
-    // Restore EP
     board.en_passant = undo.prev_en_passant;
-    if let Some(f) = ep_file_to_hash(board) {
-        board.zobrist ^= zobrist_keys().ep_file[f as usize];
-    }
-
-    // Restore clock
     board.halfmove_clock = undo.prev_halfmove_clock;
+    board.zobrist = undo.prev_zobrist;
 
     // Pop the hash we pushed
     board.history.pop();
 }
 
+impl Board {
+    /// Pass the move to the opponent (null-move pruning). Must not be called
+    /// while in check; debug builds assert this.
+    pub fn apply_null_move(&mut self, tables: &MagicTables) -> NullMoveUndo {
+        debug_assert!(
+            !in_check(self, self.side_to_move, tables),
+            "null move while in check: {}",
+            self.to_fen()
+        );
+        make_null_move(self)
+    }
+
+    /// Reverse `apply_null_move`.
+    pub fn undo_null_move(&mut self, undo: NullMoveUndo) {
+        undo_null_move(self, undo);
+    }
+
+    /// True if `mv` is legal here. Plays and takes back the move to test it,
+    /// so the board is left exactly as it was.
+    pub fn try_move(&mut self, mv: Move, tables: &MagicTables) -> bool {
+        is_pseudo_legal(self, mv, tables) && is_legal_move(self, mv, tables)
+    }
+
+    /// `try_move` on a copy of the board, for callers that only hold `&Board`.
+    pub fn is_legal_move_for(&self, mv: Move, tables: &MagicTables) -> bool {
+        self.clone().try_move(mv, tables)
+    }
+
+    /// True if the side to move has at least one legal move. Uses the same
+    /// checks as `generate_legal_optimized` but stops at the first legal move
+    /// instead of filtering the whole list.
+    pub fn legal_move_exists(&mut self, tables: &MagicTables) -> bool {
+        let mover = self.side_to_move;
+        let checkers = checkers(self, mover, tables);
+
+        let mut moves: ArrayVec<Move, 256> = ArrayVec::new();
+        if checkers == 0 {
+            generate_pseudo_legal(self, tables, &mut moves);
+        } else {
+            generate_pseudo_legal_evasions(self, tables, checkers, &mut moves);
+        }
+
+        let (danger, pinned) = legality_masks(self, mover, checkers, tables);
+        moves
+            .into_iter()
+            .any(|mv| is_generated_move_legal(self, mv, tables, danger, pinned))
+    }
+
+    /// True when passing is reasonable: not in check, not a zugzwang
+    /// candidate and the fifty-move rule is not about to end the game.
+    pub fn can_null_move(&self, tables: &MagicTables) -> bool {
+        self.halfmove_clock < 99
+            && !self.is_zugzwang_candidate()
+            && !in_check(self, self.side_to_move, tables)
+    }
+}
+
+/// Maximum search depth in plies; sizes per-ply search buffers.
+pub const MAX_PLY: usize = 64;
+
+/// A stack of `Undo` records, so callers don't have to keep each one in a
+/// local and hand it back to `undo_move_basic` in the right order.
+#[derive(Debug, Clone, Default)]
+pub struct UndoStack {
+    stack: Vec<Undo>,
+}
+
+impl UndoStack {
+    /// Empty stack pre-allocated for `MAX_PLY` entries.
+    pub fn new() -> Self {
+        Self {
+            stack: Vec::with_capacity(MAX_PLY),
+        }
+    }
+
+    pub fn push(&mut self, undo: Undo) {
+        self.stack.push(undo);
+    }
+
+    pub fn pop(&mut self) -> Option<Undo> {
+        self.stack.pop()
+    }
+
+    pub fn peek(&self) -> Option<&Undo> {
+        self.stack.last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Make a pseudo-legal move and record its undo.
+    ///
+    /// Returns false, leaving the board and stack untouched, if the move would
+    /// leave the mover in check (or castles through check).
+    pub fn make(&mut self, board: &mut Board, tables: &MagicTables, mv: Move) -> bool {
+        if mv.is_castling() && !is_legal_castling(board, mv, tables) {
+            return false;
+        }
+        let mover = board.side_to_move;
+        let undo = make_move_basic(board, mv);
+        if in_check(board, mover, tables) {
+            undo_move_basic(board, undo);
+            return false;
+        }
+        self.stack.push(undo);
+        true
+    }
+
+    /// Take back the most recent move made through this stack.
+    /// Returns false if the stack is empty.
+    pub fn undo(&mut self, board: &mut Board) -> bool {
+        match self.stack.pop() {
+            Some(undo) => {
+                undo_move_basic(board, undo);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Use the checkers/pins generator in `generate_legal`. The make/undo filter
+/// stays available as a reference for perft comparisons.
+pub const OPTIMIZED_LEGAL_GEN: bool = true;
+
 pub fn generate_legal(
     board: &mut Board,
     tables: &MagicTables,
     moves: &mut impl MoveBuffer,
     scratch: &mut impl MoveBuffer,
+) {
+    if OPTIMIZED_LEGAL_GEN {
+        generate_legal_optimized(board, tables, moves, scratch);
+    } else {
+        generate_legal_make_undo(board, tables, moves, scratch);
+    }
+}
+
+/// Legal moves by playing every pseudo-legal move and keeping those that
+/// don't leave the king in check.
+pub fn generate_legal_make_undo(
+    board: &mut Board,
+    tables: &MagicTables,
+    moves: &mut impl MoveBuffer,
+    scratch: &mut impl MoveBuffer,
 ) {
     scratch.clear();
     generate_pseudo_legal(board, tables, scratch);
@@ -412,6 +520,11 @@ pub fn generate_legal(
         if mv.is_castling() && !is_legal_castling(board, mv, tables) {
             continue;
         }
+        // generate_king_moves already excludes attacked squares
+        if mv.piece == Piece::King && !mv.is_castling() {
+            moves.push(mv);
+            continue;
+        }
         let mover = board.side_to_move;
         let undo = make_move_basic(board, mv);
         let illegal = in_check(board, mover, tables);
@@ -422,6 +535,233 @@ pub fn generate_legal(
     }
 }
 
+/// Legal moves from the checkers and pinned pieces, computed once.
+///
+/// In check only evasions are generated (king moves alone in double check).
+/// A non-king move is then legal iff it stays on its pin ray; only en
+/// passant, which takes two pawns off the same rank, is still verified by
+/// make/undo.
+pub fn generate_legal_optimized(
+    board: &mut Board,
+    tables: &MagicTables,
+    moves: &mut impl MoveBuffer,
+    scratch: &mut impl MoveBuffer,
+) {
+    let mover = board.side_to_move;
+    let checkers = checkers(board, mover, tables);
+
+    scratch.clear();
+    if checkers == 0 {
+        generate_pseudo_legal(board, tables, scratch);
+    } else {
+        generate_pseudo_legal_evasions(board, tables, checkers, scratch);
+    }
+    moves.clear();
+
+    let (danger, pinned) = legality_masks(board, mover, checkers, tables);
+    for mv in scratch.iter().copied() {
+        if is_generated_move_legal(board, mv, tables, danger, pinned) {
+            moves.push(mv);
+        }
+    }
+}
+
+/// King danger squares (only needed in check) and pinned pieces for
+/// `is_generated_move_legal`.
+#[inline]
+fn legality_masks(board: &Board, mover: Color, checkers: u64, tables: &MagicTables) -> (u64, u64) {
+    // Evasion king steps are unfiltered; look through the king so it can't
+    // step back along the checking ray
+    let danger = if checkers == 0 {
+        0
+    } else {
+        let king = board.pieces(Piece::King, mover);
+        attacked_squares(board, mover.opposite(), board.occupied() & !king, tables)
+    };
+    (danger, pinned_pieces(board, mover, tables))
+}
+
+/// Legality of a move from `generate_pseudo_legal` (or, in check, from
+/// `generate_pseudo_legal_evasions`). Only en passant falls back to make/undo.
+#[inline]
+fn is_generated_move_legal(
+    board: &mut Board,
+    mv: Move,
+    tables: &MagicTables,
+    danger: u64,
+    pinned: u64,
+) -> bool {
+    if mv.is_castling() {
+        return is_legal_castling(board, mv, tables);
+    }
+    if mv.piece == Piece::King {
+        return danger & (1u64 << mv.to.index()) == 0;
+    }
+    if pinned & (1u64 << mv.from.index()) != 0
+        && board.pin_mask(mv.from, tables) & (1u64 << mv.to.index()) == 0
+    {
+        return false;
+    }
+    if mv.is_en_passant() {
+        let mover = board.side_to_move;
+        let undo = make_move_basic(board, mv);
+        let illegal = in_check(board, mover, tables);
+        undo_move_basic(board, undo);
+        return !illegal;
+    }
+    true
+}
+
+/// Check if a move is pseudo-legal (valid move for the current position).
+/// This validates that the move could have been generated by the move generator.
+pub(crate) fn is_pseudo_legal(board: &Board, mv: Move, tables: &MagicTables) -> bool {
+    use crate::moves::king::KING_ATTACKS;
+    use crate::moves::knight::KNIGHT_ATTACKS;
+    use crate::moves::pawn::{BLACK_PAWN_ATTACKS, WHITE_PAWN_ATTACKS};
+
+    let color = board.side_to_move;
+    let from_idx = mv.from.index() as usize;
+    let to_idx = mv.to.index() as usize;
+    let from_bb = 1u64 << from_idx;
+    let to_bb = 1u64 << to_idx;
+
+    // Check that the piece exists on the from square
+    if board.pieces(mv.piece, color) & from_bb == 0 {
+        return false;
+    }
+
+    // Check that the destination isn't occupied by a friendly piece
+    let friendly = board.occupancy(color);
+    if friendly & to_bb != 0 {
+        return false;
+    }
+
+    // For captures, check there's an enemy piece (or it's en passant)
+    let enemy = board.opponent_occupancy(color);
+    if mv.is_capture() && !mv.is_en_passant() && enemy & to_bb == 0 {
+        return false;
+    }
+    // ...and that a non-capture doesn't land on one
+    if !mv.is_capture() && enemy & to_bb != 0 {
+        return false;
+    }
+
+    // Don't allow capturing the king
+    let enemy_king = board.pieces(Piece::King, color.opposite());
+    if to_bb & enemy_king != 0 {
+        return false;
+    }
+
+    // Piece-specific validation
+    match mv.piece {
+        Piece::Pawn => {
+            let pawn_attacks = match color {
+                Color::White => WHITE_PAWN_ATTACKS[from_idx],
+                Color::Black => BLACK_PAWN_ATTACKS[from_idx],
+            };
+
+            if mv.is_en_passant() {
+                // En passant must target the EP square and be a pawn attack
+                if let Some(ep_sq) = board.en_passant {
+                    if to_idx != ep_sq.index() as usize {
+                        return false;
+                    }
+                    if pawn_attacks & to_bb == 0 {
+                        return false;
+                    }
+                } else {
+                    return false;
+                }
+            } else if mv.is_capture() {
+                // Normal capture - must be a pawn attack
+                if pawn_attacks & to_bb == 0 {
+                    return false;
+                }
+            } else {
+                // Pawn push - check direction and blockers
+                let empty = !board.occupied();
+                let (push_delta, double_rank, double_delta): (i32, u64, i32) = match color {
+                    Color::White => (8, 0x0000_0000_0000_FF00, 16),
+                    Color::Black => (-8, 0x00FF_0000_0000_0000, -16),
+                };
+
+                if mv.is_double_pawn_push() {
+                    // Double push from starting rank
+                    if from_bb & double_rank == 0 {
+                        return false;
+                    }
+                    let expected_to = (from_idx as i32 + double_delta) as usize;
+                    if to_idx != expected_to {
+                        return false;
+                    }
+                    // Check both squares are empty
+                    let middle = (from_idx as i32 + push_delta) as usize;
+                    if empty & (1u64 << middle) == 0 || empty & to_bb == 0 {
+                        return false;
+                    }
+                } else {
+                    // Single push
+                    let expected_to = (from_idx as i32 + push_delta) as usize;
+                    if to_idx != expected_to {
+                        return false;
+                    }
+                    if empty & to_bb == 0 {
+                        return false;
+                    }
+                }
+            }
+
+            // Promotion validation
+            if mv.is_promotion() {
+                let promo_rank = match color {
+                    Color::White => 7,
+                    Color::Black => 0,
+                };
+                if to_idx / 8 != promo_rank {
+                    return false;
+                }
+            }
+        }
+        Piece::Knight => {
+            if KNIGHT_ATTACKS[from_idx] & to_bb == 0 {
+                return false;
+            }
+        }
+        Piece::King => {
+            if mv.is_castling() {
+                // Castling validation - rights, path and check
+                let can_castle = if mv.is_kingside_castle() {
+                    board.can_castle_kingside(tables)
+                } else {
+                    board.can_castle_queenside(tables)
+                };
+                if !can_castle || castle_for_move(color, mv).is_none_or(|c| c.king_from != mv.from)
+                {
+                    return false;
+                }
+            } else if KING_ATTACKS[from_idx] & to_bb == 0 {
+                return false;
+            }
+        }
+        // Sliders: bishop, rook, queen
+        _ => {
+            let occ = board.occupied();
+            let mut attacks = 0;
+            if mv.piece.diagonal_slider() {
+                attacks |= tables.bishop.get_attacks(from_idx, occ);
+            }
+            if mv.piece.orthogonal_slider() {
+                attacks |= tables.rook.get_attacks(from_idx, occ);
+            }
+            if attacks & to_bb == 0 {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
 /// Check if a pseudo-legal move is legal (doesn't leave own king in check).
 /// For castling moves, this also checks that the king doesn't pass through check.
 #[inline]
@@ -451,7 +791,7 @@ pub fn generate_captures(
     moves.clear();
     for &mv in scratch.iter() {
         // Only consider captures or promotions first
-        if !mv.is_capture() && !mv.is_promotion() {
+        if mv.is_quiet() {
             continue;
         }
 
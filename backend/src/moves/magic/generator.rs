@@ -0,0 +1,74 @@
+//! Find magic numbers from scratch, one square at a time (`magic_gen` feature).
+//!
+//! `precompute` builds whole tables; this module only returns the numbers, for
+//! callers that want to inspect them or build their own layout.
+
+use super::attacks::{get_bishop_attack_bitboards, get_rook_attack_bitboards};
+use super::masks::{
+    bishop_vision_mask, generate_bishop_blockers, generate_rook_blockers, rook_vision_mask,
+};
+use super::search::find_magic_number_for_square;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// A magic number for a rook (or bishop) on `sq`, and the shift to apply
+/// after multiplying: `index = ((occupancy & mask) * magic) >> shift`.
+pub fn generate_magic(sq: usize, is_rook: bool) -> (u64, u32) {
+    let mut rng = StdRng::seed_from_u64(rand::rng().next_u64());
+    generate_magic_with_rng(sq, is_rook, &mut rng)
+}
+
+fn generate_magic_with_rng<R: RngCore>(sq: usize, is_rook: bool, rng: &mut R) -> (u64, u32) {
+    let (mask, blockers, attacks) = if is_rook {
+        let blockers = generate_rook_blockers(sq);
+        let attacks = get_rook_attack_bitboards(sq, &blockers);
+        (rook_vision_mask(sq), blockers, attacks)
+    } else {
+        let blockers = generate_bishop_blockers(sq);
+        let attacks = get_bishop_attack_bitboards(sq, &blockers);
+        (bishop_vision_mask(sq), blockers, attacks)
+    };
+    let shift = 64 - mask.count_ones();
+    let magic = find_magic_number_for_square(&blockers, &attacks, shift, rng)
+        .unwrap_or_else(|e| panic!("square {}: {}", sq, e));
+    (magic, shift)
+}
+
+/// Magic numbers for every square: (rook, bishop), indexed by square.
+pub fn generate_all_magics() -> (Vec<u64>, Vec<u64>) {
+    let mut rng = StdRng::seed_from_u64(rand::rng().next_u64());
+    let rook = (0..64)
+        .map(|sq| generate_magic_with_rng(sq, true, &mut rng).0)
+        .collect();
+    let bishop = (0..64)
+        .map(|sq| generate_magic_with_rng(sq, false, &mut rng).0)
+        .collect();
+    (rook, bishop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::moves::magic::attacks::rook_attacks_per_square;
+
+    #[test]
+    fn test_generated_rook_magic_a1() {
+        let (magic, shift) = generate_magic(0, true);
+        let mask = rook_vision_mask(0);
+        assert_eq!(shift, 64 - mask.count_ones());
+
+        let mut table = vec![0u64; 1 << mask.count_ones()];
+        for blockers in generate_rook_blockers(0) {
+            let idx = (blockers.wrapping_mul(magic) >> shift) as usize;
+            table[idx] = rook_attacks_per_square(0, blockers);
+        }
+
+        // Full-board occupancies, including bits outside the mask
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..12 {
+            let occupancy = rng.next_u64() & rng.next_u64();
+            let idx = ((occupancy & mask).wrapping_mul(magic) >> shift) as usize;
+            assert_eq!(table[idx], rook_attacks_per_square(0, occupancy));
+        }
+    }
+}
@@ -86,3 +86,54 @@ pub fn load_magic_tables() -> MagicTables {
     generate_magic_tables(MagicTableSeed::Randomized)
         .expect("Failed to generate randomized magic tables")
 }
+
+//////////////////////////////////////////////////////////////////////////////
+// Non-blocking wrappers (native only; WASM uses `wasm_init_async`)
+//////////////////////////////////////////////////////////////////////////////
+
+/// Build the tables on a background thread, e.g. while a UI starts up.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_magic_tables_threaded() -> std::thread::JoinHandle<MagicTables> {
+    std::thread::spawn(|| {
+        let tables = load_magic_tables();
+        tables.precompute_all_attacks();
+        tables
+    })
+}
+
+/// Future that resolves once `load_magic_tables_threaded` finishes, so async
+/// callers never block their executor on table generation.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_magic_tables_async() -> impl std::future::Future<Output = MagicTables> {
+    use std::sync::{Arc, Mutex};
+    use std::task::{Poll, Waker};
+
+    #[derive(Default)]
+    struct Shared {
+        tables: Option<MagicTables>,
+        waker: Option<Waker>,
+    }
+
+    let shared = Arc::new(Mutex::new(Shared::default()));
+    let worker = Arc::clone(&shared);
+    std::thread::spawn(move || {
+        let tables = load_magic_tables();
+        tables.precompute_all_attacks();
+        let mut state = worker.lock().unwrap();
+        state.tables = Some(tables);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    });
+
+    std::future::poll_fn(move |cx| {
+        let mut state = shared.lock().unwrap();
+        match state.tables.take() {
+            Some(tables) => Poll::Ready(tables),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    })
+}
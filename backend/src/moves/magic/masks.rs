@@ -114,13 +114,13 @@ pub fn generate_bishop_blockers(square: usize) -> Vec<u64> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::bitboard_to_string;
+    use crate::bitboard::to_bitboard_string;
 
     #[test]
     fn test_rook_vision_d4() {
         let idx = 3 * 8 + 3; // d4
         let mask = rook_vision_mask(idx);
-        println!("Rook vision mask for d4:\n{}", bitboard_to_string(mask));
+        println!("Rook vision mask for d4:\n{}", to_bitboard_string(mask));
         assert_eq!(mask.count_ones(), 10);
     }
 
@@ -128,7 +128,7 @@ mod tests {
     fn test_bishop_vision_d4() {
         let idx = 3 * 8 + 3; // d4
         let mask = bishop_vision_mask(idx);
-        println!("Bishop vision mask for d4:\n{}", bitboard_to_string(mask));
+        println!("Bishop vision mask for d4:\n{}", to_bitboard_string(mask));
         assert_eq!(mask.count_ones(), 9);
     }
 
@@ -136,7 +136,7 @@ mod tests {
     fn test_rook_vision_a1() {
         let idx = 0; // a1
         let mask = rook_vision_mask(idx);
-        println!("Rook vision mask for a1:\n{}", bitboard_to_string(mask));
+        println!("Rook vision mask for a1:\n{}", to_bitboard_string(mask));
         assert_eq!(mask.count_ones(), 12);
     }
 
@@ -144,7 +144,7 @@ mod tests {
     fn test_bishop_vision_h8() {
         let idx = 63; // h8
         let mask = bishop_vision_mask(idx);
-        println!("Bishop vision mask for h8:\n{}", bitboard_to_string(mask));
+        println!("Bishop vision mask for h8:\n{}", to_bitboard_string(mask));
         assert_eq!(mask.count_ones(), 6);
     }
 }
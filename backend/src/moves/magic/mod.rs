@@ -1,4 +1,6 @@
 pub mod attacks;
+#[cfg(feature = "magic_gen")]
+pub mod generator;
 pub mod loader;
 pub mod masks;
 pub mod precompute;
@@ -181,8 +181,9 @@ pub fn generate_magic_tables(seed_mode: MagicTableSeed) -> Result<MagicTables, S
 #[cfg(all(test, not(feature = "load_magic")))]
 mod tests {
     use super::*;
+    use crate::bitboard::to_bitboard_string;
     use crate::moves::magic::masks::{bishop_vision_mask, rook_vision_mask};
-    use crate::utils::{bitboard_to_string, square_index};
+    use crate::utils::square_index;
 
     /// Same seed for every run so CI is repeatable
     const TEST_SEED: u64 = 0b100_0101;
@@ -218,8 +219,8 @@ mod tests {
             actual,
             expected,
             "Incorrect rook attack for e4 (no blockers)\nExpected:\n{}\nGot:\n{}",
-            bitboard_to_string(expected),
-            bitboard_to_string(actual)
+            to_bitboard_string(expected),
+            to_bitboard_string(actual)
         );
         Ok(())
     }
@@ -247,8 +248,8 @@ mod tests {
             actual,
             expected,
             "Incorrect bishop attack for c1 with blocker on e3\nExpected:\n{}\nGot:\n{}",
-            bitboard_to_string(expected),
-            bitboard_to_string(actual)
+            to_bitboard_string(expected),
+            to_bitboard_string(actual)
         );
         Ok(())
     }
@@ -78,6 +78,31 @@ impl MagicTables {
         let bishop = self.bishop.get_attacks(square, blockers);
         rook | bishop
     }
+
+    /// Look up every square's empty-board rook and bishop attacks once.
+    ///
+    /// Touching each square's table right after loading pays the page-in cost
+    /// up front (noticeable in WASM) instead of during the first search.
+    pub fn precompute_all_attacks(&self) -> AttackCache {
+        AttackCache {
+            rook: std::array::from_fn(|sq| self.rook.get_attacks(sq, 0)),
+            bishop: std::array::from_fn(|sq| self.bishop.get_attacks(sq, 0)),
+        }
+    }
+}
+
+/// Empty-board slider attacks for all 64 squares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttackCache {
+    pub rook: [u64; 64],
+    pub bishop: [u64; 64],
+}
+
+impl AttackCache {
+    #[inline(always)]
+    pub fn queen(&self, square: usize) -> u64 {
+        self.rook[square] | self.bishop[square]
+    }
 }
 
 // These tests generate magic tables from scratch, so skip when using pre-loaded tables
@@ -1,3 +1,4 @@
+pub mod castle;
 pub mod execute;
 pub mod king;
 pub mod knight;
@@ -5,5 +6,7 @@ pub mod magic;
 pub mod movegen;
 pub mod pawn;
 pub mod perft;
+pub mod pin;
+pub mod san;
 pub mod square_control;
 pub mod types;
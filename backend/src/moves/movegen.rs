@@ -1,31 +1,23 @@
 use crate::board::{Board, Color, Piece};
+use crate::moves::castle::castle_config;
 use crate::moves::king::KING_ATTACKS;
 use crate::moves::knight::KNIGHT_ATTACKS;
 use crate::moves::magic::MagicTables;
 use crate::moves::magic::structs::{BishopMagicTables, RookMagicTables};
 use crate::moves::pawn::{BLACK_PAWN_ATTACKS, WHITE_PAWN_ATTACKS};
-use crate::moves::square_control::is_legal_castling;
+use crate::moves::square_control::attacked_squares;
 use crate::moves::types::{
-    CAPTURE, DOUBLE_PAWN_PUSH, EN_PASSANT, KINGSIDE_CASTLE, Move, MoveBuffer, PROMOTION,
-    PROMOTION_CAPTURE, QUEENSIDE_CASTLE, QUIET_MOVE,
+    CAPTURE, DOUBLE_PAWN_PUSH, EN_PASSANT, Move, MoveBuffer, PROMOTION, PROMOTION_CAPTURE,
+    QUIET_MOVE,
 };
 use crate::square::Square;
 use crate::utils::pop_lsb;
 
 // Predefined Rank Constants
-const RANK1: u64 = 0x0000_0000_0000_00FF;
-const RANK2: u64 = 0x0000_0000_0000_FF00;
-const RANK7: u64 = 0x00FF_0000_0000_0000;
-const RANK8: u64 = 0xFF00_0000_0000_0000;
-
-// Castling Constants
-const WHITE_KINGSIDE_BETWEEN: u64 = 0x0000_0000_0000_0060;
-const WHITE_QUEENSIDE_BETWEEN: u64 = 0x0000_0000_0000_000E;
-const BLACK_KINGSIDE_BETWEEN: u64 = 0x6000_0000_0000_0000;
-const BLACK_QUEENSIDE_BETWEEN: u64 = 0x0E00_0000_0000_0000;
-
-// Promotion Array
-const PROMOS: [Piece; 4] = [Piece::Queen, Piece::Rook, Piece::Bishop, Piece::Knight];
+pub(crate) const RANK1: u64 = 0x0000_0000_0000_00FF;
+pub(crate) const RANK2: u64 = 0x0000_0000_0000_FF00;
+pub(crate) const RANK7: u64 = 0x00FF_0000_0000_0000;
+pub(crate) const RANK8: u64 = 0xFF00_0000_0000_0000;
 
 /// Helper functionality to push latest found move
 #[inline(always)]
@@ -49,24 +41,6 @@ fn push_piece_moves(
     }
 }
 
-/// Function to determine which squares to check for kingside castling
-#[inline(always)]
-fn kingside_between(color: Color) -> u64 {
-    match color {
-        Color::White => WHITE_KINGSIDE_BETWEEN,
-        Color::Black => BLACK_KINGSIDE_BETWEEN,
-    }
-}
-
-/// Function to determine which squares to check for queenside castling
-#[inline(always)]
-fn queenside_between(color: Color) -> u64 {
-    match color {
-        Color::White => WHITE_QUEENSIDE_BETWEEN,
-        Color::Black => BLACK_QUEENSIDE_BETWEEN,
-    }
-}
-
 pub fn generate_knight_moves(board: &Board, move_list: &mut impl MoveBuffer) {
     let color = board.side_to_move;
     let knights = board.pieces(Piece::Knight, color);
@@ -154,35 +128,19 @@ pub fn generate_king_moves(board: &Board, tables: &MagicTables, move_list: &mut
     let enemy_king = board.pieces(Piece::King, color.opposite());
     let enemy = board.opponent_occupancy(color);
 
-    let targets = KING_ATTACKS[from as usize] & !friendly & !enemy_king;
-    push_piece_moves(from, targets, enemy, Piece::King, move_list);
-
     let occ = board.occupied();
 
-    // King-side castle
-    if board.has_kingside_castle(color) && (occ & kingside_between(color)) == 0 {
-        let mv = Move {
-            from: Square::from_index(from),
-            to: Square::from_index(from + 2), // g-file
-            piece: Piece::King,
-            promotion: None,
-            flags: KINGSIDE_CASTLE,
-        };
+    // Only squares the enemy doesn't attack (looking through our own king),
+    // so every non-castling king move generated here is legal
+    let danger = attacked_squares(board, color.opposite(), occ & !king_bb, tables);
+    let targets = KING_ATTACKS[from as usize] & !friendly & !enemy_king & !danger;
+    push_piece_moves(from, targets, enemy, Piece::King, move_list);
 
-        if is_legal_castling(board, mv, tables) {
-            move_list.push(mv);
-        }
+    if board.can_castle_kingside(tables) {
+        move_list.push(castle_config(color, true).to_move());
     }
-
-    // Queen-side castle
-    if board.has_queenside_castle(color) && (occ & queenside_between(color)) == 0 {
-        move_list.push(Move {
-            from: Square::from_index(from),
-            to: Square::from_index(from - 2), // c-file
-            piece: Piece::King,
-            promotion: None,
-            flags: QUEENSIDE_CASTLE,
-        });
+    if board.can_castle_queenside(tables) {
+        move_list.push(castle_config(color, false).to_move());
     }
 }
 
@@ -285,7 +243,7 @@ pub fn generate_pawn_moves(board: &Board, move_list: &mut impl MoveBuffer) {
     while bb != 0 {
         let to = pop_lsb(&mut bb);
         let from = if push_up > 0 { to - shift } else { to + shift };
-        for &promo in PROMOS.iter() {
+        for &promo in Piece::promotable() {
             move_list.push(Move {
                 from: Square::from_index(from),
                 to: Square::from_index(to),
@@ -304,7 +262,7 @@ pub fn generate_pawn_moves(board: &Board, move_list: &mut impl MoveBuffer) {
         let mut t = targets;
         while t != 0 {
             let to = pop_lsb(&mut t);
-            for &promo in PROMOS.iter() {
+            for &promo in Piece::promotable() {
                 move_list.push(Move {
                     from: Square::from_index(from),
                     to: Square::from_index(to),
@@ -575,31 +533,11 @@ fn generate_king_quiets(board: &Board, tables: &MagicTables, move_list: &mut imp
     push_quiets_only(from, targets, empty, Piece::King, move_list);
 
     // Castling (quiet moves)
-    let occ = board.occupied();
-
-    // King-side castle
-    if board.has_kingside_castle(color) && (occ & kingside_between(color)) == 0 {
-        let mv = Move {
-            from: Square::from_index(from),
-            to: Square::from_index(from + 2),
-            piece: Piece::King,
-            promotion: None,
-            flags: KINGSIDE_CASTLE,
-        };
-        if is_legal_castling(board, mv, tables) {
-            move_list.push(mv);
-        }
+    if board.can_castle_kingside(tables) {
+        move_list.push(castle_config(color, true).to_move());
     }
-
-    // Queen-side castle
-    if board.has_queenside_castle(color) && (occ & queenside_between(color)) == 0 {
-        move_list.push(Move {
-            from: Square::from_index(from),
-            to: Square::from_index(from - 2),
-            piece: Piece::King,
-            promotion: None,
-            flags: QUEENSIDE_CASTLE,
-        });
+    if board.can_castle_queenside(tables) {
+        move_list.push(castle_config(color, false).to_move());
     }
 }
 
@@ -659,7 +597,7 @@ fn generate_pawn_captures(board: &Board, move_list: &mut impl MoveBuffer) {
         } else {
             to + shift
         };
-        for &promo in PROMOS.iter() {
+        for &promo in Piece::promotable() {
             move_list.push(Move {
                 from: Square::from_index(from),
                 to: Square::from_index(to),
@@ -678,7 +616,7 @@ fn generate_pawn_captures(board: &Board, move_list: &mut impl MoveBuffer) {
         let mut t = targets;
         while t != 0 {
             let to = pop_lsb(&mut t);
-            for &promo in PROMOS.iter() {
+            for &promo in Piece::promotable() {
                 move_list.push(Move {
                     from: Square::from_index(from),
                     to: Square::from_index(to),
@@ -808,3 +746,326 @@ pub fn generate_pseudo_legal_quiets(
     generate_queen_quiets(board, tables, moves);
     generate_king_quiets(board, tables, moves);
 }
+
+/// Squares strictly between a king and a sliding checker, i.e. where the
+/// check can be blocked. Zero for contact checks and non-sliders.
+fn block_squares(board: &Board, king_sq: u8, checker_sq: u8, tables: &MagicTables) -> u64 {
+    let ends = (1u64 << king_sq) | (1u64 << checker_sq);
+    let (k, c) = (king_sq as usize, checker_sq as usize);
+
+    let rook_line = tables.rook.get_attacks(k, ends) & tables.rook.get_attacks(c, ends);
+    let bishop_line = tables.bishop.get_attacks(k, ends) & tables.bishop.get_attacks(c, ends);
+
+    match board.piece_at(Square::from_index(checker_sq)) {
+        Some((_, Piece::Rook)) => rook_line,
+        Some((_, Piece::Bishop)) => bishop_line,
+        Some((_, Piece::Queen)) => {
+            if tables.rook.get_attacks(k, ends) & (1u64 << checker_sq) != 0 {
+                rook_line
+            } else {
+                bishop_line
+            }
+        }
+        _ => 0,
+    }
+}
+
+/// Generate pseudo-legal check evasions for the side to move, given the
+/// bitboard of pieces giving check (see `square_control::checkers`).
+///
+/// Only king moves in double check; otherwise also captures of the checker and
+/// interpositions on a slider's ray. Pins are not checked.
+pub fn generate_pseudo_legal_evasions(
+    board: &Board,
+    tables: &MagicTables,
+    checkers: u64,
+    moves: &mut impl MoveBuffer,
+) {
+    debug_assert!(checkers != 0, "evasions requested while not in check");
+
+    let color = board.side_to_move;
+    let own = board.occupancy(color);
+    let enemy = board.opponent_occupancy(color);
+    let king_sq = board.king_square(color).index();
+
+    // King steps (never castling out of check)
+    push_piece_moves(
+        king_sq,
+        KING_ATTACKS[king_sq as usize] & !own,
+        enemy,
+        Piece::King,
+        moves,
+    );
+
+    if checkers.count_ones() > 1 {
+        return;
+    }
+
+    let checker_sq = checkers.trailing_zeros() as u8;
+    let blocks = block_squares(board, king_sq, checker_sq, tables);
+    let targets = checkers | blocks;
+    let occ = board.occupied();
+
+    // Knights and sliders
+    for piece in [Piece::Knight, Piece::Bishop, Piece::Rook, Piece::Queen] {
+        let mut pieces = board.pieces(piece, color);
+        while pieces != 0 {
+            let from = pop_lsb(&mut pieces);
+            let attacks = match piece {
+                Piece::Knight => KNIGHT_ATTACKS[from as usize],
+                Piece::Bishop => tables.bishop.get_attacks(from as usize, occ),
+                Piece::Rook => tables.rook.get_attacks(from as usize, occ),
+                _ => tables.queen_attacks(from as usize, occ),
+            };
+            push_piece_moves(from, attacks & targets, enemy, piece, moves);
+        }
+    }
+
+    // Pawns
+    let pawns = board.pieces(Piece::Pawn, color);
+    let empty = !occ;
+    let (double_rank, promo_rank) = match color {
+        Color::White => (RANK2, RANK8),
+        Color::Black => (RANK7, RANK1),
+    };
+    let push = |bb: u64| match color {
+        Color::White => bb << 8,
+        Color::Black => bb >> 8,
+    };
+    let push_pawn = |from: u8, to: u8, capture: bool, moves: &mut dyn FnMut(Move)| {
+        if (1u64 << to) & promo_rank != 0 {
+            for &promo in Piece::promotable() {
+                moves(Move {
+                    from: Square::from_index(from),
+                    to: Square::from_index(to),
+                    piece: Piece::Pawn,
+                    promotion: Some(promo),
+                    flags: if capture {
+                        PROMOTION_CAPTURE
+                    } else {
+                        PROMOTION
+                    },
+                });
+            }
+        } else {
+            moves(Move {
+                from: Square::from_index(from),
+                to: Square::from_index(to),
+                piece: Piece::Pawn,
+                promotion: None,
+                flags: if capture { CAPTURE } else { QUIET_MOVE },
+            });
+        }
+    };
+    let mut emit = |mv: Move| moves.push(mv);
+
+    // Blocking pushes
+    let single = push(pawns) & empty;
+    let mut bb = single & blocks;
+    while bb != 0 {
+        let to = pop_lsb(&mut bb);
+        let from = match color {
+            Color::White => to - 8,
+            Color::Black => to + 8,
+        };
+        push_pawn(from, to, false, &mut emit);
+    }
+    let mut bb = push(push(pawns & double_rank) & empty) & empty & blocks;
+    while bb != 0 {
+        let to = pop_lsb(&mut bb);
+        let from = match color {
+            Color::White => to - 16,
+            Color::Black => to + 16,
+        };
+        emit(Move {
+            from: Square::from_index(from),
+            to: Square::from_index(to),
+            piece: Piece::Pawn,
+            promotion: None,
+            flags: DOUBLE_PAWN_PUSH,
+        });
+    }
+
+    // Capturing the checker
+    let attacks_of = |sq: u8| match color {
+        Color::White => WHITE_PAWN_ATTACKS[sq as usize],
+        Color::Black => BLACK_PAWN_ATTACKS[sq as usize],
+    };
+    let mut attackers = pawns;
+    while attackers != 0 {
+        let from = pop_lsb(&mut attackers);
+        if attacks_of(from) & checkers != 0 {
+            push_pawn(from, checker_sq, true, &mut emit);
+        }
+    }
+
+    // En passant, when it removes a checking pawn or lands on a block square
+    if let Some(ep_sq) = board.en_passant {
+        let ep = ep_sq.index();
+        let cap_sq = match color {
+            Color::White => ep - 8,
+            Color::Black => ep + 8,
+        };
+        let enemy_pawns = board.pieces(Piece::Pawn, color.opposite());
+        let removes_checker = checker_sq == cap_sq && enemy_pawns & checkers != 0;
+        if (removes_checker || blocks & (1u64 << ep) != 0) && empty & (1u64 << ep) != 0 {
+            let mut atk = pawns;
+            while atk != 0 {
+                let from = pop_lsb(&mut atk);
+                if attacks_of(from) & (1u64 << ep) != 0 {
+                    emit(Move {
+                        from: Square::from_index(from),
+                        to: Square::from_index(ep),
+                        piece: Piece::Pawn,
+                        promotion: None,
+                        flags: EN_PASSANT,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Generate pseudo-legal moves that give direct check: the moved (or
+/// promoted) piece attacks the enemy king from its destination.
+///
+/// Discovered checks and castling checks (from the rook) are not generated.
+/// Moves are appended; `moves` is not cleared.
+pub fn generate_pseudo_legal_checks(
+    board: &Board,
+    tables: &MagicTables,
+    moves: &mut impl MoveBuffer,
+) {
+    let color = board.side_to_move;
+    let own = board.occupancy(color);
+    let enemy = board.opponent_occupancy(color);
+    let occ = board.occupied();
+    let king_sq = board.king_square(color.opposite()).index() as usize;
+
+    // Squares a piece of each type would check from, seen from the king
+    let bishop_checks = tables.bishop.get_attacks(king_sq, occ);
+    let rook_checks = tables.rook.get_attacks(king_sq, occ);
+    let pawn_checks = match color {
+        Color::White => BLACK_PAWN_ATTACKS[king_sq],
+        Color::Black => WHITE_PAWN_ATTACKS[king_sq],
+    };
+
+    // Knights and sliders
+    for piece in [Piece::Knight, Piece::Bishop, Piece::Rook, Piece::Queen] {
+        let checks = match piece {
+            Piece::Knight => KNIGHT_ATTACKS[king_sq],
+            Piece::Bishop => bishop_checks,
+            Piece::Rook => rook_checks,
+            _ => bishop_checks | rook_checks,
+        };
+        let mut pieces = board.pieces(piece, color);
+        while pieces != 0 {
+            let from = pop_lsb(&mut pieces);
+            let attacks = match piece {
+                Piece::Knight => KNIGHT_ATTACKS[from as usize],
+                Piece::Bishop => tables.bishop.get_attacks(from as usize, occ),
+                Piece::Rook => tables.rook.get_attacks(from as usize, occ),
+                _ => tables.queen_attacks(from as usize, occ),
+            };
+            push_piece_moves(from, attacks & !own & checks, enemy, piece, moves);
+        }
+    }
+
+    // Pawns
+    let pawns = board.pieces(Piece::Pawn, color);
+    let empty = !occ;
+    let (double_rank, seventh_rank, promo_rank, up) = match color {
+        Color::White => (RANK2, RANK7, RANK8, 8i8),
+        Color::Black => (RANK7, RANK2, RANK1, -8i8),
+    };
+    let push = |bb: u64| match color {
+        Color::White => bb << 8,
+        Color::Black => bb >> 8,
+    };
+    let attacks_of = |sq: u8| match color {
+        Color::White => WHITE_PAWN_ATTACKS[sq as usize],
+        Color::Black => BLACK_PAWN_ATTACKS[sq as usize],
+    };
+    let mut push_pawn = |from: u8, to: u8, flags| {
+        moves.push(Move {
+            from: Square::from_index(from),
+            to: Square::from_index(to),
+            piece: Piece::Pawn,
+            promotion: None,
+            flags,
+        })
+    };
+
+    let single = push(pawns) & empty;
+    let mut bb = single & pawn_checks & !promo_rank;
+    while bb != 0 {
+        let to = pop_lsb(&mut bb);
+        push_pawn((to as i8 - up) as u8, to, QUIET_MOVE);
+    }
+    let mut bb = push(push(pawns & double_rank) & empty) & empty & pawn_checks;
+    while bb != 0 {
+        let to = pop_lsb(&mut bb);
+        push_pawn((to as i8 - 2 * up) as u8, to, DOUBLE_PAWN_PUSH);
+    }
+    let mut attackers = pawns;
+    while attackers != 0 {
+        let from = pop_lsb(&mut attackers);
+        let mut bb = attacks_of(from) & enemy & pawn_checks & !promo_rank;
+        while bb != 0 {
+            push_pawn(from, pop_lsb(&mut bb), CAPTURE);
+        }
+    }
+    if let Some(ep_sq) = board.en_passant {
+        let ep = ep_sq.index();
+        let cap_sq = (ep as i8 - up) as u8;
+        let enemy_pawns = board.pieces(Piece::Pawn, color.opposite());
+        if pawn_checks & empty & (1u64 << ep) != 0 && enemy_pawns & (1u64 << cap_sq) != 0 {
+            let mut atk = pawns;
+            while atk != 0 {
+                let from = pop_lsb(&mut atk);
+                if attacks_of(from) & (1u64 << ep) != 0 {
+                    push_pawn(from, ep, EN_PASSANT);
+                }
+            }
+        }
+    }
+
+    // Promotions: check from the promoted piece, with the pawn gone from `from`
+    let mut push_promos = |from: u8, to: u8, capture: bool| {
+        let occ_after = (occ & !(1u64 << from)) | (1u64 << to);
+        for &promo in Piece::promotable() {
+            let attacks = match promo {
+                Piece::Knight => KNIGHT_ATTACKS[to as usize],
+                Piece::Bishop => tables.bishop.get_attacks(to as usize, occ_after),
+                Piece::Rook => tables.rook.get_attacks(to as usize, occ_after),
+                _ => tables.queen_attacks(to as usize, occ_after),
+            };
+            if attacks & (1u64 << king_sq) != 0 {
+                moves.push(Move {
+                    from: Square::from_index(from),
+                    to: Square::from_index(to),
+                    piece: Piece::Pawn,
+                    promotion: Some(promo),
+                    flags: if capture {
+                        PROMOTION_CAPTURE
+                    } else {
+                        PROMOTION
+                    },
+                });
+            }
+        }
+    };
+    let mut bb = single & promo_rank;
+    while bb != 0 {
+        let to = pop_lsb(&mut bb);
+        push_promos((to as i8 - up) as u8, to, false);
+    }
+    let mut attackers = pawns & seventh_rank;
+    while attackers != 0 {
+        let from = pop_lsb(&mut attackers);
+        let mut bb = attacks_of(from) & enemy & promo_rank;
+        while bb != 0 {
+            push_promos(from, pop_lsb(&mut bb), true);
+        }
+    }
+}
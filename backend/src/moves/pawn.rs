@@ -84,6 +84,7 @@ fn pawn_attack_mask(square: u8, color: Color) -> u64 {
 mod tests {
 
     use super::{BLACK_PAWN_ATTACKS, WHITE_PAWN_ATTACKS, pawn_attack_mask};
+    use crate::bitboard::to_bitboard_string;
     use crate::board::Color;
 
     #[test]
@@ -125,20 +126,43 @@ mod tests {
     fn test_white_pawn_attack_mask_d4() {
         let d4 = 3 + 8 * 3; // index 27
         let expected = (1u64 << (4 * 8 + 2)) | (1u64 << (4 * 8 + 4)); // c5 + e5
-        assert_eq!(pawn_attack_mask(d4, Color::White), expected);
+        let actual = pawn_attack_mask(d4, Color::White);
+        assert_eq!(
+            actual,
+            expected,
+            "Expected:\n{}\nGot:\n{}",
+            to_bitboard_string(expected),
+            to_bitboard_string(actual)
+        );
     }
 
     #[test]
     fn test_white_attacks_match_reference() {
         for (square, &attack) in WHITE_PAWN_ATTACKS.iter().enumerate() {
-            assert_eq!(attack, pawn_attack_mask(square as u8, Color::White));
+            let computed = pawn_attack_mask(square as u8, Color::White);
+            assert_eq!(
+                attack,
+                computed,
+                "square {}\nTable:\n{}\nComputed:\n{}",
+                square,
+                to_bitboard_string(attack),
+                to_bitboard_string(computed)
+            );
         }
     }
 
     #[test]
     fn test_black_attacks_match_reference() {
         for (square, &attack) in BLACK_PAWN_ATTACKS.iter().enumerate() {
-            assert_eq!(attack, pawn_attack_mask(square as u8, Color::Black));
+            let computed = pawn_attack_mask(square as u8, Color::Black);
+            assert_eq!(
+                attack,
+                computed,
+                "square {}\nTable:\n{}\nComputed:\n{}",
+                square,
+                to_bitboard_string(attack),
+                to_bitboard_string(computed)
+            );
         }
     }
 
@@ -5,10 +5,11 @@ use crate::moves::{
     square_control::in_check,
     types::Move,
 };
+use std::collections::BTreeMap;
 use tracing::{debug, instrument};
 
 const MAX_LOG_DEPTH: u32 = 3; // only trace details for shallow nodes
-const MAX_PERFT_DEPTH: usize = 20; // support perft up to depth 20
+pub const MAX_PERFT_DEPTH: usize = 20; // support perft up to depth 20
 
 pub struct PerftCounters {
     pub nodes: u64,
@@ -143,6 +144,24 @@ pub fn perft(board: &mut Board, tables: &MagicTables, depth: u32) -> u64 {
 
 #[instrument(skip(board, tables), fields(depth))]
 pub fn perft_divide(board: &mut Board, tables: &MagicTables, depth: u32) -> u64 {
+    let mut total = 0;
+    for (mv, count) in perft_divide_counts(board, tables, depth) {
+        println!("{}: {}", mv, count);
+        total += count;
+    }
+
+    debug!(depth, total, "divide: total");
+    println!("Total: {}", total);
+    total
+}
+
+/// Node count below each root move, in move generation order, without printing.
+#[instrument(skip(board, tables), fields(depth))]
+pub fn perft_divide_counts(
+    board: &mut Board,
+    tables: &MagicTables,
+    depth: u32,
+) -> Vec<(Move, u64)> {
     if depth as usize > MAX_PERFT_DEPTH {
         panic!(
             "Depth {} exceeds MAX_PERFT_DEPTH {}",
@@ -166,8 +185,8 @@ pub fn perft_divide(board: &mut Board, tables: &MagicTables, depth: u32) -> u64
         }
     }
 
-    let mut total = 0;
     let move_count = move_buffers[0].len();
+    let mut counts = Vec::with_capacity(move_count);
 
     for i in 0..move_count {
         let mv = move_buffers[0][i];
@@ -206,13 +225,104 @@ pub fn perft_divide(board: &mut Board, tables: &MagicTables, depth: u32) -> u64
             debug!(%mv, nodes = count, "divide: root child total");
         }
 
-        println!("{}: {}", mv, count);
-        total += count;
+        counts.push((mv, count));
     }
 
-    debug!(depth, total, "divide: total");
-    println!("Total: {}", total);
-    total
+    counts
+}
+
+/// A root move whose perft count differs from the reference.
+///
+/// `expected == 0` means we generate a move the reference doesn't, and
+/// `got == 0` means we miss a move the reference has.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerftDiscrepancy {
+    pub move_uci: String,
+    pub expected: u64,
+    pub got: u64,
+}
+
+/// Discrepancies at one node, plus the subtree below each diverging move
+/// that both sides generate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerftDebugResult {
+    pub fen: String,
+    pub depth: u32,
+    pub discrepancies: Vec<PerftDiscrepancy>,
+    pub children: Vec<(String, PerftDebugResult)>,
+}
+
+/// Compare our perft divide at `depth` against `expected` (UCI move -> count,
+/// e.g. parsed from Stockfish's `go perft`). Results are sorted by move.
+pub fn perft_debug(
+    board: &mut Board,
+    tables: &MagicTables,
+    depth: u32,
+    expected: &BTreeMap<String, u64>,
+) -> Vec<PerftDiscrepancy> {
+    let ours: BTreeMap<String, u64> = perft_divide_counts(board, tables, depth)
+        .into_iter()
+        .map(|(mv, count)| (mv.to_uci(), count))
+        .collect();
+
+    let mut all_moves: Vec<&String> = ours.keys().chain(expected.keys()).collect();
+    all_moves.sort();
+    all_moves.dedup();
+
+    all_moves
+        .into_iter()
+        .filter_map(|uci| {
+            let got = ours.get(uci).copied().unwrap_or(0);
+            let expected = expected.get(uci).copied().unwrap_or(0);
+            (got != expected).then(|| PerftDiscrepancy {
+                move_uci: uci.clone(),
+                expected,
+                got,
+            })
+        })
+        .collect()
+}
+
+/// Walk down the tree into every diverging move until the discrepancy is
+/// pinned to a missing or extra move (or reaches depth 1).
+///
+/// `reference` returns the expected divide for a position and depth, for
+/// example by asking another engine.
+pub fn perft_debug_tree<F>(
+    board: &mut Board,
+    tables: &MagicTables,
+    depth: u32,
+    reference: &mut F,
+) -> PerftDebugResult
+where
+    F: FnMut(&Board, u32) -> BTreeMap<String, u64>,
+{
+    let expected = reference(board, depth);
+    let discrepancies = perft_debug(board, tables, depth, &expected);
+
+    let mut children = Vec::new();
+    if depth > 1 {
+        let mut moves = Vec::with_capacity(64);
+        let mut scratch = Vec::with_capacity(256);
+        generate_legal(board, tables, &mut moves, &mut scratch);
+
+        for d in discrepancies.iter().filter(|d| d.got > 0 && d.expected > 0) {
+            let Some(&mv) = moves.iter().find(|m| m.to_uci() == d.move_uci) else {
+                continue;
+            };
+            let undo = make_move_basic(board, mv);
+            let child = perft_debug_tree(board, tables, depth - 1, reference);
+            undo_move_basic(board, undo);
+            children.push((d.move_uci.clone(), child));
+        }
+    }
+
+    PerftDebugResult {
+        fen: board.to_fen(),
+        depth,
+        discrepancies,
+        children,
+    }
 }
 
 // Recursive implementation with per-ply buffers for breakdown
@@ -269,7 +379,7 @@ fn perft_count_recursive(
         if mv.is_castling() {
             out.castles += 1;
         }
-        if mv.promotion.is_some() {
+        if mv.is_promotion() {
             out.promotions += 1;
         }
 
@@ -0,0 +1,130 @@
+//! Absolute pins: pieces that may only move along the line to their king.
+
+use crate::board::{Board, Color, Piece};
+use crate::moves::magic::MagicTables;
+use crate::square::Square;
+use crate::utils::pop_lsb;
+
+/// Squares strictly between `a` and `b`, or 0 if they share no rank, file
+/// or diagonal.
+pub fn between(a: Square, b: Square, tables: &MagicTables) -> u64 {
+    let (a_idx, b_idx) = (a.index() as usize, b.index() as usize);
+    let (a_bb, b_bb) = (1u64 << a_idx, 1u64 << b_idx);
+
+    if tables.rook.get_attacks(a_idx, 0) & b_bb != 0 {
+        tables.rook.get_attacks(a_idx, b_bb) & tables.rook.get_attacks(b_idx, a_bb)
+    } else if tables.bishop.get_attacks(a_idx, 0) & b_bb != 0 {
+        tables.bishop.get_attacks(a_idx, b_bb) & tables.bishop.get_attacks(b_idx, a_bb)
+    } else {
+        0
+    }
+}
+
+/// Enemy sliders that would attack `color`'s king if nothing stood in between.
+fn snipers(board: &Board, color: Color, tables: &MagicTables) -> u64 {
+    let enemy = color.opposite();
+    let king = board.king_square(color).index() as usize;
+    let queens = board.pieces(Piece::Queen, enemy);
+    let rooks = board.pieces(Piece::Rook, enemy) | queens;
+    let bishops = board.pieces(Piece::Bishop, enemy) | queens;
+
+    (tables.rook.get_attacks(king, 0) & rooks) | (tables.bishop.get_attacks(king, 0) & bishops)
+}
+
+/// The pin ray through the piece on `sq`: every square between `color`'s
+/// king and the pinner, plus the pinner itself. 0 if the piece is not pinned.
+fn pin_ray(board: &Board, color: Color, sq: Square, tables: &MagicTables) -> u64 {
+    let king = board.king_square(color);
+    let occupied = board.occupied();
+    let mut snipers = snipers(board, color, tables);
+    while snipers != 0 {
+        let pinner = pop_lsb(&mut snipers);
+        let ray = between(king, Square::from_index(pinner), tables);
+        if ray & occupied == 1u64 << sq.index() {
+            return ray | (1u64 << pinner);
+        }
+    }
+    0
+}
+
+/// Bitboard of `color`'s pieces pinned to their own king.
+pub fn pinned_pieces(board: &Board, color: Color, tables: &MagicTables) -> u64 {
+    let king = board.king_square(color);
+    let occupied = board.occupied();
+    let own = board.occupancy(color);
+    let mut pinned = 0;
+
+    let mut snipers = snipers(board, color, tables);
+    while snipers != 0 {
+        let pinner = pop_lsb(&mut snipers);
+        let blockers = between(king, Square::from_index(pinner), tables) & occupied;
+        if blockers.count_ones() == 1 && blockers & own != 0 {
+            pinned |= blockers;
+        }
+    }
+    pinned
+}
+
+impl Board {
+    /// Squares the piece on `sq` may move to without exposing its king: the
+    /// pin ray (pinner included) if it is pinned, `u64::MAX` otherwise.
+    ///
+    /// Only absolute pins are considered; en passant captures that uncover
+    /// the king along a rank still need a full legality check.
+    pub fn pin_mask(&self, sq: Square, tables: &MagicTables) -> u64 {
+        let Some((color, piece)) = self.piece_at(sq) else {
+            return u64::MAX;
+        };
+        if piece == Piece::King || pinned_pieces(self, color, tables) & (1u64 << sq.index()) == 0 {
+            return u64::MAX;
+        }
+        pin_ray(self, color, sq, tables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitboard::bb_from_squares;
+    use crate::moves::magic::loader::load_magic_tables;
+    use std::str::FromStr;
+
+    fn bb(squares: &[&str]) -> u64 {
+        let squares: Vec<Square> = squares.iter().map(|s| s.parse().unwrap()).collect();
+        bb_from_squares(&squares)
+    }
+
+    #[test]
+    fn test_pin_mask_diagonal() {
+        let tables = load_magic_tables();
+        // Bishop on b4 pins the d2 knight to the e1 king
+        let board = Board::from_str("4k3/8/8/8/1b6/8/3N4/4K3 w - - 0 1").unwrap();
+        let d2 = Square::from_str("d2").unwrap();
+        assert_eq!(pinned_pieces(&board, Color::White, &tables), bb(&["d2"]));
+        assert_eq!(board.pin_mask(d2, &tables), bb(&["d2", "c3", "b4"]));
+    }
+
+    #[test]
+    fn test_pin_mask_orthogonal() {
+        let tables = load_magic_tables();
+        // Rook on e8 pins the e4 rook, which may still slide along the file
+        let board = Board::from_str("k3r3/8/8/8/4R3/8/8/4K3 w - - 0 1").unwrap();
+        let e4 = Square::from_str("e4").unwrap();
+        assert_eq!(
+            board.pin_mask(e4, &tables),
+            bb(&["e2", "e3", "e4", "e5", "e6", "e7", "e8"])
+        );
+    }
+
+    #[test]
+    fn test_pin_mask_unpinned() {
+        let tables = load_magic_tables();
+        // Two pieces between king and rook: neither is pinned
+        let board = Board::from_str("k3r3/8/8/4N3/4R3/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(pinned_pieces(&board, Color::White, &tables), 0);
+        for sq in ["e4", "e5", "e1", "a1"] {
+            let sq = Square::from_str(sq).unwrap();
+            assert_eq!(board.pin_mask(sq, &tables), u64::MAX);
+        }
+    }
+}
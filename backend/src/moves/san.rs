@@ -0,0 +1,174 @@
+//! Standard Algebraic Notation (SAN) parsing and formatting.
+
+use crate::board::{Board, Piece};
+use crate::error::VantageError;
+use crate::moves::execute::generate_legal;
+use crate::moves::magic::MagicTables;
+use crate::moves::types::Move;
+use crate::square::Square;
+use std::str::FromStr;
+
+impl Move {
+    /// Parse a SAN move ("e4", "Nbd7", "exd6", "O-O-O", "e8=Q+") against `board`.
+    ///
+    /// The move is resolved against the legal moves of the position, so the
+    /// result is always legal. Check/mate markers and annotation glyphs
+    /// (`+`, `#`, `!`, `?`) are ignored; promotions may be written `e8=Q`,
+    /// `e8Q` or `e8(Q)`.
+    pub fn from_san(san: &str, board: &Board, tables: &MagicTables) -> Result<Move, VantageError> {
+        let invalid = |why: &str| VantageError::InvalidMove(format!("{}: {}", san, why));
+
+        let s = san.trim_end_matches(['+', '#', '!', '?']);
+        if !s.is_ascii() || s.is_empty() {
+            return Err(invalid("empty or non-ASCII move"));
+        }
+
+        // generate_legal needs `&mut` for its make/undo filter; work on a copy
+        let mut scratch_board = board.clone();
+        let mut legal = Vec::with_capacity(64);
+        let mut scratch = Vec::with_capacity(256);
+        generate_legal(&mut scratch_board, tables, &mut legal, &mut scratch);
+
+        let castle = match s {
+            "O-O" | "0-0" => Some(true),
+            "O-O-O" | "0-0-0" => Some(false),
+            _ => None,
+        };
+        if let Some(kingside) = castle {
+            return legal
+                .into_iter()
+                .find(|m| {
+                    if kingside {
+                        m.is_kingside_castle()
+                    } else {
+                        m.is_queenside_castle()
+                    }
+                })
+                .ok_or_else(|| invalid("castling is not legal here"));
+        }
+
+        let mut rest = s;
+
+        // Promotion suffix: "=Q", "(Q)" or a bare trailing piece letter
+        let mut promotion = None;
+        if let Some(stripped) = rest.strip_suffix(')') {
+            let (head, piece) = stripped
+                .rsplit_once('(')
+                .ok_or_else(|| invalid("unbalanced promotion parentheses"))?;
+            promotion = Some(promotion_piece(piece).ok_or_else(|| invalid("bad promotion piece"))?);
+            rest = head;
+        } else if let Some(last) = rest.chars().last()
+            && let Some(piece) = promotion_piece(&last.to_string())
+            && rest.len() > 2
+        {
+            promotion = Some(piece);
+            rest = rest[..rest.len() - 1].trim_end_matches('=');
+        }
+
+        let piece = match rest.as_bytes()[0] {
+            b'K' => Piece::King,
+            b'Q' => Piece::Queen,
+            b'R' => Piece::Rook,
+            b'B' => Piece::Bishop,
+            b'N' => Piece::Knight,
+            _ => Piece::Pawn,
+        };
+        if piece != Piece::Pawn {
+            rest = &rest[1..];
+        }
+
+        if rest.len() < 2 {
+            return Err(invalid("missing destination square"));
+        }
+        let to = Square::from_str(&rest[rest.len() - 2..]).map_err(|e| invalid(&e))?;
+
+        // Whatever is left between the piece letter and destination is
+        // an optional disambiguating file and/or rank plus the capture marker.
+        let mut from_file = None;
+        let mut from_rank = None;
+        for c in rest[..rest.len() - 2].chars() {
+            match c {
+                'a'..='h' => from_file = Some(c as u8 - b'a'),
+                '1'..='8' => from_rank = Some(c as u8 - b'1'),
+                'x' | ':' | '-' => {}
+                _ => return Err(invalid("unexpected character")),
+            }
+        }
+
+        let mut candidates = legal.into_iter().filter(|m| {
+            m.piece == piece
+                && m.to == to
+                && m.promotion == promotion
+                && from_file.is_none_or(|f| m.from.file() == f)
+                && from_rank.is_none_or(|r| m.from.rank() == r)
+        });
+
+        match (candidates.next(), candidates.next()) {
+            (Some(mv), None) => Ok(mv),
+            (None, _) => Err(invalid("no matching legal move")),
+            (Some(_), Some(_)) => Err(invalid("ambiguous move")),
+        }
+    }
+}
+
+impl Move {
+    /// Format this move in SAN, disambiguating against `legal_moves` (the
+    /// legal moves of `board`).
+    ///
+    /// Check and mate markers are left off since they depend on the position
+    /// after the move; `from_san` treats them as optional.
+    pub fn to_san(&self, board: &Board, legal_moves: &[Move]) -> String {
+        if self.is_kingside_castle() {
+            return "O-O".to_string();
+        }
+        if self.is_queenside_castle() {
+            return "O-O-O".to_string();
+        }
+
+        let mut san = String::with_capacity(8);
+        let file = |sq: Square| (b'a' + sq.file()) as char;
+        if self.piece == Piece::Pawn {
+            if self.is_capture() {
+                san.push(file(self.from));
+            }
+        } else {
+            san.push(char::from(self.piece));
+            let rivals: Vec<&Move> = legal_moves
+                .iter()
+                .filter(|m| m.piece == self.piece && m.to == self.to && m.from != self.from)
+                .collect();
+            if !rivals.is_empty() {
+                let same_file = rivals.iter().any(|m| m.from.file() == self.from.file());
+                let same_rank = rivals.iter().any(|m| m.from.rank() == self.from.rank());
+                if !same_file {
+                    san.push(file(self.from));
+                } else if !same_rank {
+                    san.push((b'1' + self.from.rank()) as char);
+                } else {
+                    san.push_str(&self.from.to_string());
+                }
+            }
+        }
+
+        // Captures onto an occupied square or en passant
+        if self.is_capture() || board.piece_at(self.to).is_some() {
+            san.push('x');
+        }
+        san.push_str(&self.to.to_string());
+        if let Some(promo) = self.promotion {
+            san.push('=');
+            san.push(char::from(promo));
+        }
+        san
+    }
+}
+
+fn promotion_piece(s: &str) -> Option<Piece> {
+    match s {
+        "Q" => Some(Piece::Queen),
+        "R" => Some(Piece::Rook),
+        "B" => Some(Piece::Bishop),
+        "N" => Some(Piece::Knight),
+        _ => None,
+    }
+}
@@ -1,10 +1,13 @@
 use crate::board::{Board, Color, Piece};
+use crate::moves::castle::castle_for_move;
+use crate::moves::execute::make_move_basic;
 use crate::moves::king::KING_ATTACKS;
 use crate::moves::knight::KNIGHT_ATTACKS;
 use crate::moves::magic::MagicTables;
 use crate::moves::pawn::pawn_attacks;
 use crate::moves::types::Move;
 use crate::square::Square;
+use crate::utils::pop_lsb;
 
 /// Bitboard file masks (a1 = bit 0 … h8 = bit 63).
 pub const FILE_A: u64 = 0x0101_0101_0101_0101;
@@ -73,10 +76,171 @@ pub fn is_square_attacked(
     false
 }
 
+/// Bitboard of `by`'s pieces attacking `square`, with sliders blocked by
+/// `occupancy`. Pieces missing from `occupancy` are treated as already gone,
+/// which is how SEE uncovers x-ray attackers.
+pub fn attackers_to(
+    board: &Board,
+    square: u8,
+    by: Color,
+    occupancy: u64,
+    tables: &MagicTables,
+) -> u64 {
+    let index = square as usize;
+    let rook_attacks = tables.rook.get_attacks(index, occupancy);
+    let bishop_attacks = tables.bishop.get_attacks(index, occupancy);
+    let queens = board.pieces(Piece::Queen, by);
+
+    // A pawn of `by` attacks `square` from where an opposing pawn on
+    // `square` would capture
+    let attackers = (pawn_attacks(square, by.opposite()) & board.pieces(Piece::Pawn, by))
+        | (KNIGHT_ATTACKS[index] & board.pieces(Piece::Knight, by))
+        | (KING_ATTACKS[index] & board.pieces(Piece::King, by))
+        | (bishop_attacks & (board.pieces(Piece::Bishop, by) | queens))
+        | (rook_attacks & (board.pieces(Piece::Rook, by) | queens));
+    attackers & occupancy
+}
+
+/// Bitboard of the enemy pieces giving check to `side`'s king.
+pub fn checkers(board: &Board, side: Color, tables: &MagicTables) -> u64 {
+    // The enemy king is included: adjacent kings only occur mid legality
+    // test, but must count so a king can't step next to the other king
+    let king = board.king_square(side).index();
+    attackers_to(board, king, side.opposite(), board.occupied(), tables)
+}
+
+/// Every square attacked by `color`, with sliders blocked by `occupancy`.
+///
+/// Passing the occupancy without the defending king lets king move generation
+/// see through it, so a king can't step back along a checking ray.
+pub fn attacked_squares(board: &Board, color: Color, occupancy: u64, tables: &MagicTables) -> u64 {
+    let pawns = board.pieces(Piece::Pawn, color);
+    let mut attacks = match color {
+        Color::White => ((pawns & !FILE_A) << 7) | ((pawns & !FILE_H) << 9),
+        Color::Black => ((pawns & !FILE_H) >> 7) | ((pawns & !FILE_A) >> 9),
+    };
+
+    let mut knights = board.pieces(Piece::Knight, color);
+    while knights != 0 {
+        attacks |= KNIGHT_ATTACKS[pop_lsb(&mut knights) as usize];
+    }
+
+    let mut diagonal = 0;
+    let mut orthogonal = 0;
+    for piece in [Piece::Bishop, Piece::Rook, Piece::Queen] {
+        let bb = board.pieces(piece, color);
+        if piece.diagonal_slider() {
+            diagonal |= bb;
+        }
+        if piece.orthogonal_slider() {
+            orthogonal |= bb;
+        }
+    }
+    while diagonal != 0 {
+        attacks |= tables
+            .bishop
+            .get_attacks(pop_lsb(&mut diagonal) as usize, occupancy);
+    }
+    while orthogonal != 0 {
+        attacks |= tables
+            .rook
+            .get_attacks(pop_lsb(&mut orthogonal) as usize, occupancy);
+    }
+
+    let king = board.pieces(Piece::King, color);
+    if king != 0 {
+        attacks |= KING_ATTACKS[king.trailing_zeros() as usize];
+    }
+
+    attacks
+}
+
+impl Board {
+    /// Bitboard of `by`'s pieces attacking `sq` in the current position.
+    /// Only the first slider on each ray counts; pieces behind it are x-rays.
+    pub fn attackers_of_color(&self, sq: Square, by: Color, tables: &MagicTables) -> u64 {
+        attackers_to(self, sq.index(), by, self.occupied(), tables)
+    }
+
+    /// Number of `by`'s pieces attacking `sq` (see `attackers_of_color`).
+    pub fn count_attacks_on(&self, sq: Square, by: Color, tables: &MagicTables) -> u32 {
+        self.attackers_of_color(sq, by, tables).count_ones()
+    }
+
+    /// Bitboard of every square `color` attacks in the current position.
+    pub fn generate_attacks(&self, color: Color, tables: &MagicTables) -> u64 {
+        attacked_squares(self, color, self.occupied(), tables)
+    }
+
+    /// `color`'s pieces (king excluded) that the opponent attacks and no
+    /// friendly piece defends. Pins and x-rays are ignored.
+    pub fn threatened_pieces(&self, color: Color, tables: &MagicTables) -> u64 {
+        self.threatened_pieces_with(
+            color,
+            self.generate_attacks(color.opposite(), tables),
+            self.generate_attacks(color, tables),
+        )
+    }
+
+    /// `threatened_pieces` with the attack maps already built: `attackers`
+    /// is the opponent's `generate_attacks`, `defenders` is `color`'s.
+    pub fn threatened_pieces_with(&self, color: Color, attackers: u64, defenders: u64) -> u64 {
+        let pieces = self.occupancy(color) & !self.pieces(Piece::King, color);
+        pieces & attackers & !defenders
+    }
+
+    /// Enemy pieces (king excluded) that the piece moved by `mv` attacks
+    /// from its new square and that nothing defends once `mv` is played:
+    /// the threats a quiet move creates. A promotion is looked at as the
+    /// promoted piece. Threats the move uncovers from other pieces aren't
+    /// included.
+    pub fn threats_from_move(&self, mv: Move, tables: &MagicTables) -> u64 {
+        let mut after = self.clone();
+        make_move_basic(&mut after, mv);
+        let enemy = after.side_to_move;
+        let undefended = after.occupancy(enemy)
+            & !after.pieces(Piece::King, enemy)
+            & !after.generate_attacks(enemy, tables);
+        after.attacks_from(mv.to, tables) & undefended
+    }
+
+    /// Squares the piece on `sq` attacks (0 if `sq` is empty).
+    ///
+    /// Pseudo-legal: pins are ignored, and squares holding friendly pieces are
+    /// included since the piece defends them. Pawns give their capture squares.
+    pub fn attacks_from(&self, sq: Square, tables: &MagicTables) -> u64 {
+        let Some((color, piece)) = self.piece_at(sq) else {
+            return 0;
+        };
+        let idx = sq.index() as usize;
+        let occ = self.occupied();
+        match piece {
+            Piece::Pawn => pawn_attacks(sq.index(), color),
+            Piece::Knight => KNIGHT_ATTACKS[idx],
+            Piece::King => KING_ATTACKS[idx],
+            _ => {
+                let mut attacks = 0;
+                if piece.diagonal_slider() {
+                    attacks |= tables.bishop.get_attacks(idx, occ);
+                }
+                if piece.orthogonal_slider() {
+                    attacks |= tables.rook.get_attacks(idx, occ);
+                }
+                attacks
+            }
+        }
+    }
+}
+
 #[inline(always)]
 pub fn in_check(board: &Board, side: Color, tables: &MagicTables) -> bool {
-    let king_sq = board.king_square(side); // you’ll need this helper if not already implemented
-    is_square_attacked(board, king_sq, side.opposite(), tables)
+    checkers(board, side, tables) != 0
+}
+
+/// Two checkers at once: only king moves can be legal.
+#[inline(always)]
+pub fn in_double_check(board: &Board, side: Color, tables: &MagicTables) -> bool {
+    checkers(board, side, tables).count_ones() >= 2
 }
 
 pub fn is_legal_castling(board: &Board, mv: Move, tables: &MagicTables) -> bool {
@@ -87,19 +251,16 @@ pub fn is_legal_castling(board: &Board, mv: Move, tables: &MagicTables) -> bool
         return false;
     }
 
-    // 2. Check squares king passes through
-    let (start_idx, middle_idx, end_idx) = match (color, mv.to.index()) {
-        (Color::White, 6) => (4, 5, 6),     // White kingside
-        (Color::White, 2) => (4, 3, 2),     // White queenside
-        (Color::Black, 62) => (60, 61, 62), // Black kingside
-        (Color::Black, 58) => (60, 59, 58), // Black queenside
-        _ => return false,
+    // 2. Squares the king starts on, crosses and lands on
+    let Some(castle) = castle_for_move(color, mv) else {
+        return false;
     };
 
-    // After computing (start_idx, middle_idx, end_idx):
     let opp = color.opposite();
-    for &test_idx in &[start_idx, middle_idx, end_idx] {
-        if is_square_attacked(board, Square::from_index(test_idx as u8), opp, tables) {
+    let mut path = castle.check_mask;
+    while path != 0 {
+        let sq = Square::from_index(pop_lsb(&mut path));
+        if is_square_attacked(board, sq, opp, tables) {
             return false;
         }
     }
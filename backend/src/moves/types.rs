@@ -1,8 +1,10 @@
-use crate::board::{Color, Piece};
+use crate::board::{Board, Color, Piece};
+use crate::error::VantageError;
 use crate::square::Square;
 use arrayvec::ArrayVec;
 use std::fmt;
 use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
 
 pub trait MoveBuffer: Deref<Target = [Move]> + DerefMut {
     fn push(&mut self, mv: Move);
@@ -80,14 +82,41 @@ impl Move {
         (self.flags & PROMOTION) != 0
     }
 
+    /// True if this is a promotion by a pawn to one of Q/R/B/N.
+    #[inline(always)]
+    pub fn is_valid_promotion(&self) -> bool {
+        self.is_promotion()
+            && self.piece == Piece::Pawn
+            && self
+                .promotion
+                .is_some_and(|p| Piece::promotable().contains(&p))
+    }
+
     #[inline(always)]
     pub fn is_double_pawn_push(&self) -> bool {
         self.flags == DOUBLE_PAWN_PUSH
     }
 
+    /// True for moves that neither capture nor promote (pushes, double
+    /// pushes, castling and ordinary piece moves).
     #[inline(always)]
     pub fn is_quiet(&self) -> bool {
-        self.flags == QUIET_MOVE
+        !self.is_capture() && !self.is_promotion()
+    }
+
+    /// True for captures and promotions: the moves quiescence searches and
+    /// the pruning heuristics leave alone.
+    #[inline(always)]
+    pub fn is_noisy(&self) -> bool {
+        self.is_capture() || self.is_promotion()
+    }
+
+    /// Noisy moves plus en passant. En passant already carries the capture
+    /// bit, so today this matches `is_noisy`; it is spelled out so callers
+    /// don't depend on that encoding detail.
+    #[inline(always)]
+    pub fn is_tactical(&self) -> bool {
+        self.is_noisy() || self.is_en_passant()
     }
 
     pub fn to_uci(&self) -> String {
@@ -105,6 +134,121 @@ impl Move {
 
         format!("{}{}{}", self.from, self.to, promo)
     }
+
+    /// Parse a UCI move string ("e2e4", "e7e8q", "e1g1") against `board`.
+    ///
+    /// The moving piece and flags (capture, en passant, castling, double push,
+    /// promotion) are inferred from the position. The result is only checked for
+    /// consistency with the board, not for legality; pair it with
+    /// `is_legal_move` or match it against `generate_legal` output.
+    pub fn from_uci(s: &str, board: &Board) -> Result<Move, VantageError> {
+        let invalid = |why: &str| VantageError::InvalidMove(format!("{}: {}", s, why));
+
+        if !s.is_ascii() || !(s.len() == 4 || s.len() == 5) {
+            return Err(invalid("expected 4 or 5 characters"));
+        }
+        let from = Square::from_str(&s[0..2]).map_err(|e| invalid(&e))?;
+        let to = Square::from_str(&s[2..4]).map_err(|e| invalid(&e))?;
+        let promotion = match s.as_bytes().get(4) {
+            None => None,
+            Some(b'q') => Some(Piece::Queen),
+            Some(b'r') => Some(Piece::Rook),
+            Some(b'b') => Some(Piece::Bishop),
+            Some(b'n') => Some(Piece::Knight),
+            Some(_) => return Err(invalid("promotion piece must be one of q, r, b, n")),
+        };
+        board.decode_move(from, to, promotion)
+    }
+}
+
+impl Board {
+    /// Build the move from `from` to `to`, working out the moving piece and
+    /// its flags (capture, en passant, castling, double push, promotion) from
+    /// the position, as `Move::from_uci` does for UCI text.
+    ///
+    /// Fails with `InvalidMove` if there is no piece of the side to move on
+    /// `from`, the destination holds a friendly piece, or the promotion piece
+    /// doesn't match a pawn reaching the last rank. Like `from_uci`, it does
+    /// not check that the piece can actually reach `to`, or legality.
+    pub fn decode_move(
+        &self,
+        from: Square,
+        to: Square,
+        promotion: Option<Piece>,
+    ) -> Result<Move, VantageError> {
+        let invalid = |why: &str| {
+            let promo = promotion.map_or(String::new(), |p| p.to_char(Color::Black).to_string());
+            VantageError::InvalidMove(format!("{}{}{}: {}", from, to, promo, why))
+        };
+
+        if from == to {
+            return Err(invalid("from and to squares are the same"));
+        }
+
+        let (color, piece) = self
+            .piece_at(from)
+            .ok_or_else(|| invalid("no piece on the from square"))?;
+        if color != self.side_to_move {
+            return Err(invalid(
+                "piece on the from square belongs to the side not to move",
+            ));
+        }
+        let captured = self.piece_at(to);
+        if captured.is_some_and(|(c, _)| c == color) {
+            return Err(invalid("destination is occupied by a friendly piece"));
+        }
+        let is_capture = captured.is_some();
+
+        if matches!(promotion, Some(Piece::Pawn | Piece::King)) {
+            return Err(invalid("promotion piece must be one of q, r, b, n"));
+        }
+        let last_rank = match color {
+            Color::White => 7,
+            Color::Black => 0,
+        };
+        let reaches_last_rank = piece == Piece::Pawn && to.rank() == last_rank;
+        if promotion.is_some() && !reaches_last_rank {
+            return Err(invalid("only a pawn reaching the last rank can promote"));
+        }
+        if promotion.is_none() && reaches_last_rank {
+            return Err(invalid("missing promotion piece"));
+        }
+
+        let file_delta = (to.file() as i8 - from.file() as i8).abs();
+        let rank_delta = (to.rank() as i8 - from.rank() as i8).abs();
+
+        let flags = match piece {
+            _ if promotion.is_some() => {
+                if is_capture {
+                    PROMOTION_CAPTURE
+                } else {
+                    PROMOTION
+                }
+            }
+            Piece::Pawn if !is_capture && file_delta == 1 && self.en_passant == Some(to) => {
+                EN_PASSANT
+            }
+            Piece::Pawn if rank_delta == 2 => DOUBLE_PAWN_PUSH,
+            // Castling is encoded as the king moving two squares
+            Piece::King if rank_delta == 0 && file_delta == 2 => {
+                if to.file() > from.file() {
+                    KINGSIDE_CASTLE
+                } else {
+                    QUEENSIDE_CASTLE
+                }
+            }
+            _ if is_capture => CAPTURE,
+            _ => QUIET_MOVE,
+        };
+
+        Ok(Move {
+            from,
+            to,
+            piece,
+            promotion,
+            flags,
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -159,9 +303,13 @@ impl fmt::Display for Move {
     }
 }
 
+/// State a null move overwrites, handed back to `undo_null_move`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NullMoveUndo {
+    /// En passant square before the null move (it is always cleared).
     pub prev_en_passant: Option<Square>,
     pub prev_halfmove_clock: u32,
     pub prev_side: Color,
+    /// Zobrist hash before the null move, restored verbatim on undo.
+    pub prev_zobrist: u64,
 }
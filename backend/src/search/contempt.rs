@@ -0,0 +1,115 @@
+//! Contempt: how much less than a real draw a drawn line is worth to us.
+//!
+//! Draw scores are taken from the root side's point of view, so a positive
+//! contempt makes the engine steer away from repetitions and fifty-move
+//! draws while the opponent's replies still see them as welcome.
+
+use crate::search::search::DRAW_SCORE;
+
+pub const DEFAULT_CONTEMPT: i32 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContemptConfig {
+    /// Centipawns a draw is worth below `DRAW_SCORE` to the side to move at
+    /// the root (UCI `Contempt`). Zero or negative turns contempt off.
+    pub contempt: i32,
+    /// Opponent's rating, from the Elo field of UCI `UCI_Opponent`.
+    pub opponent_elo: Option<u32>,
+    /// Our rating (UCI `UCI_Elo`).
+    pub our_elo: Option<u32>,
+    /// Our rating minus the opponent's (UCI `UCI_RatingAdv`). Takes
+    /// precedence over the two ratings when set.
+    pub rating_advantage: Option<i32>,
+}
+
+impl Default for ContemptConfig {
+    fn default() -> Self {
+        Self {
+            contempt: DEFAULT_CONTEMPT,
+            opponent_elo: None,
+            our_elo: None,
+            rating_advantage: None,
+        }
+    }
+}
+
+impl ContemptConfig {
+    /// Set `opponent_elo` from a `UCI_Opponent` value,
+    /// `<title> <elo> <computer|human> <name>`, e.g. `GM 2800 human Gary`.
+    /// An Elo of `none` clears it.
+    pub fn set_uci_opponent(&mut self, value: &str) {
+        self.opponent_elo = value
+            .split_whitespace()
+            .nth(1)
+            .and_then(|elo| elo.parse().ok());
+    }
+
+    /// False when the rating advantage is negative, or, without one, when
+    /// both ratings are known and the opponent's is higher.
+    pub fn we_are_stronger(&self) -> bool {
+        if let Some(advantage) = self.rating_advantage {
+            return advantage >= 0;
+        }
+        match (self.our_elo, self.opponent_elo) {
+            (Some(ours), Some(theirs)) => ours >= theirs,
+            _ => true,
+        }
+    }
+
+    /// What a draw is worth to the root side: `DRAW_SCORE - contempt` when
+    /// contempt is on and we are the stronger side, else `DRAW_SCORE`.
+    pub fn root_draw_score(&self) -> i32 {
+        if self.contempt > 0 && self.we_are_stronger() {
+            DRAW_SCORE - self.contempt
+        } else {
+            DRAW_SCORE
+        }
+    }
+
+    /// The draw score for the side to move `ply` plies below the root.
+    #[inline]
+    pub fn draw_score(&self, ply: usize) -> i32 {
+        if ply.is_multiple_of(2) {
+            self.root_draw_score()
+        } else {
+            -self.root_draw_score()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw_score_by_strength() {
+        let mut config = ContemptConfig {
+            contempt: 30,
+            ..Default::default()
+        };
+        assert_eq!(config.draw_score(0), DRAW_SCORE - 30);
+        assert_eq!(config.draw_score(1), -(DRAW_SCORE - 30));
+
+        config.our_elo = Some(2000);
+        config.opponent_elo = Some(2500);
+        assert_eq!(config.draw_score(0), DRAW_SCORE);
+
+        config.opponent_elo = Some(1800);
+        assert_eq!(config.draw_score(0), DRAW_SCORE - 30);
+
+        config.rating_advantage = Some(-100);
+        assert_eq!(config.draw_score(0), DRAW_SCORE);
+
+        config.contempt = 0;
+        assert_eq!(config.draw_score(0), DRAW_SCORE);
+    }
+
+    #[test]
+    fn test_set_uci_opponent() {
+        let mut config = ContemptConfig::default();
+        config.set_uci_opponent("GM 2800 human Gary Kasparov");
+        assert_eq!(config.opponent_elo, Some(2800));
+        config.set_uci_opponent("none none computer Shredder");
+        assert_eq!(config.opponent_elo, None);
+    }
+}
@@ -1,8 +1,34 @@
+use crate::logger::{NullLogger, SearchLogger};
+use crate::moves::execute::MAX_PLY;
 use crate::moves::types::Move;
+use crate::search::contempt::ContemptConfig;
+use crate::search::pv_table::PvTable;
+use crate::search::tuning::TuningParams;
+#[cfg(feature = "debug_registry")]
+use crate::utils::registry::PositionRegistry;
+
+/// History scores are kept within `-HISTORY_MAX..=HISTORY_MAX`.
+pub const HISTORY_MAX: i32 = 1 << 20;
 
 pub struct SearchContext {
     pub killer_moves: Vec<[Option<Move>; 2]>,
     pub history: [[i32; 64]; 64],
+    /// Quiet reply that refuted a move, by that move's from/to squares.
+    pub countermoves: [[Option<Move>; 64]; 64],
+    /// Move played at each ply of the current line (`None` for a null move).
+    pub move_stack: [Option<Move>; MAX_PLY],
+    /// Principal variation of the iteration in progress.
+    pub pv_table: PvTable,
+    /// Eval and pruning parameters; defaults unless loaded for tuning.
+    pub params: TuningParams,
+    /// Draw avoidance; set from the UCI `Contempt` option.
+    pub contempt: ContemptConfig,
+    /// Receives an `info` line per completed iteration. `NullLogger` by
+    /// default; the UCI loop installs a `StdoutLogger`.
+    pub logger: Box<dyn SearchLogger>,
+    /// Every position `alpha_beta` has visited, by Zobrist key.
+    #[cfg(feature = "debug_registry")]
+    pub registry: PositionRegistry,
 }
 
 impl Default for SearchContext {
@@ -13,9 +39,46 @@ impl Default for SearchContext {
 
 impl SearchContext {
     pub fn new() -> Self {
+        Self::with_params(TuningParams::DEFAULT)
+    }
+
+    /// Context that searches with `params` instead of the defaults.
+    pub fn with_params(params: TuningParams) -> Self {
         Self {
-            killer_moves: vec![[None; 2]; 64],
+            killer_moves: vec![[None; 2]; MAX_PLY],
             history: [[0; 64]; 64],
+            countermoves: [[None; 64]; 64],
+            move_stack: [None; MAX_PLY],
+            pv_table: PvTable::new(),
+            params,
+            contempt: ContemptConfig::default(),
+            logger: Box::new(NullLogger),
+            #[cfg(feature = "debug_registry")]
+            registry: PositionRegistry::new(),
+        }
+    }
+
+    /// Forget everything learned so far; call on `ucinewgame`.
+    pub fn reset_for_new_game(&mut self) {
+        self.killer_moves.fill([None; 2]);
+        self.history = [[0; 64]; 64];
+        self.countermoves = [[None; 64]; 64];
+    }
+
+    /// Prepare for the next search in the same game: killers are ply-relative
+    /// and describe the previous root, so they are dropped, but history is only
+    /// decayed so move ordering keeps what it learned.
+    pub fn reset_for_new_search(&mut self) {
+        self.killer_moves.fill([None; 2]);
+        self.decay_history();
+    }
+
+    /// Divide every history score by 8.
+    pub fn decay_history(&mut self) {
+        for row in self.history.iter_mut() {
+            for score in row.iter_mut() {
+                *score /= 8;
+            }
         }
     }
 
@@ -27,7 +90,77 @@ impl SearchContext {
     }
 
     pub fn update_history(&mut self, mv: Move, depth: i32) {
+        self.add_history(mv, (depth * depth).min(400));
+    }
+
+    fn add_history(&mut self, mv: Move, delta: i32) {
+        let score = &mut self.history[mv.from.index() as usize][mv.to.index() as usize];
+        *score = (*score + delta).clamp(-HISTORY_MAX, HISTORY_MAX);
+    }
+
+    /// The recorded reply to `prev`, if any.
+    pub fn countermove(&self, prev: Move) -> Option<Move> {
+        self.countermoves[prev.from.index() as usize][prev.to.index() as usize]
+    }
+
+    /// Everything a quiet beta cutoff by `mv` teaches: `mv` becomes a killer
+    /// at `ply` and the countermove to `prev_move`, its history gains
+    /// `depth * depth` and every quiet in `quiet_moves_tried` before it
+    /// loses the same amount.
+    pub fn update_all_heuristics(
+        &mut self,
+        mv: Move,
+        quiet_moves_tried: &[Move],
+        depth: i32,
+        ply: usize,
+        prev_move: Option<Move>,
+    ) {
+        self.update_killer(ply, mv);
+
         let bonus = (depth * depth).min(400);
-        self.history[mv.from.index() as usize][mv.to.index() as usize] += bonus;
+        self.add_history(mv, bonus);
+        for &tried in quiet_moves_tried {
+            if tried != mv {
+                self.add_history(tried, -bonus);
+            }
+        }
+
+        if let Some(prev) = prev_move {
+            self.countermoves[prev.from.index() as usize][prev.to.index() as usize] = Some(mv);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Board;
+
+    #[test]
+    fn test_update_all_heuristics() {
+        let start = Board::new();
+        let e4 = Move::from_uci("e2e4", &start).unwrap();
+        let board: Board = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"
+            .parse()
+            .unwrap();
+        let mv = |uci: &str| Move::from_uci(uci, &board).unwrap();
+        let (e5, d5, nf6) = (mv("e7e5"), mv("d7d5"), mv("g8f6"));
+        let mut ctx = SearchContext::new();
+
+        ctx.update_all_heuristics(nf6, &[e5, d5], 5, 3, Some(e4));
+
+        assert_eq!(ctx.killer_moves[3], [Some(nf6), None]);
+        assert_eq!(ctx.history[62][45], 25);
+        assert_eq!(ctx.history[52][36], -25);
+        assert_eq!(ctx.history[51][35], -25);
+        assert_eq!(ctx.countermove(e4), Some(nf6));
+
+        // A second cutoff keeps the old killer in the second slot
+        ctx.update_all_heuristics(e5, &[], 2, 3, None);
+        assert_eq!(ctx.killer_moves[3], [Some(e5), Some(nf6)]);
+        assert_eq!(ctx.history[52][36], -21);
+
+        ctx.reset_for_new_game();
+        assert_eq!(ctx.countermove(e4), None);
     }
 }
@@ -1,26 +1,14 @@
-use crate::board::{Board, Color, Piece};
+use crate::board::{ALL_PIECES, Board, Color, EndgameType, Piece};
+use crate::moves::king::KING_ATTACKS;
+use crate::moves::knight::KNIGHT_ATTACKS;
 use crate::moves::magic::MagicTables;
+use crate::moves::movegen::{RANK1, RANK2, RANK7, RANK8};
+use crate::moves::pawn::{BLACK_PAWN_ATTACKS, WHITE_PAWN_ATTACKS, pawn_attacks};
 use crate::search::pesto;
+use crate::search::tuning::TuningParams;
 use crate::square::Square;
 use crate::utils::pop_lsb;
 
-const MOBILITY_WEIGHT: i32 = 5;
-const ISOLATED_PAWN_PENALTY: i32 = -15;
-const DOUBLED_PAWN_PENALTY: i32 = -10;
-const LAZY_EVAL_MARGIN: i32 = 200;
-const KING_ZONE_ATTACK_PENALTY: i32 = 15;
-
-// [NEW] Feature constants
-const KING_SHIELD_BONUS: i32 = 20; // Bonus for having a pawn shield
-const KING_OPEN_FILE_PENALTY: i32 = -30; // Penalty for standing on an open file
-const BLOCKED_PASSER_PENALTY: i32 = -50; // Penalty if a passed pawn is blocked
-
-// Passed pawn bonus by rank (index 0 = rank 1, index 7 = rank 8)
-// Higher bonus for pawns closer to promotion
-// TUNED: Increased 6th/7th rank bonuses significantly based on Crafty match analysis
-// A pawn on 7th rank is often worth more than a minor piece
-const PASSED_PAWN_BONUS: [i32; 8] = [0, 10, 20, 40, 80, 150, 300, 0];
-
 // Phase Weights
 const KNIGHT_PHASE: i32 = 1;
 const BISHOP_PHASE: i32 = 1;
@@ -45,14 +33,14 @@ impl Iterator for BitIter {
 }
 
 fn calculate_phase(board: &Board) -> i32 {
-    let knights = board.pieces(Piece::Knight, Color::White).count_ones()
-        + board.pieces(Piece::Knight, Color::Black).count_ones();
-    let bishops = board.pieces(Piece::Bishop, Color::White).count_ones()
-        + board.pieces(Piece::Bishop, Color::Black).count_ones();
-    let rooks = board.pieces(Piece::Rook, Color::White).count_ones()
-        + board.pieces(Piece::Rook, Color::Black).count_ones();
-    let queens = board.pieces(Piece::Queen, Color::White).count_ones()
-        + board.pieces(Piece::Queen, Color::Black).count_ones();
+    let knights = board.count_pieces(Color::White, Piece::Knight)
+        + board.count_pieces(Color::Black, Piece::Knight);
+    let bishops = board.count_pieces(Color::White, Piece::Bishop)
+        + board.count_pieces(Color::Black, Piece::Bishop);
+    let rooks = board.count_pieces(Color::White, Piece::Rook)
+        + board.count_pieces(Color::Black, Piece::Rook);
+    let queens = board.count_pieces(Color::White, Piece::Queen)
+        + board.count_pieces(Color::Black, Piece::Queen);
 
     let current_phase_material = (knights as i32 * KNIGHT_PHASE)
         + (bishops as i32 * BISHOP_PHASE)
@@ -68,7 +56,7 @@ pub fn mirror_vert(sq: u8) -> usize {
 }
 
 // Helper: Map piece to tables from pesto.rs
-fn get_psqt(kind: Piece) -> (&'static [i32; 64], &'static [i32; 64]) {
+pub(crate) fn get_psqt(kind: Piece) -> (&'static [i32; 64], &'static [i32; 64]) {
     match kind {
         Piece::Pawn => (&pesto::PAWN_TABLE.0, &pesto::PAWN_TABLE.1),
         Piece::Knight => (&pesto::KNIGHT_TABLE.0, &pesto::KNIGHT_TABLE.1),
@@ -91,22 +79,53 @@ fn get_piece_value(kind: Piece) -> (i32, i32) {
     }
 }
 
+// --- Per-piece terms for Board's incremental accumulator ---
+
+/// Game-phase weight one piece contributes (24 = all pieces on board).
+#[cfg(feature = "incremental_eval")]
+pub(crate) fn piece_phase(kind: Piece) -> i32 {
+    match kind {
+        Piece::Knight => KNIGHT_PHASE,
+        Piece::Bishop => BISHOP_PHASE,
+        Piece::Rook => ROOK_PHASE,
+        Piece::Queen => QUEEN_PHASE,
+        Piece::Pawn | Piece::King => 0,
+    }
+}
+
+/// (mg, eg) material + PST value of one piece, signed from White's view.
+#[cfg(feature = "incremental_eval")]
+pub(crate) fn pesto_piece_value(color: Color, kind: Piece, sq: u8) -> (i32, i32) {
+    let (mg_val, eg_val) = get_piece_value(kind);
+    let (mg_table, eg_table) = get_psqt(kind);
+    match color {
+        Color::White => {
+            let table_sq = mirror_vert(sq);
+            (mg_val + mg_table[table_sq], eg_val + eg_table[table_sq])
+        }
+        Color::Black => (
+            -(mg_val + mg_table[sq as usize]),
+            -(eg_val + eg_table[sq as usize]),
+        ),
+    }
+}
+
+/// Blend middlegame and endgame scores by raw phase material.
+#[cfg(feature = "incremental_eval")]
+#[inline(always)]
+pub(crate) fn taper(mg: i32, eg: i32, phase: i32) -> i32 {
+    let phase = phase.clamp(0, TOTAL_PHASE);
+    (mg * phase + eg * (TOTAL_PHASE - phase)) / TOTAL_PHASE
+}
+
 /// Mop-Up Evaluation: Guides the engine to push enemy king to edges in won endgames
 /// Only activates when we have a significant material advantage (>200cp) AND in endgame
 fn mop_up_eval(board: &Board, my_color: Color) -> i32 {
     // 1. Check if we're in an endgame (few pieces on board)
     // Count total pieces (excluding kings)
-    let mut total_pieces = 0u32;
-    for piece_type in [
-        Piece::Pawn,
-        Piece::Knight,
-        Piece::Bishop,
-        Piece::Rook,
-        Piece::Queen,
-    ] {
-        total_pieces += board.pieces(piece_type, Color::White).count_ones();
-        total_pieces += board.pieces(piece_type, Color::Black).count_ones();
-    }
+    let total_pieces = board.total_piece_count()
+        - board.count_pieces(Color::White, Piece::King)
+        - board.count_pieces(Color::Black, Piece::King);
 
     // Only activate in endgame (≤10 pieces total, excluding kings)
     if total_pieces > 10 {
@@ -146,6 +165,114 @@ fn mop_up_eval(board: &Board, my_color: Color) -> i32 {
     (10 * center_dist) + (4 * (14 - king_dist))
 }
 
+/// Deep endgames only (at most 8 men, phase below 4): the king belongs next
+/// to its most advanced pawn, ideally in front of it. Tapered to zero as
+/// material comes back.
+fn evaluate_king_activity_endgame(board: &Board, color: Color, p: &TuningParams) -> i32 {
+    let phase = calculate_phase(board);
+    if board.total_piece_count() > 8 || phase >= 4 {
+        return 0;
+    }
+    let Some(pawn) = board.most_advanced_pawn(color) else {
+        return 0;
+    };
+    let king = board.king_square(color);
+
+    if (king.file() as i32 - pawn.file() as i32).abs() > 1 {
+        return 0;
+    }
+    let ahead = match color {
+        Color::White => king.rank() > pawn.rank(),
+        Color::Black => king.rank() < pawn.rank(),
+    };
+    let bonus = p.king_pawn_file_bonus + if ahead { p.king_leads_pawn_bonus } else { 0 };
+    bonus * (TOTAL_PHASE - phase) / TOTAL_PHASE
+}
+
+/// Endgame king placement around the pawns, from `side`'s view: king
+/// activity next to the most advanced pawn and proximity to passed pawns.
+fn king_pawn_terms(board: &Board, side: Color, p: &TuningParams) -> i32 {
+    let enemy = side.opposite();
+    evaluate_king_activity_endgame(board, side, p) - evaluate_king_activity_endgame(board, enemy, p)
+        + evaluate_king_proximity_to_passed_pawns(board, side, p)
+        - evaluate_king_proximity_to_passed_pawns(board, enemy, p)
+}
+
+/// King and pawn against king, from White's view: the pawn side wants its
+/// king nearer the pawn than the defender's, the opposition in front of the
+/// pawn, and best of all a defender that can't get back into the pawn's
+/// square in time.
+fn eval_kpk(board: &Board, p: &TuningParams) -> i32 {
+    let strong = if board.pieces(Piece::Pawn, Color::White) != 0 {
+        Color::White
+    } else {
+        Color::Black
+    };
+    let weak = strong.opposite();
+    let pawn = board.pieces(Piece::Pawn, strong).trailing_zeros() as usize;
+    let strong_king = board.king_square(strong).index() as usize;
+    let weak_king = board.king_square(weak).index() as usize;
+
+    let (pawn_rank, file) = ((pawn / 8) as i32, pawn % 8);
+    let (promotion, mut steps, forward) = match strong {
+        Color::White => (56 + file, 7 - pawn_rank, 1),
+        Color::Black => (file, pawn_rank, -1),
+    };
+    // The first move can be a double step
+    if steps == 6 {
+        steps = 5;
+    }
+
+    let mut score = p.kpk_king_distance_weight
+        * (chebyshev_distance(weak_king, pawn) - chebyshev_distance(strong_king, pawn));
+
+    // Kings face each other on a file with one square between them, the
+    // strong one in front of its pawn, and the defender has to give way
+    let strong_rank = (strong_king / 8) as i32;
+    let weak_rank = (weak_king / 8) as i32;
+    if strong_king % 8 == weak_king % 8
+        && weak_rank - strong_rank == 2 * forward
+        && (strong_rank - pawn_rank) * forward > 0
+        && board.side_to_move == weak
+    {
+        score += p.kpk_opposition_bonus;
+    }
+
+    // Rule of the square: the defender moving first gains a tempo
+    let tempo = (board.side_to_move == weak) as i32;
+    if chebyshev_distance(weak_king, promotion) - tempo > steps {
+        score += p.kpk_unstoppable_bonus;
+    }
+
+    match strong {
+        Color::White => score,
+        Color::Black => -score,
+    }
+}
+
+/// Endgames only (phase below 8): `color`'s king should escort its passed
+/// pawns and the enemy king stay away from them. Each passer earns
+/// `king_passed_proximity_bonus / (distance + 1)` for the own king plus
+/// `enemy_king_passed_distance_bonus` per square to the enemy king.
+fn evaluate_king_proximity_to_passed_pawns(board: &Board, color: Color, p: &TuningParams) -> i32 {
+    if calculate_phase(board) >= 8 {
+        return 0;
+    }
+    let passed = board.passed_pawns(color);
+    if passed == 0 {
+        return 0;
+    }
+    let own_king = board.king_square(color).index() as usize;
+    let enemy_king = board.king_square(color.opposite()).index() as usize;
+
+    BitIter(passed)
+        .map(|sq| {
+            p.king_passed_proximity_bonus / (chebyshev_distance(own_king, sq) + 1)
+                + p.enemy_king_passed_distance_bonus * chebyshev_distance(enemy_king, sq)
+        })
+        .sum()
+}
+
 /// Helper: Calculate total material value for a color (tapered)
 fn calculate_material(board: &Board, color: Color) -> i32 {
     let phase = calculate_phase(board);
@@ -160,7 +287,7 @@ fn calculate_material(board: &Board, color: Color) -> i32 {
         Piece::Queen,
     ] {
         let (mg_val, eg_val) = get_piece_value(piece_type);
-        let count = board.pieces(piece_type, color).count_ones() as i32;
+        let count = board.count_pieces(color, piece_type) as i32;
         mg_total += mg_val * count;
         eg_total += eg_val * count;
     }
@@ -169,43 +296,111 @@ fn calculate_material(board: &Board, color: Color) -> i32 {
 }
 
 pub fn static_eval(board: &Board, tables: &MagicTables, alpha: i32, beta: i32) -> i32 {
+    static_eval_with(board, tables, alpha, beta, &TuningParams::DEFAULT)
+}
+
+/// `static_eval` with an explicit parameter set (SPSA tuning, `--tune-params`).
+pub fn static_eval_with(
+    board: &Board,
+    tables: &MagicTables,
+    alpha: i32,
+    beta: i32,
+    p: &TuningParams,
+) -> i32 {
     let side = board.side_to_move;
     let enemy = side.opposite();
 
-    // 1. Perspective Base Score
+    // 1. Perspective Base Score (material + PSQT + tempo only, so the lazy
+    // cutoff below never depends on the terms it is deciding whether to compute)
     let color_multiplier = if side == Color::White { 1 } else { -1 };
-    let mut score = pesto_eval(board) * color_multiplier;
+    let mut score = board.evaluate_fast() * color_multiplier + p.tempo_bonus;
+
+    // Endgames with their own scoring skip the general terms, all but the
+    // king-and-pawn ones that KPK is made of
+    match board.endgame_type() {
+        EndgameType::Kpk => {
+            return score + eval_kpk(board, p) * color_multiplier + king_pawn_terms(board, side, p);
+        }
+        EndgameType::Krk | EndgameType::Kqk => {
+            return score + mop_up_eval(board, side) - mop_up_eval(board, enemy);
+        }
+        _ => {}
+    }
 
     // 2. Lazy Cutoffs
-    if score - LAZY_EVAL_MARGIN >= beta {
+    if score - p.lazy_eval_margin >= beta {
         return score;
     }
-    if score + LAZY_EVAL_MARGIN <= alpha {
+    if score + p.lazy_eval_margin <= alpha {
         return score;
     }
 
     // 3. Positional Terms
-    score += eval_mobility(board, tables, side) - eval_mobility(board, tables, enemy);
+    let phase = board.phase();
+    score += eval_mobility(board, tables, side, p) - eval_mobility(board, tables, enemy, p);
 
     // [MODIFIED] Uses updated evaluate_pawn_structure with blocked logic
-    score += evaluate_pawn_structure(board) * color_multiplier;
+    score += pawn_structure(board, p) * color_multiplier;
+
+    // Rooks on open / semi-open files
+    score += evaluate_rook_file_bonuses(board, p) * color_multiplier;
+
+    // Bishop pair and bad bishops
+    score += evaluate_bishop_pair(board, side, p) - evaluate_bishop_pair(board, enemy, p);
+    score += evaluate_bad_bishop(board, side, p) - evaluate_bad_bishop(board, enemy, p);
+
+    // Knight outposts
+    score += evaluate_knight_outposts(board, side, p) - evaluate_knight_outposts(board, enemy, p);
+
+    // Rooks on the seventh rank
+    score += evaluate_rook_seventh(board, side, p) - evaluate_rook_seventh(board, enemy, p);
+
+    // Central space behind and around the pawns
+    score += evaluate_space_advantage(board, side, p) - evaluate_space_advantage(board, enemy, p);
 
     // 4. Phased King Safety (Attacks)
     // Subtracting enemy attacks on our king, adding our attacks on theirs.
-    score += calculate_phased_safety(board, side, tables)
-        - calculate_phased_safety(board, enemy, tables);
+    score += calculate_phased_safety(board, side, tables, phase, p)
+        - calculate_phased_safety(board, enemy, tables, phase, p);
 
     // [NEW] 5. King Shield Safety (Passive)
-    score += evaluate_king_shield(board, side) - evaluate_king_shield(board, enemy);
+    score += evaluate_king_shield(board, side, p) - evaluate_king_shield(board, enemy, p);
 
     // 6. Mop-Up Evaluation (Endgame King Confinement)
     score += mop_up_eval(board, side);
+    score += king_pawn_terms(board, side, p);
+
+    // 7. King Tropism (middlegame only, tapered out as material comes off)
+    score += (evaluate_king_tropism(board, side, p) - evaluate_king_tropism(board, enemy, p))
+        * phase
+        / TOTAL_PHASE;
+
+    // 8. Hanging pieces: attacked and not defended. Each side's attack map
+    // is built once and shared by both calls
+    let side_attacks = board.generate_attacks(side, tables);
+    let enemy_attacks = board.generate_attacks(enemy, tables);
+    score += evaluate_hanging_pieces(board, side, enemy_attacks, side_attacks, p)
+        - evaluate_hanging_pieces(board, enemy, side_attacks, enemy_attacks, p);
 
     score
 }
 
+/// `hanging_piece_penalty` for each of `color`'s non-pawn pieces that is
+/// attacked and undefended (see `Board::threatened_pieces_with`).
+fn evaluate_hanging_pieces(
+    board: &Board,
+    color: Color,
+    attackers: u64,
+    defenders: u64,
+    p: &TuningParams,
+) -> i32 {
+    let hanging = board.threatened_pieces_with(color, attackers, defenders)
+        & !board.pieces(Piece::Pawn, color);
+    hanging.count_ones() as i32 * p.hanging_piece_penalty
+}
+
 // [NEW] Calculates bonus for friendly pawns in front of the King
-fn evaluate_king_shield(board: &Board, color: Color) -> i32 {
+fn evaluate_king_shield(board: &Board, color: Color, p: &TuningParams) -> i32 {
     let king_sq_mask = board.pieces(Piece::King, color);
     if king_sq_mask == 0 {
         return 0;
@@ -246,19 +441,24 @@ fn evaluate_king_shield(board: &Board, color: Color) -> i32 {
             }
         }
 
-        score += shield_count * KING_SHIELD_BONUS;
+        score += shield_count * p.king_shield_bonus;
 
         // Penalty for ZERO shield on an open file
         if shield_count == 0 {
-            score += KING_OPEN_FILE_PENALTY;
+            score += p.king_open_file_penalty;
         }
     }
     score
 }
 
-fn calculate_phased_safety(board: &Board, color: Color, tables: &MagicTables) -> i32 {
+fn calculate_phased_safety(
+    board: &Board,
+    color: Color,
+    tables: &MagicTables,
+    phase: i32, // 24 = MG, 0 = EG
+    p: &TuningParams,
+) -> i32 {
     let enemy = color.opposite();
-    let phase = calculate_phase(board); // 24 = MG, 0 = EG
 
     let attack_count = count_king_zone_attacks(board, enemy, color, tables);
     if attack_count == 0 {
@@ -266,7 +466,7 @@ fn calculate_phased_safety(board: &Board, color: Color, tables: &MagicTables) ->
     }
 
     // Tapering logic: Penalty is 100% at phase 24 and 0% at phase 0.
-    let penalty = (attack_count * KING_ZONE_ATTACK_PENALTY * phase) / 24;
+    let penalty = (attack_count * p.king_zone_attack_penalty * phase) / 24;
 
     -penalty // Return as negative value (a penalty)
 }
@@ -350,31 +550,17 @@ fn count_king_zone_attacks(
     attack_count
 }
 
-fn eval_mobility(board: &Board, tables: &MagicTables, color: Color) -> i32 {
-    let mut score = 0;
-    let us_bb = board.occupancy(color);
-    let them_bb = board.opponent_occupancy(color);
-    let occupied = us_bb | them_bb;
-    let idx = color as usize;
-
-    // Bishops
-    for sq in BitIter(board.piece_bb[idx][Piece::Bishop as usize]) {
-        let attacks = tables.bishop.get_attacks(sq, occupied);
-        score += (attacks & !us_bb).count_ones() as i32 * MOBILITY_WEIGHT;
-    }
-
-    // Rooks
-    for sq in BitIter(board.piece_bb[idx][Piece::Rook as usize]) {
-        let attacks = tables.rook.get_attacks(sq, occupied);
-        score += (attacks & !us_bb).count_ones() as i32 * 3;
-    }
-
-    score
+fn eval_mobility(board: &Board, tables: &MagicTables, color: Color, p: &TuningParams) -> i32 {
+    let bishops = board.count_mobility(Piece::Bishop, color, tables) as i32;
+    let rooks = board.count_mobility(Piece::Rook, color, tables) as i32;
+    bishops * p.mobility_weight + rooks * p.rook_mobility_weight
 }
 
 // --- BITWISE HELPERS ---
 const FILE_A: u64 = 0x0101010101010101;
 const FILE_H: u64 = 0x8080808080808080;
+const LIGHT_SQUARES: u64 = 0x55AA55AA55AA55AA;
+const DARK_SQUARES: u64 = !LIGHT_SQUARES;
 
 /// Chebyshev distance (king distance) between two squares
 #[inline(always)]
@@ -400,6 +586,10 @@ fn file_fill(mut pawns: u64) -> u64 {
 }
 
 pub fn evaluate_pawn_structure(board: &Board) -> i32 {
+    pawn_structure(board, &TuningParams::DEFAULT)
+}
+
+fn pawn_structure(board: &Board, p: &TuningParams) -> i32 {
     let wp = board.pieces(Piece::Pawn, Color::White);
     let bp = board.pieces(Piece::Pawn, Color::Black);
 
@@ -415,8 +605,8 @@ pub fn evaluate_pawn_structure(board: &Board) -> i32 {
     let w_doubled_mask = wp & (wp >> 8);
     let b_doubled_mask = bp & (bp << 8);
 
-    white_score += (w_doubled_mask.count_ones() as i32) * DOUBLED_PAWN_PENALTY;
-    black_score += (b_doubled_mask.count_ones() as i32) * DOUBLED_PAWN_PENALTY;
+    white_score += (w_doubled_mask.count_ones() as i32) * p.doubled_pawn_penalty;
+    black_score += (b_doubled_mask.count_ones() as i32) * p.doubled_pawn_penalty;
 
     // --- 2. Isolated Pawns (Bitwise Parallel) ---
     let w_file_mask = file_fill(wp);
@@ -431,8 +621,38 @@ pub fn evaluate_pawn_structure(board: &Board) -> i32 {
     let w_isolated_pawns = wp & w_isolated_files;
     let b_isolated_pawns = bp & b_isolated_files;
 
-    white_score += (w_isolated_pawns.count_ones() as i32) * ISOLATED_PAWN_PENALTY;
-    black_score += (b_isolated_pawns.count_ones() as i32) * ISOLATED_PAWN_PENALTY;
+    white_score += (w_isolated_pawns.count_ones() as i32) * p.isolated_pawn_penalty;
+    black_score += (b_isolated_pawns.count_ones() as i32) * p.isolated_pawn_penalty;
+
+    // --- 2b. Connected Pawns & Chains ---
+    // A pawn is connected if a friendly pawn attacks its square. The squares
+    // that attack `sq` for White are exactly the squares a Black pawn on `sq` attacks.
+    let mut w_defended = 0u64;
+    for sq in BitIter(wp) {
+        if BLACK_PAWN_ATTACKS[sq] & wp != 0 {
+            w_defended |= 1u64 << sq;
+            white_score += p.connected_pawn_bonus[sq / 8];
+        }
+    }
+    let mut b_defended = 0u64;
+    for sq in BitIter(bp) {
+        if WHITE_PAWN_ATTACKS[sq] & bp != 0 {
+            b_defended |= 1u64 << sq;
+            black_score += p.connected_pawn_bonus[7 - sq / 8];
+        }
+    }
+
+    // Chain: a pawn defended by a pawn that is itself defended (three links)
+    for sq in BitIter(w_defended) {
+        if BLACK_PAWN_ATTACKS[sq] & w_defended != 0 {
+            white_score += p.pawn_chain_bonus;
+        }
+    }
+    for sq in BitIter(b_defended) {
+        if WHITE_PAWN_ATTACKS[sq] & b_defended != 0 {
+            black_score += p.pawn_chain_bonus;
+        }
+    }
 
     // --- 3. Passed Pawns ---
     // A pawn is passed if no enemy pawns can block or capture it (no enemy pawns
@@ -443,28 +663,10 @@ pub fn evaluate_pawn_structure(board: &Board) -> i32 {
     while w_iter != 0 {
         let sq = pop_lsb(&mut w_iter) as usize;
         let rank = sq / 8;
-        let file = sq % 8;
-
-        // Front span: all squares on ranks ahead of this pawn
-        // For white, "ahead" means higher ranks (toward rank 8)
-        let front_mask = if rank < 7 {
-            !((1u64 << ((rank + 1) * 8)) - 1)
-        } else {
-            0
-        };
-
-        // File mask: current file + adjacent files
-        let mut file_mask = FILE_A << file;
-        if file > 0 {
-            file_mask |= FILE_A << (file - 1);
-        }
-        if file < 7 {
-            file_mask |= FILE_A << (file + 1);
-        }
 
         // If no black pawns in the "cone" ahead, it's passed
-        if (bp & file_mask & front_mask) == 0 {
-            let mut bonus = PASSED_PAWN_BONUS[rank];
+        if bp & passed_pawn_span(sq, Color::White) == 0 {
+            let mut bonus = p.passed_pawn_bonus[rank];
 
             // [NEW] Blocked Penalty (White)
             // If rank < 7, check the square immediately in front
@@ -472,7 +674,7 @@ pub fn evaluate_pawn_structure(board: &Board) -> i32 {
                 let stop_sq = sq + 8;
                 // Check if black piece is there
                 if (board.occupancy(Color::Black) & (1u64 << stop_sq)) != 0 {
-                    bonus += BLOCKED_PASSER_PENALTY;
+                    bonus += p.blocked_passer_penalty;
                 }
             }
 
@@ -495,29 +697,11 @@ pub fn evaluate_pawn_structure(board: &Board) -> i32 {
     while b_iter != 0 {
         let sq = pop_lsb(&mut b_iter) as usize;
         let rank = sq / 8;
-        let file = sq % 8;
-
-        // Front span: all squares on ranks ahead of this pawn
-        // For black, "ahead" means lower ranks (toward rank 1)
-        let front_mask = if rank > 0 {
-            (1u64 << (rank * 8)) - 1
-        } else {
-            0
-        };
-
-        // File mask: current file + adjacent files
-        let mut file_mask = FILE_A << file;
-        if file > 0 {
-            file_mask |= FILE_A << (file - 1);
-        }
-        if file < 7 {
-            file_mask |= FILE_A << (file + 1);
-        }
 
         // If no white pawns in the "cone" ahead, it's passed
-        if (wp & file_mask & front_mask) == 0 {
+        if wp & passed_pawn_span(sq, Color::Black) == 0 {
             // Mirror rank for bonus (rank 1 for black = close to promotion)
-            let mut bonus = PASSED_PAWN_BONUS[7 - rank];
+            let mut bonus = p.passed_pawn_bonus[7 - rank];
 
             // [NEW] Blocked Penalty (Black)
             // If rank > 0, check the square immediately in front (index - 8)
@@ -525,7 +709,7 @@ pub fn evaluate_pawn_structure(board: &Board) -> i32 {
                 let stop_sq = sq - 8;
                 // Check if white piece is there
                 if (board.occupancy(Color::White) & (1u64 << stop_sq)) != 0 {
-                    bonus += BLOCKED_PASSER_PENALTY;
+                    bonus += p.blocked_passer_penalty;
                 }
             }
 
@@ -546,6 +730,398 @@ pub fn evaluate_pawn_structure(board: &Board) -> i32 {
     white_score - black_score
 }
 
+/// Squares ahead of a `color` pawn on `sq`, on its own and the adjacent
+/// files: the pawn is passed when no enemy pawn stands on any of them.
+#[inline(always)]
+fn passed_pawn_span(sq: usize, color: Color) -> u64 {
+    let rank = sq / 8;
+    let file = sq % 8;
+
+    // Front span: all squares on ranks ahead of this pawn
+    let front_mask = match color {
+        Color::White if rank < 7 => !((1u64 << ((rank + 1) * 8)) - 1),
+        Color::Black if rank > 0 => (1u64 << (rank * 8)) - 1,
+        _ => 0,
+    };
+
+    // File mask: current file + adjacent files
+    let mut file_mask = FILE_A << file;
+    if file > 0 {
+        file_mask |= FILE_A << (file - 1);
+    }
+    if file < 7 {
+        file_mask |= FILE_A << (file + 1);
+    }
+    front_mask & file_mask
+}
+
+/// Rook file bonuses from White's perspective (White minus Black).
+/// Open file: no pawns at all. Semi-open: only enemy pawns.
+/// A rook behind its own rammed pawn is penalized instead.
+fn evaluate_rook_file_bonuses(board: &Board, p: &TuningParams) -> i32 {
+    let wp = board.pieces(Piece::Pawn, Color::White);
+    let bp = board.pieces(Piece::Pawn, Color::Black);
+
+    let w_files = board.pawn_file_mask(Color::White);
+    let b_files = board.pawn_file_mask(Color::Black);
+
+    // Files where a friendly pawn is blocked head-on by an enemy pawn
+    let w_rammed_files = file_fill(wp & (bp >> 8));
+    let b_rammed_files = file_fill(bp & (wp << 8));
+
+    let rook_score = |rooks: u64, own_files: u64, enemy_files: u64, rammed_files: u64| -> i32 {
+        let mut score = 0;
+        for sq in BitIter(rooks) {
+            let bit = 1u64 << sq;
+            if bit & own_files == 0 {
+                score += if bit & enemy_files == 0 {
+                    p.rook_open_file_bonus
+                } else {
+                    p.rook_semi_open_bonus
+                };
+            } else if bit & rammed_files != 0 {
+                score += p.rook_blocked_file_penalty;
+            }
+        }
+        score
+    };
+
+    let white = rook_score(
+        board.pieces(Piece::Rook, Color::White),
+        w_files,
+        b_files,
+        w_rammed_files,
+    );
+    let black = rook_score(
+        board.pieces(Piece::Rook, Color::Black),
+        b_files,
+        w_files,
+        b_rammed_files,
+    );
+
+    white - black
+}
+
+/// Tapered bonus for owning two or more bishops (worth more as the board opens up).
+fn evaluate_bishop_pair(board: &Board, color: Color, p: &TuningParams) -> i32 {
+    if board.count_pieces(color, Piece::Bishop) < 2 {
+        return 0;
+    }
+    let phase = calculate_phase(board);
+    (p.bishop_pair_bonus_mg * phase + p.bishop_pair_bonus_eg * (TOTAL_PHASE - phase)) / TOTAL_PHASE
+}
+
+/// Penalty for each bishop whose own pawns mostly sit on its square color.
+fn evaluate_bad_bishop(board: &Board, color: Color, p: &TuningParams) -> i32 {
+    let pawns = board.pieces(Piece::Pawn, color);
+    let total = board.count_pieces(color, Piece::Pawn);
+    if total == 0 {
+        return 0;
+    }
+
+    let mut score = 0;
+    for sq in BitIter(board.pieces(Piece::Bishop, color)) {
+        let same_color = if (1u64 << sq) & LIGHT_SQUARES != 0 {
+            LIGHT_SQUARES
+        } else {
+            DARK_SQUARES
+        };
+        if (pawns & same_color).count_ones() * 2 > total {
+            score += p.bad_bishop_penalty;
+        }
+    }
+    score
+}
+
+/// Tapered bonus for knights on outposts: a square in the enemy half that is
+/// defended by a friendly pawn and can never be challenged by an enemy pawn
+/// (no enemy pawns ahead on the adjacent files).
+fn evaluate_knight_outposts(board: &Board, color: Color, p: &TuningParams) -> i32 {
+    let own_pawns = board.pieces(Piece::Pawn, color);
+    let enemy_pawns = board.pieces(Piece::Pawn, color.opposite());
+
+    let mut outposts = 0;
+    for sq in BitIter(board.pieces(Piece::Knight, color)) {
+        let rank = sq / 8;
+        let file = sq % 8;
+
+        let in_enemy_half = match color {
+            Color::White => rank >= 4,
+            Color::Black => rank <= 3,
+        };
+        if !in_enemy_half {
+            continue;
+        }
+
+        // A friendly pawn defends `sq` iff an enemy pawn on `sq` would attack it
+        let supported = pawn_attacks(sq as u8, color.opposite()) & own_pawns != 0;
+        if !supported {
+            continue;
+        }
+
+        let mut adjacent_files = 0u64;
+        if file > 0 {
+            adjacent_files |= FILE_A << (file - 1);
+        }
+        if file < 7 {
+            adjacent_files |= FILE_A << (file + 1);
+        }
+        let ahead = match color {
+            Color::White => !((1u64 << ((rank + 1) * 8)) - 1),
+            Color::Black => (1u64 << (rank * 8)) - 1,
+        };
+        if enemy_pawns & adjacent_files & ahead == 0 {
+            outposts += 1;
+        }
+    }
+
+    if outposts == 0 {
+        return 0;
+    }
+    let phase = calculate_phase(board);
+    outposts
+        * (p.knight_outpost_bonus_mg * phase + p.knight_outpost_bonus_eg * (TOTAL_PHASE - phase))
+        / TOTAL_PHASE
+}
+
+/// Bonus for rooks on the opponent's second rank, counted only when there is
+/// something to attack there (enemy pawns) or the enemy king is cut off on
+/// its back rank. Two rooks on that rank earn an extra bonus.
+fn evaluate_rook_seventh(board: &Board, color: Color, p: &TuningParams) -> i32 {
+    let enemy = color.opposite();
+    let (seventh, eighth) = match color {
+        Color::White => (RANK7, RANK8),
+        Color::Black => (RANK2, RANK1),
+    };
+
+    let rooks = board.pieces(Piece::Rook, color) & seventh;
+    if rooks == 0 {
+        return 0;
+    }
+
+    let targets = board.pieces(Piece::Pawn, enemy) & seventh != 0;
+    let king_cut_off = board.pieces(Piece::King, enemy) & eighth != 0;
+    if !targets && !king_cut_off {
+        return 0;
+    }
+
+    let phase = calculate_phase(board);
+    let per_rook = (p.rook_seventh_bonus_mg * phase
+        + p.rook_seventh_bonus_eg * (TOTAL_PHASE - phase))
+        / TOTAL_PHASE;
+
+    let count = rooks.count_ones() as i32;
+    let mut score = count * per_rook;
+    if count >= 2 {
+        score += p.rook_pair_seventh_bonus;
+    }
+    score
+}
+
+/// Files c-f of ranks 1-4 for White, ranks 5-8 for Black.
+const WHITE_SPACE_ZONE: u64 = 0x0000_0000_3C3C_3C3C;
+const BLACK_SPACE_ZONE: u64 = 0x3C3C_3C3C_0000_0000;
+
+/// Tapered bonus per square of `color`'s space zone (the central files of
+/// its own half) that its pawns hold: occupied by a pawn, attacked by one,
+/// or up to three squares behind one on the same file. Squares holding or
+/// attacked by an enemy pawn don't count.
+fn evaluate_space_advantage(board: &Board, color: Color, p: &TuningParams) -> i32 {
+    let own_pawns = board.pieces(Piece::Pawn, color);
+    let enemy = color.opposite();
+    let enemy_pawns = board.pieces(Piece::Pawn, enemy);
+
+    let (zone, behind) = match color {
+        Color::White => (
+            WHITE_SPACE_ZONE,
+            (own_pawns >> 8) | (own_pawns >> 16) | (own_pawns >> 24),
+        ),
+        Color::Black => (
+            BLACK_SPACE_ZONE,
+            (own_pawns << 8) | (own_pawns << 16) | (own_pawns << 24),
+        ),
+    };
+
+    let mut own_attacks = 0u64;
+    for sq in BitIter(own_pawns) {
+        own_attacks |= pawn_attacks(sq as u8, color);
+    }
+    let mut enemy_attacks = 0u64;
+    for sq in BitIter(enemy_pawns) {
+        enemy_attacks |= pawn_attacks(sq as u8, enemy);
+    }
+
+    let held = (own_pawns | own_attacks | behind) & zone & !(enemy_pawns | enemy_attacks);
+    let squares = held.count_ones() as i32;
+    let phase = calculate_phase(board);
+    squares * (p.space_bonus_mg * phase + p.space_bonus_eg * (TOTAL_PHASE - phase)) / TOTAL_PHASE
+}
+
+/// Sum of `attacker`'s non-pawn pieces' closeness to the enemy king.
+/// Each piece earns `weight / (distance + 1)` (Chebyshev distance).
+fn evaluate_king_tropism(board: &Board, attacker: Color, p: &TuningParams) -> i32 {
+    let enemy_king = board.pieces(Piece::King, attacker.opposite());
+    if enemy_king == 0 {
+        return 0;
+    }
+    let king_sq = enemy_king.trailing_zeros() as usize;
+
+    let mut score = 0;
+    for (piece, weight) in [
+        (Piece::Knight, p.tropism_weight_minor),
+        (Piece::Bishop, p.tropism_weight_minor),
+        (Piece::Rook, p.tropism_weight_rook),
+        (Piece::Queen, p.tropism_weight_queen),
+    ] {
+        for sq in BitIter(board.pieces(piece, attacker)) {
+            score += weight / (chebyshev_distance(sq, king_sq) + 1);
+        }
+    }
+    score
+}
+
+impl Board {
+    /// Game phase from the remaining non-pawn material: `TOTAL_PHASE` (24)
+    /// with all pieces on the board, 0 in a pawn or bare-king endgame.
+    #[inline]
+    pub fn phase(&self) -> i32 {
+        calculate_phase(self)
+    }
+
+    /// Every square on a file holding at least one `color` pawn.
+    #[inline]
+    pub fn pawn_file_mask(&self, color: Color) -> u64 {
+        file_fill(self.pieces(Piece::Pawn, color))
+    }
+
+    /// `color`'s passed pawns: no enemy pawn ahead of them on their own or
+    /// an adjacent file.
+    pub fn passed_pawns(&self, color: Color) -> u64 {
+        let enemy_pawns = self.pieces(Piece::Pawn, color.opposite());
+        let mut passed = 0;
+        for sq in BitIter(self.pieces(Piece::Pawn, color)) {
+            if enemy_pawns & passed_pawn_span(sq, color) == 0 {
+                passed |= 1u64 << sq;
+            }
+        }
+        passed
+    }
+
+    pub fn count_passed_pawns(&self, color: Color) -> u32 {
+        self.passed_pawns(color).count_ones()
+    }
+
+    /// `color`'s pawn nearest promotion, passed or not. Ties on the same
+    /// rank go to the lower file for Black and the higher file for White.
+    pub fn most_advanced_pawn(&self, color: Color) -> Option<Square> {
+        let pawns = self.pieces(Piece::Pawn, color);
+        if pawns == 0 {
+            return None;
+        }
+        let sq = match color {
+            Color::White => 63 - pawns.leading_zeros(),
+            Color::Black => pawns.trailing_zeros(),
+        };
+        Some(Square::from_index(sq as u8))
+    }
+
+    /// Every square on a file with no pawns of either color.
+    #[inline]
+    pub fn open_files(&self) -> u64 {
+        !(self.pawn_file_mask(Color::White) | self.pawn_file_mask(Color::Black))
+    }
+
+    /// Every square on a file where `color` has no pawns but the opponent does.
+    #[inline]
+    pub fn half_open_files(&self, color: Color) -> u64 {
+        !self.pawn_file_mask(color) & self.pawn_file_mask(color.opposite())
+    }
+
+    /// `phase()` scaled to 0.0 (endgame) ..= 1.0 (opening).
+    #[inline]
+    pub fn phase_ratio(&self) -> f32 {
+        self.phase() as f32 / TOTAL_PHASE as f32
+    }
+
+    /// How many squares `color`'s pieces of type `piece` can move to, summed
+    /// over all of them. Pseudo-legal: friendly-occupied squares are excluded
+    /// but pins and checks are ignored. Pawns count their pushes (single and
+    /// double) plus captures of enemy pieces; en passant is not counted.
+    pub fn count_mobility(&self, piece: Piece, color: Color, tables: &MagicTables) -> u32 {
+        let us = self.occupancy(color);
+        let occupied = self.occupied();
+        let pieces = self.pieces(piece, color);
+
+        if piece == Piece::Pawn {
+            let empty = !occupied;
+            let enemy = self.opponent_occupancy(color);
+            let (single, double) = match color {
+                Color::White => {
+                    let single = (pieces << 8) & empty;
+                    (single, ((single & (RANK2 << 8)) << 8) & empty)
+                }
+                Color::Black => {
+                    let single = (pieces >> 8) & empty;
+                    (single, ((single & (RANK7 >> 8)) >> 8) & empty)
+                }
+            };
+            let captures: u32 = BitIter(pieces)
+                .map(|sq| (pawn_attacks(sq as u8, color) & enemy).count_ones())
+                .sum();
+            return single.count_ones() + double.count_ones() + captures;
+        }
+
+        BitIter(pieces)
+            .map(|sq| {
+                let attacks = match piece {
+                    Piece::Knight => KNIGHT_ATTACKS[sq],
+                    Piece::Bishop => tables.bishop.get_attacks(sq, occupied),
+                    Piece::Rook => tables.rook.get_attacks(sq, occupied),
+                    Piece::Queen => {
+                        tables.bishop.get_attacks(sq, occupied)
+                            | tables.rook.get_attacks(sq, occupied)
+                    }
+                    Piece::King => KING_ATTACKS[sq],
+                    Piece::Pawn => unreachable!(),
+                };
+                (attacks & !us).count_ones()
+            })
+            .sum()
+    }
+
+    /// `count_mobility` for every piece type, in `ALL_PIECES` order.
+    pub fn piece_mobility_summary(&self, color: Color, tables: &MagicTables) -> [(Piece, u32); 6] {
+        ALL_PIECES.map(|piece| (piece, self.count_mobility(piece, color, tables)))
+    }
+
+    /// The side to move's eval minus the eval of the same position with the
+    /// opponent to move, both from the side to move's point of view and with
+    /// nothing else changed. The eval is otherwise symmetric, so this is the
+    /// tempo swing: `2 * tempo_bonus` with the default parameters.
+    pub fn flipped_stm_eval(&self, tables: &MagicTables) -> i32 {
+        let ours = static_eval(self, tables, -i32::MAX, i32::MAX);
+        let mut flipped = self.clone();
+        flipped.side_to_move = self.side_to_move.opposite();
+        let theirs = static_eval(&flipped, tables, -i32::MAX, i32::MAX);
+        ours + theirs
+    }
+
+    /// Material + PSQT only (White's point of view): the score the lazy-eval
+    /// margin in `static_eval` is measured against. Reads the accumulator
+    /// with `incremental_eval`, otherwise the same as `pesto_eval`.
+    #[inline]
+    pub fn evaluate_fast(&self) -> i32 {
+        #[cfg(feature = "incremental_eval")]
+        {
+            self.incremental_eval()
+        }
+        #[cfg(not(feature = "incremental_eval"))]
+        {
+            pesto_eval(self)
+        }
+    }
+}
+
 // Renamed from evaluate to pesto_eval
 pub fn pesto_eval(board: &Board) -> i32 {
     let mut mg_score = 0;
@@ -606,8 +1182,8 @@ pub fn eval_material(board: &Board) -> i32 {
     ] {
         let (mg_val, eg_val) = get_piece_value(piece_type);
 
-        let w_count = board.pieces(piece_type, Color::White).count_ones() as i32;
-        let b_count = board.pieces(piece_type, Color::Black).count_ones() as i32;
+        let w_count = board.count_pieces(Color::White, piece_type) as i32;
+        let b_count = board.count_pieces(Color::Black, piece_type) as i32;
 
         mg_score += mg_val * (w_count - b_count);
         eg_score += eg_val * (w_count - b_count);
@@ -657,6 +1233,233 @@ mod tests {
     use crate::moves::magic::loader::load_magic_tables;
     use std::str::FromStr;
 
+    #[test]
+    fn test_board_phase() {
+        assert_eq!(Board::new().phase(), TOTAL_PHASE);
+        assert_eq!(Board::new().phase_ratio(), 1.0);
+
+        let bare = Board::from_str("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(bare.phase(), 0);
+        assert_eq!(bare.phase_ratio(), 0.0);
+
+        let rooks = Board::from_str("r3k3/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+        assert_eq!(rooks.phase(), 2 * ROOK_PHASE);
+    }
+
+    #[test]
+    fn test_evaluate_fast_is_pesto_eval() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 0 1",
+        ] {
+            let board = Board::from_str(fen).unwrap();
+            assert_eq!(board.evaluate_fast(), pesto_eval(&board), "{}", fen);
+        }
+    }
+
+    #[test]
+    fn test_pesto_eval_color_symmetry() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        ] {
+            let board = Board::from_str(fen).expect("Invalid FEN");
+            let mirrored = board.mirror_colors();
+            assert_eq!(
+                pesto_eval(&board),
+                -pesto_eval(&mirrored),
+                "PSQT asymmetry detected for {}",
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn test_rook_open_file_bonus() {
+        // Rook on d1 with the d-file empty vs rook on e1 behind its own e2 pawn
+        let open = Board::from_str("4k3/8/8/8/8/8/4P3/3RK3 w - - 0 1").expect("Invalid FEN");
+        let closed = Board::from_str("3k4/8/8/8/8/8/4P3/3KR3 w - - 0 1").expect("Invalid FEN");
+
+        assert_eq!(
+            evaluate_rook_file_bonuses(&open, &TuningParams::DEFAULT),
+            TuningParams::DEFAULT.rook_open_file_bonus
+        );
+        assert_eq!(
+            evaluate_rook_file_bonuses(&closed, &TuningParams::DEFAULT),
+            0
+        );
+        assert!(
+            evaluate_rook_file_bonuses(&open, &TuningParams::DEFAULT)
+                > evaluate_rook_file_bonuses(&closed, &TuningParams::DEFAULT)
+        );
+    }
+
+    #[test]
+    fn test_rook_semi_open_and_blocked_files() {
+        // White rook on d1 facing only a black d-pawn; black rook on e8 behind its rammed e-pawn
+        let board =
+            Board::from_str("4r1k1/8/8/3p4/4p3/4P3/8/3R2K1 w - - 0 1").expect("Invalid FEN");
+        assert_eq!(
+            evaluate_rook_file_bonuses(&board, &TuningParams::DEFAULT),
+            TuningParams::DEFAULT.rook_semi_open_bonus
+                - TuningParams::DEFAULT.rook_blocked_file_penalty
+        );
+    }
+
+    #[test]
+    fn test_bishop_pair_bonus() {
+        let pair = Board::from_str("4k3/8/8/8/8/8/8/2B1KB2 w - - 0 1").expect("Invalid FEN");
+        let mixed = Board::from_str("4k3/8/8/8/8/8/8/2B1KN2 w - - 0 1").expect("Invalid FEN");
+        let tables = load_magic_tables();
+
+        assert!(evaluate_bishop_pair(&pair, Color::White, &TuningParams::DEFAULT) > 0);
+        assert_eq!(
+            evaluate_bishop_pair(&mixed, Color::White, &TuningParams::DEFAULT),
+            0
+        );
+        assert!(
+            static_eval(&pair, &tables, -i32::MAX, i32::MAX)
+                > static_eval(&mixed, &tables, -i32::MAX, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_bad_bishop_penalty() {
+        // Light-squared bishop on f1 with all four pawns (a2, c2, e2, g2) on light squares
+        let bad = Board::from_str("4k3/8/8/8/8/8/P1P1P1P1/4KB2 w - - 0 1").expect("Invalid FEN");
+        // Same pawns with a dark-squared bishop on c1 instead.
+        let good = Board::from_str("4k3/8/8/8/8/8/P1P1P1P1/2B1K3 w - - 0 1").expect("Invalid FEN");
+
+        assert_eq!(
+            evaluate_bad_bishop(&bad, Color::White, &TuningParams::DEFAULT),
+            TuningParams::DEFAULT.bad_bishop_penalty
+        );
+        assert_eq!(
+            evaluate_bad_bishop(&good, Color::White, &TuningParams::DEFAULT),
+            0
+        );
+    }
+
+    #[test]
+    fn test_knight_outpost_bonus() {
+        // Knight on d5 supported by c4, no black pawn can ever attack it
+        let outpost = Board::from_str("4k3/p6p/8/3N4/2P5/8/8/4K3 w - - 0 1").expect("Invalid FEN");
+        // Same knight, but black's c6 pawn attacks d5
+        let attacked =
+            Board::from_str("4k3/p6p/2p5/3N4/2P5/8/8/4K3 w - - 0 1").expect("Invalid FEN");
+        // Unsupported knight on d5
+        let unsupported =
+            Board::from_str("4k3/p6p/8/3N4/8/8/2P5/4K3 w - - 0 1").expect("Invalid FEN");
+
+        assert!(evaluate_knight_outposts(&outpost, Color::White, &TuningParams::DEFAULT) > 0);
+        assert_eq!(
+            evaluate_knight_outposts(&attacked, Color::White, &TuningParams::DEFAULT),
+            0
+        );
+        assert_eq!(
+            evaluate_knight_outposts(&unsupported, Color::White, &TuningParams::DEFAULT),
+            0
+        );
+    }
+
+    #[test]
+    fn test_knight_outpost_black() {
+        // Mirror image: black knight on d4 supported by c5
+        let board = Board::from_str("4k3/8/8/2p5/3n4/8/P6P/4K3 b - - 0 1").expect("Invalid FEN");
+        assert!(evaluate_knight_outposts(&board, Color::Black, &TuningParams::DEFAULT) > 0);
+        assert_eq!(
+            evaluate_knight_outposts(&board, Color::White, &TuningParams::DEFAULT),
+            0
+        );
+    }
+
+    #[test]
+    fn test_rook_seventh_bonus() {
+        let tables = load_magic_tables();
+        let on_seventh =
+            Board::from_str("6k1/pR3ppp/8/8/8/8/5PPP/6K1 w - - 0 1").expect("Invalid FEN");
+        let retreated =
+            Board::from_str("6k1/p4ppp/8/8/8/8/1R3PPP/6K1 w - - 0 1").expect("Invalid FEN");
+
+        assert!(evaluate_rook_seventh(&on_seventh, Color::White, &TuningParams::DEFAULT) > 0);
+        assert_eq!(
+            evaluate_rook_seventh(&retreated, Color::White, &TuningParams::DEFAULT),
+            0
+        );
+        assert!(
+            static_eval(&on_seventh, &tables, -i32::MAX, i32::MAX)
+                > static_eval(&retreated, &tables, -i32::MAX, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_rook_pair_seventh_bonus() {
+        // Second rook parked on a1 so both positions share the same phase
+        let single = Board::from_str("6k1/1R6/8/8/8/8/8/R5K1 w - - 0 1").expect("Invalid FEN");
+        let pair = Board::from_str("6k1/RR6/8/8/8/8/8/6K1 w - - 0 1").expect("Invalid FEN");
+        // No pawns and no king on the 8th: nothing to attack
+        let idle = Board::from_str("8/1R6/6k1/8/8/8/8/6K1 w - - 0 1").expect("Invalid FEN");
+
+        let one = evaluate_rook_seventh(&single, Color::White, &TuningParams::DEFAULT);
+        assert!(one > 0);
+        assert_eq!(
+            evaluate_rook_seventh(&pair, Color::White, &TuningParams::DEFAULT),
+            2 * one + TuningParams::DEFAULT.rook_pair_seventh_bonus
+        );
+        assert_eq!(
+            evaluate_rook_seventh(&idle, Color::White, &TuningParams::DEFAULT),
+            0
+        );
+    }
+
+    #[test]
+    fn test_pawn_chain_beats_isolated_pawns() {
+        let chain = Board::from_str("4k3/8/8/4P3/3P4/2P5/8/4K3 w - - 0 1").expect("Invalid FEN");
+        let isolated = Board::from_str("4k3/8/8/8/8/2P1P1P1/8/4K3 w - - 0 1").expect("Invalid FEN");
+
+        assert!(evaluate_pawn_structure(&chain) > evaluate_pawn_structure(&isolated));
+    }
+
+    #[test]
+    fn test_connected_pawns_are_color_symmetric() {
+        for fen in [
+            "4k3/8/8/4P3/3P4/2P5/8/4K3 w - - 0 1",
+            "4k3/pp3ppp/2p5/3p4/3P4/2P2N2/PP3PPP/4K3 w - - 0 1",
+        ] {
+            let board = Board::from_str(fen).expect("Invalid FEN");
+            assert_eq!(
+                evaluate_pawn_structure(&board),
+                -evaluate_pawn_structure(&board.mirror_colors()),
+                "Pawn structure asymmetry for {}",
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn test_king_tropism_rewards_closer_pieces() {
+        // Queen and knight far from the black king vs. the same pieces close to it
+        let far = Board::from_str("6k1/5ppp/8/8/8/8/PPP5/QN4K1 w - - 0 1").expect("Invalid FEN");
+        let near = Board::from_str("6k1/5ppp/5N2/6Q1/8/8/PPP5/6K1 w - - 0 1").expect("Invalid FEN");
+
+        assert!(
+            evaluate_king_tropism(&near, Color::White, &TuningParams::DEFAULT)
+                > evaluate_king_tropism(&far, Color::White, &TuningParams::DEFAULT)
+        );
+        // Nf6 earns 3 / 3 and Qg5 5 / 4, while seven squares away both earn 0
+        assert_eq!(
+            evaluate_king_tropism(&near, Color::White, &TuningParams::DEFAULT),
+            2
+        );
+        // Black has no pieces, so no tropism
+        assert_eq!(
+            evaluate_king_tropism(&near, Color::Black, &TuningParams::DEFAULT),
+            0
+        );
+    }
+
     #[test]
     fn test_lazy_eval_matches_full_eval_in_close_positions() {
         // Standard starting position
@@ -692,6 +1495,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_king_leading_pawn_in_kpk() {
+        let tables = load_magic_tables();
+        let p = &TuningParams::DEFAULT;
+        let leading = Board::from_str("k7/8/8/4K3/4P3/8/8/8 w - - 0 1").unwrap();
+        let behind = Board::from_str("k7/8/8/8/4P3/4K3/8/8 w - - 0 1").unwrap();
+        let far = Board::from_str("k7/8/8/8/4P3/8/8/K7 w - - 0 1").unwrap();
+
+        assert_eq!(
+            evaluate_king_activity_endgame(&leading, Color::White, p),
+            p.king_pawn_file_bonus + p.king_leads_pawn_bonus
+        );
+        assert_eq!(
+            evaluate_king_activity_endgame(&behind, Color::White, p),
+            p.king_pawn_file_bonus
+        );
+        assert_eq!(evaluate_king_activity_endgame(&far, Color::White, p), 0);
+        assert!(
+            static_eval(&leading, &tables, -i32::MAX, i32::MAX)
+                > static_eval(&behind, &tables, -i32::MAX, i32::MAX)
+        );
+
+        // Mirrored for Black: king on e4 leads a pawn on e5
+        let black = Board::from_str("8/8/8/4p3/4k3/8/8/K7 b - - 0 1").unwrap();
+        assert_eq!(
+            evaluate_king_activity_endgame(&black, Color::Black, p),
+            p.king_pawn_file_bonus + p.king_leads_pawn_bonus
+        );
+
+        // Off as soon as there is real material on the board
+        assert_eq!(
+            evaluate_king_activity_endgame(&Board::new(), Color::White, p),
+            0
+        );
+    }
+
     #[test]
     fn test_perspective_flip() {
         let board = Board::from_str("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
@@ -704,7 +1543,13 @@ mod tests {
         black_board.side_to_move = Color::Black;
         let black_eval = static_eval(&black_board, &tables, -i32::MAX, i32::MAX);
 
-        assert_eq!(white_eval, -black_eval, "Eval should be symmetric");
+        // Symmetric apart from the side to move's tempo bonus
+        let tempo = TuningParams::DEFAULT.tempo_bonus;
+        assert_eq!(
+            white_eval - tempo,
+            -(black_eval - tempo),
+            "Eval should be symmetric"
+        );
     }
 
     #[test]
@@ -722,7 +1567,7 @@ mod tests {
         let s1 = static_eval(&safe, &tables, -10000, 10000);
         let s2 = static_eval(&unsafe_board, &tables, -10000, 10000);
 
-        // The safe board should score HIGHER because of KING_SHIELD_BONUS
+        // The safe board should score HIGHER because of TuningParams::DEFAULT.king_shield_bonus
         assert!(
             s1 > s2,
             "Safe king ({}) should score higher than naked king ({})",
@@ -742,8 +1587,8 @@ mod tests {
         let castled = Board::from_str("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQ1RK1 w kq - 0 1")
             .expect("Invalid castled FEN");
 
-        let center_shield = evaluate_king_shield(&center, Color::White);
-        let castled_shield = evaluate_king_shield(&castled, Color::White);
+        let center_shield = evaluate_king_shield(&center, Color::White, &TuningParams::DEFAULT);
+        let castled_shield = evaluate_king_shield(&castled, Color::White, &TuningParams::DEFAULT);
 
         assert_eq!(
             center_shield, 0,
@@ -756,4 +1601,241 @@ mod tests {
             castled_shield
         );
     }
+
+    #[test]
+    fn test_startpos_mobility() {
+        let tables = load_magic_tables();
+        let board = Board::new();
+        for color in [Color::White, Color::Black] {
+            // 8 single + 8 double pushes; b1/g1 knights reach a3, c3, f3, h3
+            assert_eq!(
+                board.piece_mobility_summary(color, &tables),
+                [
+                    (Piece::Pawn, 16),
+                    (Piece::Knight, 4),
+                    (Piece::Bishop, 0),
+                    (Piece::Rook, 0),
+                    (Piece::Queen, 0),
+                    (Piece::King, 0),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_mobility_captures_and_blocks() {
+        let tables = load_magic_tables();
+        // d4 pawn: push to d5 and capture on e5; d2 pawn blocked from a double push by d3
+        let board = Board::from_str("4k3/8/8/4p3/3P4/3N4/3P4/4K2R w K - 0 1").unwrap();
+        assert_eq!(board.count_mobility(Piece::Pawn, Color::White, &tables), 2);
+        // h1 rook: f1, g1 and h2..h8
+        assert_eq!(board.count_mobility(Piece::Rook, Color::White, &tables), 9);
+        // d3 knight: b2 b4 c1 c5 e5 f2 f4 (e1 friendly)
+        assert_eq!(
+            board.count_mobility(Piece::Knight, Color::White, &tables),
+            7
+        );
+    }
+
+    #[test]
+    fn test_flipped_stm_eval_is_tempo() {
+        let tables = load_magic_tables();
+        let tempo = Board::new().flipped_stm_eval(&tables);
+        assert!((5..=30).contains(&tempo), "tempo swing {}", tempo);
+        assert_eq!(tempo, 2 * TuningParams::DEFAULT.tempo_bonus);
+
+        // Asymmetric position: the swing still only comes from the tempo term
+        let board =
+            Board::from_str("r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4")
+                .unwrap();
+        assert_eq!(board.flipped_stm_eval(&tables), tempo);
+    }
+
+    #[test]
+    fn test_open_and_half_open_files() {
+        const FILE_E: u64 = FILE_A << 4;
+        const FILE_D: u64 = FILE_A << 3;
+
+        let start = Board::new();
+        assert_eq!(start.open_files(), 0);
+        assert_eq!(start.half_open_files(Color::White), 0);
+        assert_eq!(start.pawn_file_mask(Color::White), u64::MAX);
+
+        let e4_e5 =
+            Board::from_str("rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2")
+                .unwrap();
+        assert_eq!(e4_e5.open_files(), 0);
+
+        let e_pawns_traded =
+            Board::from_str("rnbqkbnr/pppp1ppp/8/8/8/8/PPPP1PPP/RNBQKBNR w KQkq - 0 4").unwrap();
+        assert_eq!(e_pawns_traded.open_files(), FILE_E);
+
+        // White's d-pawn gone as well: the d-file is half-open for White only
+        let board =
+            Board::from_str("rnbqkbnr/pppp1ppp/8/8/8/8/PPP2PPP/RNBQKBNR w KQkq - 0 5").unwrap();
+        assert_eq!(board.open_files(), FILE_E);
+        assert_eq!(board.half_open_files(Color::White), FILE_D);
+        assert_eq!(board.half_open_files(Color::Black), 0);
+        assert_eq!(board.pawn_file_mask(Color::White), !(FILE_D | FILE_E));
+    }
+
+    #[test]
+    fn test_hanging_queen_penalty() {
+        let tables = load_magic_tables();
+        let p = &TuningParams::DEFAULT;
+        // White's d4 queen is attacked by the d8 rook and undefended
+        let board = Board::from_str("3rk3/8/8/8/3Q4/8/8/4K3 w - - 0 1").unwrap();
+        let d4 = 1u64 << Square::from_str("d4").unwrap().index();
+        assert_eq!(board.threatened_pieces(Color::White, &tables), d4);
+        let white = board.generate_attacks(Color::White, &tables);
+        let black = board.generate_attacks(Color::Black, &tables);
+        assert_eq!(
+            evaluate_hanging_pieces(&board, Color::White, black, white, p),
+            p.hanging_piece_penalty
+        );
+        // The queen attacks the rook back, but the king defends it
+        assert_eq!(
+            evaluate_hanging_pieces(&board, Color::Black, white, black, p),
+            0
+        );
+
+        // Defended by a pawn: no longer hanging
+        let defended = Board::from_str("3rk3/8/8/8/3Q4/2P5/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(defended.threatened_pieces(Color::White, &tables), 0);
+    }
+
+    #[test]
+    fn test_eval_kpk() {
+        let p = &TuningParams::DEFAULT;
+        let kpk = |fen: &str| eval_kpk(&Board::from_str(fen).unwrap(), p);
+
+        // Outside the square of the h-pawn even with the move
+        assert_eq!(kpk("8/8/8/k6P/8/8/8/K7 b - - 0 1"), p.kpk_unstoppable_bonus);
+        // g6 catches it; the black king is six squares closer to the pawn
+        assert_eq!(
+            kpk("8/8/6k1/7P/8/8/8/K7 b - - 0 1"),
+            -6 * p.kpk_king_distance_weight
+        );
+
+        // Ke5 in front of the pawn holds the opposition only with Black to move
+        let distance = 2 * p.kpk_king_distance_weight;
+        assert_eq!(
+            kpk("8/4k3/8/4K3/4P3/8/8/8 b - - 0 1"),
+            distance + p.kpk_opposition_bonus
+        );
+        assert_eq!(kpk("8/4k3/8/4K3/4P3/8/8/8 w - - 0 1"), distance);
+
+        // Mirrored for Black, scored from White's view
+        assert_eq!(
+            kpk("8/8/8/8/4p3/4k3/8/4K3 w - - 0 1"),
+            -(distance + p.kpk_opposition_bonus)
+        );
+    }
+
+    #[test]
+    fn test_kpk_keeps_king_pawn_terms() {
+        let tables = load_magic_tables();
+        // Ke5 sits next to the e4 pawn and in front of it
+        let board = Board::from_str("8/4k3/8/4K3/4P3/8/8/8 w - - 0 1").unwrap();
+        assert_eq!(board.endgame_type(), EndgameType::Kpk);
+        let eval = |p: &TuningParams| static_eval_with(&board, &tables, -32000, 32000, p);
+
+        let base = &TuningParams::DEFAULT;
+        let active = &TuningParams {
+            king_leads_pawn_bonus: base.king_leads_pawn_bonus + 100,
+            ..TuningParams::DEFAULT
+        };
+        assert_eq!(eval(active) - eval(base), 100);
+    }
+
+    #[test]
+    fn test_passed_pawns_and_most_advanced() {
+        let start = Board::new();
+        assert_eq!(start.count_passed_pawns(Color::White), 0);
+        assert_eq!(start.count_passed_pawns(Color::Black), 0);
+        assert_eq!(
+            start.most_advanced_pawn(Color::White),
+            Some(Square::from_str("h2").unwrap())
+        );
+
+        // Only d5 is passed: e4 and f5 hold each other back, and the a-pawns
+        // face each other
+        let board = Board::from_str("4k3/p7/8/3P1p2/4P3/8/P7/4K3 w - - 0 1").unwrap();
+        let d5 = Square::from_str("d5").unwrap();
+        assert_eq!(board.passed_pawns(Color::White), 1u64 << d5.index());
+        assert_eq!(board.count_passed_pawns(Color::White), 1);
+        assert_eq!(board.count_passed_pawns(Color::Black), 0);
+        assert_eq!(board.most_advanced_pawn(Color::White), Some(d5));
+        assert_eq!(
+            board.most_advanced_pawn(Color::Black),
+            Some(Square::from_str("f5").unwrap())
+        );
+
+        let bare = Board::from_str("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(bare.most_advanced_pawn(Color::White), None);
+    }
+
+    #[test]
+    fn test_king_proximity_to_passed_pawns() {
+        let p = &TuningParams::DEFAULT;
+        let score = |fen: &str| {
+            evaluate_king_proximity_to_passed_pawns(&Board::from_str(fen).unwrap(), Color::White, p)
+        };
+
+        // Passed d5 pawn: White king next to it vs. on the far side of the board
+        let escorted = score("7k/8/8/3P4/4K3/8/8/8 w - - 0 1");
+        let distant = score("7k/8/8/3P4/8/8/8/K7 w - - 0 1");
+        assert!(escorted > distant, "{} vs {}", escorted, distant);
+        // e4 is one square from d5, h8 four
+        assert_eq!(
+            escorted,
+            p.king_passed_proximity_bonus / 2 + 4 * p.enemy_king_passed_distance_bonus
+        );
+
+        // A closer enemy king costs the bonus it was worth
+        let blockaded = score("8/8/3k4/3P4/4K3/8/8/8 w - - 0 1");
+        assert!(blockaded < escorted);
+
+        // No passers, or too much material, gives nothing
+        assert_eq!(score("7k/8/3p4/3P4/4K3/8/8/8 w - - 0 1"), 0);
+        assert_eq!(score("r2qk3/8/8/3P4/4K3/8/8/3Q3R w - - 0 1"), 0);
+    }
+
+    #[test]
+    fn test_space_advantage() {
+        // One point per square, so the score is the square count
+        let p = &TuningParams {
+            space_bonus_mg: 1,
+            space_bonus_eg: 1,
+            ..TuningParams::DEFAULT
+        };
+        let tables = load_magic_tables();
+        let space = |board: &Board| {
+            (
+                evaluate_space_advantage(board, Color::White, p),
+                evaluate_space_advantage(board, Color::Black, p),
+            )
+        };
+
+        // Start: the second and third ranks of c-f plus the back rank behind
+        assert_eq!(space(&Board::new()), (12, 12));
+
+        // 1.e4 Nf6 2.d4 Ng8 3.c4 Nf6: everything but f4 for White, while
+        // Black's fifth rank is covered by the white centre
+        let board =
+            Board::from_moves(&["e2e4", "g8f6", "d2d4", "f6g8", "c2c4", "g8f6"], &tables).unwrap();
+        assert_eq!(space(&board), (15, 12));
+
+        // Squares an enemy pawn holds or attacks are not space
+        let board = Board::from_str("4k3/8/8/8/3p4/8/3P4/4K3 w - - 0 1").unwrap();
+        // d2 and d1 behind it; c3 and e3 are attacked by the d4 pawn
+        assert_eq!(space(&board).0, 2);
+
+        // Tapered: 5 per square with all pieces on, 2 in a bare endgame
+        let d = &TuningParams::DEFAULT;
+        assert_eq!(evaluate_space_advantage(&Board::new(), Color::White, d), 60);
+        let board = Board::from_str("4k3/8/8/8/8/8/3P4/4K3 w - - 0 1").unwrap();
+        // d1, d2, c3, e3
+        assert_eq!(evaluate_space_advantage(&board, Color::White, d), 8);
+    }
 }
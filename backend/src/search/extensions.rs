@@ -0,0 +1,129 @@
+//! Search extensions: how many extra plies a move gets in `alpha_beta`.
+
+use crate::board::Piece;
+use crate::moves::types::Move;
+
+/// Most plies a single move can be extended by.
+pub const MAX_EXTENSION: i32 = 2;
+
+/// Extension for searching `mv`, capped at `MAX_EXTENSION`:
+///  * +1 when the side to move is in check (the node is extended, so every
+///    evasion gets it),
+///  * +1 when `mv` has been found singular,
+///  * +1 for a queen promotion,
+///  * +1 for a recapture on the square `prev_move` just captured on.
+pub fn compute_extensions(
+    mv: Move,
+    prev_move: Option<Move>,
+    in_check: bool,
+    singular: bool,
+) -> i32 {
+    let recapture =
+        mv.is_capture() && prev_move.is_some_and(|prev| prev.is_capture() && prev.to == mv.to);
+
+    let extension = in_check as i32
+        + singular as i32
+        + (mv.promotion == Some(Piece::Queen)) as i32
+        + recapture as i32;
+    extension.min(MAX_EXTENSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Board;
+    use crate::moves::types::{CAPTURE, QUIET_MOVE};
+    use crate::square::Square;
+    use std::str::FromStr;
+
+    fn uci(board: &Board, s: &str) -> Move {
+        Move::from_uci(s, board).unwrap()
+    }
+
+    #[test]
+    fn test_quiet_move_not_extended() {
+        let board = Board::new();
+        assert_eq!(
+            compute_extensions(uci(&board, "g1f3"), None, false, false),
+            0
+        );
+    }
+
+    #[test]
+    fn test_check_extension() {
+        // Black king on e8 checked by the e1 rook
+        let board = Board::from_str("4k3/8/8/8/8/8/8/4R1K1 b - - 0 1").unwrap();
+        assert_eq!(
+            compute_extensions(uci(&board, "e8d8"), None, true, false),
+            1
+        );
+    }
+
+    #[test]
+    fn test_singular_extension() {
+        let board = Board::new();
+        assert_eq!(
+            compute_extensions(uci(&board, "e2e4"), None, false, true),
+            1
+        );
+    }
+
+    #[test]
+    fn test_queen_promotion_extension() {
+        let board = Board::from_str("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(
+            compute_extensions(uci(&board, "b7b8q"), None, false, false),
+            1
+        );
+        assert_eq!(
+            compute_extensions(uci(&board, "b7b8n"), None, false, false),
+            0
+        );
+    }
+
+    #[test]
+    fn test_recapture_extension() {
+        // After ...Nxd4, both Nxd4 and Qxd4 recapture on the same square
+        let board = Board::from_str("4k3/8/8/8/3n4/5N2/8/3QK3 w - - 0 1").unwrap();
+        let prev = Move {
+            from: Square::from_str("c6").unwrap(),
+            to: Square::from_str("d4").unwrap(),
+            piece: Piece::Knight,
+            promotion: None,
+            flags: CAPTURE,
+        };
+        assert_eq!(
+            compute_extensions(uci(&board, "f3d4"), Some(prev), false, false),
+            1
+        );
+        assert_eq!(
+            compute_extensions(uci(&board, "d1d4"), Some(prev), false, false),
+            1
+        );
+
+        // Capturing elsewhere, or after a quiet move, is not a recapture
+        let board = Board::from_str("4k3/8/8/4p3/3n4/5N2/8/3QK3 w - - 0 1").unwrap();
+        assert_eq!(
+            compute_extensions(uci(&board, "f3e5"), Some(prev), false, false),
+            0
+        );
+        let quiet = Move {
+            flags: QUIET_MOVE,
+            ..prev
+        };
+        assert_eq!(
+            compute_extensions(uci(&board, "f3d4"), Some(quiet), false, false),
+            0
+        );
+    }
+
+    #[test]
+    fn test_extension_is_capped() {
+        // Check, singular and queen promotion flags together: still only +2
+        let board = Board::from_str("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(
+            compute_extensions(uci(&board, "a7b8q"), None, true, true),
+            MAX_EXTENSION
+        );
+    }
+}
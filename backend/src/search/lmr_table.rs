@@ -0,0 +1,65 @@
+//! Late move reductions, precomputed by depth and move number.
+
+use once_cell::sync::OnceCell;
+
+/// Rows and columns in the table; larger depths and move counts share the
+/// last entry.
+pub const LMR_TABLE_SIZE: usize = 64;
+
+type LmrTable = [[i32; LMR_TABLE_SIZE]; LMR_TABLE_SIZE];
+
+static LMR_TABLE: OnceCell<LmrTable> = OnceCell::new();
+
+/// The reduction table, `[depth][move_count]`, built on first use:
+/// `round(ln(depth) * ln(move_count) / 2.5)`, never below zero.
+pub fn lmr_table() -> &'static LmrTable {
+    LMR_TABLE.get_or_init(|| {
+        let mut table = [[0; LMR_TABLE_SIZE]; LMR_TABLE_SIZE];
+        for (depth, row) in table.iter_mut().enumerate().skip(1) {
+            for (move_count, r) in row.iter_mut().enumerate().skip(1) {
+                let reduction = (depth as f64).ln() * (move_count as f64).ln() / 2.5;
+                *r = reduction.round().max(0.0) as i32;
+            }
+        }
+        table
+    })
+}
+
+/// Plies to reduce the `move_count`-th move (0-based) at `depth`.
+#[inline]
+pub fn lmr_reduction(depth: i32, move_count: usize) -> i32 {
+    let depth = depth.clamp(0, LMR_TABLE_SIZE as i32 - 1) as usize;
+    let move_count = move_count.min(LMR_TABLE_SIZE - 1);
+    lmr_table()[depth][move_count]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lmr_reduction_values() {
+        assert_eq!(lmr_reduction(1, 0), 0);
+        // ln(1) = 0: nothing is reduced at depth 1
+        assert_eq!(lmr_reduction(1, 40), 0);
+        // ln 4 * ln 8 / 2.5 = 1.15
+        assert_eq!(lmr_reduction(4, 8), 1);
+        // ln 10 * ln 20 / 2.5 = 2.76
+        assert_eq!(lmr_reduction(10, 20), 3);
+
+        // Out-of-range arguments clamp to the table edges
+        assert_eq!(lmr_reduction(-3, 10), 0);
+        assert_eq!(lmr_reduction(200, 500), lmr_reduction(63, 63));
+    }
+
+    #[test]
+    fn test_lmr_table_is_monotonic() {
+        let table = lmr_table();
+        for depth in 1..LMR_TABLE_SIZE {
+            for move_count in 1..LMR_TABLE_SIZE {
+                assert!(table[depth][move_count] >= table[depth - 1][move_count]);
+                assert!(table[depth][move_count] >= table[depth][move_count - 1]);
+            }
+        }
+    }
+}
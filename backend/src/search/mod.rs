@@ -1,9 +1,68 @@
 #![allow(clippy::module_inception)]
+pub mod contempt;
 pub mod context;
 pub mod eval;
+pub mod extensions;
+pub mod lmr_table;
 pub mod ordering;
 pub mod pesto;
+pub mod pesto_debug;
 pub mod picker;
+pub mod pv_table;
 pub mod search;
 pub mod see;
 pub mod tt;
+pub mod tuning;
+
+use crate::moves::types::Move;
+use crate::output::engine_println;
+use std::time::Duration;
+
+/// Everything `search::search` found, so callers decide how to report it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub score: i32,
+    pub best_move: Option<Move>,
+    /// Principal variation starting with `best_move`.
+    pub pv: Vec<Move>,
+    /// Deepest iteration that completed.
+    pub depth_reached: i32,
+    pub nodes: u64,
+    pub elapsed: Duration,
+    /// Fraction of TT probes that hit during this search (0.0–1.0).
+    pub tt_hit_rate: f64,
+}
+
+/// UCI `score` field: `cp <n>` or `mate <moves>` (negative when being mated).
+pub fn format_uci_score(score: i32) -> String {
+    use crate::search::search::{MATE_SCORE, MATE_THRESHOLD};
+
+    if score.abs() >= MATE_THRESHOLD {
+        let moves = (MATE_SCORE - score.abs() + 1) / 2;
+        if score > 0 {
+            format!("mate {}", moves)
+        } else {
+            format!("mate -{}", moves)
+        }
+    } else {
+        format!("cp {}", score)
+    }
+}
+
+/// The standard UCI `info` line for a search result.
+pub fn format_uci_info(result: &SearchResult) -> String {
+    let pv: Vec<String> = result.pv.iter().map(|m| m.to_uci()).collect();
+    format!(
+        "info depth {} score {} nodes {} time {} pv {}",
+        result.depth_reached,
+        format_uci_score(result.score),
+        result.nodes,
+        result.elapsed.as_millis(),
+        pv.join(" ")
+    )
+}
+
+/// Print the standard UCI `info` line for a finished search.
+pub fn print_uci_info(result: &SearchResult) {
+    engine_println!("{}", format_uci_info(result));
+}
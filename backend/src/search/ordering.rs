@@ -1,7 +1,6 @@
-use crate::board::{Board, Color, Piece};
+use crate::board::{ALL_PIECES, Board, Color, Piece};
 use crate::moves::magic::MagicTables;
 use crate::moves::types::Move;
-use crate::search::see::SeeExt;
 
 const PROMOTION_BASE: i32 = 20000;
 const CAPTURE_BASE: i32 = 10000;
@@ -9,25 +8,127 @@ const KILLER1_SCORE: i32 = 9000;
 const KILLER2_SCORE: i32 = 8000;
 const BAD_CAPTURE_PENALTY: i32 = 20000;
 
+/// Quiet-move history scores, indexed `[from][to]`.
+pub type HistoryTable = [[i32; 64]; 64];
+/// The two killer moves stored for a ply.
+pub type KillerTable = [Option<Move>; 2];
+
+// Scores for `score_move`, highest searched first
+const SCORE_TT_MOVE: i32 = 100_000;
+const SCORE_GOOD_CAPTURE_BASE: i32 = 90_000;
+const SCORE_GOOD_CAPTURE_MAX: i32 = 99_000;
+const SCORE_QUEEN_PROMOTION: i32 = 70_000;
+const SCORE_UNDER_PROMOTION: i32 = 60_000;
+const SCORE_KILLER1: i32 = 50_000;
+const SCORE_KILLER2: i32 = 40_000;
+const SCORE_BAD_CAPTURE: i32 = -50_000;
+
+/// `victim.value() * 10 - attacker.attacker_value()`, indexed
+/// `[victim][attacker]`, built at compile time.
+const MVV_LVA: [[i32; 6]; 6] = {
+    let mut table = [[0; 6]; 6];
+    let mut v = 0;
+    while v < 6 {
+        let mut a = 0;
+        while a < 6 {
+            table[v][a] = ALL_PIECES[v].value() * 10 - ALL_PIECES[a].attacker_value();
+            a += 1;
+        }
+        v += 1;
+    }
+    table
+};
+
+pub fn mvv_lva_table() -> &'static [[i32; 6]; 6] {
+    &MVV_LVA
+}
+
+/// MVV-LVA score of `attacker` taking `victim`.
+#[inline(always)]
+pub fn mvv_lva_fast(victim: Piece, attacker: Piece) -> i32 {
+    MVV_LVA[victim.index()][attacker.index()]
+}
+
 pub fn mvv_lva_score(mv: Move, board: &Board) -> i32 {
     if !mv.is_capture() {
         return 0;
     }
 
-    if let Some(captured) = board.piece_at(mv.to) {
-        let captured_piece = captured.1;
-        return captured_piece.value() * 10 - mv.piece.attacker_value();
+    if let Some((_, victim)) = board.piece_at(mv.to) {
+        return mvv_lva_fast(victim, mv.piece);
     }
 
     // En Passant capture: Destination is empty, but it IS a capture.
-    // Captured piece is a Pawn (value 100). Attacker is a Pawn (value 1).
     if mv.is_en_passant() {
-        return 100 * 10 - 1; // 999
+        return mvv_lva_fast(Piece::Pawn, Piece::Pawn); // 999
     }
 
     0
 }
 
+#[inline(always)]
+fn same_move(a: Move, b: Option<Move>) -> bool {
+    b.is_some_and(|b| a.from == b.from && a.to == b.to && a.promotion == b.promotion)
+}
+
+/// Single ordering score for `mv`; higher means search earlier.
+///
+/// TT move first, then SEE-safe captures by MVV-LVA, promotions, killers and
+/// quiets by history. Captures that lose material (SEE < 0) go last.
+pub fn score_move(
+    mv: Move,
+    board: &Board,
+    history: &HistoryTable,
+    killers: &KillerTable,
+    tt_move: Option<Move>,
+    tables: &MagicTables,
+) -> i32 {
+    if same_move(mv, tt_move) {
+        return SCORE_TT_MOVE;
+    }
+
+    if mv.is_capture() {
+        let mvv_lva = mvv_lva_score(mv, board);
+        if !board.see_pruning_for_move(mv, 0, tables) {
+            return SCORE_BAD_CAPTURE + mvv_lva;
+        }
+        // A promoting capture already gains at least a pawn, so it ranks with
+        // the good captures
+        return (SCORE_GOOD_CAPTURE_BASE + mvv_lva).min(SCORE_GOOD_CAPTURE_MAX);
+    }
+
+    match mv.promotion {
+        Some(Piece::Queen) => return SCORE_QUEEN_PROMOTION,
+        Some(_) => return SCORE_UNDER_PROMOTION,
+        None => {}
+    }
+
+    if same_move(mv, killers[0]) {
+        return SCORE_KILLER1;
+    }
+    if same_move(mv, killers[1]) {
+        return SCORE_KILLER2;
+    }
+
+    // Keep quiets below the killers however large history grows
+    history[mv.from.index() as usize][mv.to.index() as usize].min(SCORE_KILLER2 - 1)
+}
+
+/// Sort all of `moves` at once by `score_move`, best first: a simple
+/// alternative to the staged `MovePicker`.
+pub fn sort_moves(
+    moves: &mut [Move],
+    board: &Board,
+    history: &HistoryTable,
+    killers: &KillerTable,
+    tt_move: Option<Move>,
+    tables: &MagicTables,
+) {
+    moves.sort_by_cached_key(|&mv| {
+        std::cmp::Reverse(score_move(mv, board, history, killers, tt_move, tables))
+    });
+}
+
 pub fn order_moves(
     moves: &mut [Move],
     board: &Board,
@@ -55,7 +156,7 @@ pub fn order_moves(
         // Priority 2: Captures (MVV-LVA)
         let capture_score = mvv_lva_score(mv, board);
         if capture_score > 0 {
-            if board.static_exchange_eval(mv, 0, tables) {
+            if board.see_pruning_for_move(mv, 0, tables) {
                 return -(CAPTURE_BASE + capture_score);
             } else {
                 // Bad capture (SEE < 0): Very low priority (search after quiets)
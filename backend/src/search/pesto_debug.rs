@@ -0,0 +1,98 @@
+//! Print PeSTO piece-square tables as boards, for checking values while tuning.
+
+use crate::board::Piece;
+use crate::search::eval::get_psqt;
+
+/// Which half of a PeSTO (mg, eg) table pair to show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PiecePhase {
+    Middlegame,
+    Endgame,
+}
+
+/// `piece`'s table for `phase` as an 8×8 grid of centipawns from White's
+/// side: rank 8 on top, files labelled underneath, positive values with `+`.
+pub fn dump_psqt(piece: Piece, phase: PiecePhase) -> String {
+    let (mg, eg) = get_psqt(piece);
+    let table = match phase {
+        PiecePhase::Middlegame => mg,
+        PiecePhase::Endgame => eg,
+    };
+    render(|idx| table[idx])
+}
+
+/// Like `dump_psqt`, but tapered: `phase_fraction` 1.0 is the middlegame
+/// table, 0.0 the endgame one, matching how `static_eval` blends them.
+pub fn dump_combined_psqt(piece: Piece, phase_fraction: f64) -> String {
+    let (mg, eg) = get_psqt(piece);
+    let f = phase_fraction.clamp(0.0, 1.0);
+    render(|idx| (mg[idx] as f64 * f + eg[idx] as f64 * (1.0 - f)).round() as i32)
+}
+
+/// PeSTO tables are stored rank 8 first, so index order is print order.
+fn render(value: impl Fn(usize) -> i32) -> String {
+    let mut out = String::new();
+    for row in 0..8 {
+        out.push_str(&format!("{} ", 8 - row));
+        for file in 0..8 {
+            let v = value(row * 8 + file);
+            let cell = if v > 0 {
+                format!("+{}", v)
+            } else {
+                v.to_string()
+            };
+            out.push_str(&format!("{:>5}", cell));
+        }
+        out.push('\n');
+    }
+    out.push_str("  ");
+    for file in b'a'..=b'h' {
+        out.push_str(&format!("{:>5}", file as char));
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The value printed for `square` (e.g. "e1") in a dump.
+    fn cell(dump: &str, square: &str) -> String {
+        let (file, rank) = square.split_at(1);
+        let line = dump
+            .lines()
+            .find(|l| l.split_whitespace().next() == Some(rank))
+            .unwrap();
+        let file = (file.as_bytes()[0] - b'a') as usize;
+        line.split_whitespace().nth(file + 1).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_dump_king_middlegame() {
+        let dump = dump_psqt(Piece::King, PiecePhase::Middlegame);
+        assert_eq!(dump.lines().count(), 9);
+        assert!(dump.starts_with("8   -65  +23  +16"));
+        assert!(dump.ends_with("    a    b    c    d    e    f    g    h\n"));
+
+        // The uncastled king is only roughly neutral; the centre next to it
+        // and the advanced ranks are what the table punishes
+        assert_eq!(cell(&dump, "e1"), "+8");
+        assert_eq!(cell(&dump, "d1"), "-54");
+        assert_eq!(cell(&dump, "g1"), "+24");
+        assert_eq!(cell(&dump, "e4"), "-46");
+    }
+
+    #[test]
+    fn test_dump_combined_tapers_between_tables() {
+        let mg = dump_psqt(Piece::King, PiecePhase::Middlegame);
+        let eg = dump_psqt(Piece::King, PiecePhase::Endgame);
+        assert_eq!(dump_combined_psqt(Piece::King, 1.0), mg);
+        assert_eq!(dump_combined_psqt(Piece::King, 0.0), eg);
+
+        // e4: mg -46, eg +27
+        let half = dump_combined_psqt(Piece::King, 0.5);
+        assert_eq!(cell(&half, "e4"), "-10");
+        assert_eq!(cell(&eg, "e4"), "+27");
+    }
+}
@@ -6,16 +6,28 @@
 //! HashMove -> GoodCaptures -> Killer1 -> Killer2 -> Quiets -> BadCaptures
 //!
 //! If an early move causes a beta cutoff, later moves are never generated.
+//!
+//! When the side to move is in check, `new_evasion_picker` replaces the
+//! capture/quiet stages with a single evasion stage:
+//!
+//! HashMove -> Evasions
 
 use crate::board::Board;
-use crate::moves::execute::is_legal_move;
+use crate::moves::execute::{is_legal_move, is_pseudo_legal};
 use crate::moves::magic::MagicTables;
-use crate::moves::movegen::{generate_pseudo_legal_captures, generate_pseudo_legal_quiets};
+use crate::moves::movegen::{
+    generate_pseudo_legal_captures, generate_pseudo_legal_evasions, generate_pseudo_legal_quiets,
+};
+use crate::moves::square_control::checkers;
 use crate::moves::types::Move;
 use crate::search::ordering::mvv_lva_score;
-use crate::search::see::SeeExt;
 use arrayvec::ArrayVec;
 
+/// All-zero history table, for pickers whose quiet ordering doesn't matter
+/// (quiescence, `Board::has_legal_moves`); a static so callers don't zero
+/// 16 KB of stack each time.
+pub(crate) static EMPTY_HISTORY: [[i32; 64]; 64] = [[0; 64]; 64];
+
 /// The current stage of move generation/picking.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PickerStage {
@@ -27,6 +39,8 @@ pub enum PickerStage {
     GenerateQuiets,
     Quiets,
     BadCaptures,
+    GenerateEvasions,
+    Evasions,
     Done,
 }
 
@@ -54,6 +68,7 @@ pub struct MovePicker {
 
     // Mode
     captures_only: bool, // For quiescence search
+    checkers: u64,       // Non-zero for the check-evasion picker
 }
 
 impl MovePicker {
@@ -77,9 +92,54 @@ impl MovePicker {
             hash_move,
             killers,
             captures_only,
+            checkers: 0,
         }
     }
 
+    /// Create a MovePicker for a position where the side to move is in check.
+    ///
+    /// Only king moves, captures of the checker and interpositions are
+    /// generated. With more than one bit set in `checker_bb` only king moves
+    /// are generated.
+    ///
+    /// # Arguments
+    /// * `checker_bb` - Pieces giving check (see `square_control::checkers`)
+    /// * `hash_move` - The hash move from the transposition table (if any)
+    /// * `killers` - Killer moves for this ply, used only for ordering
+    pub fn new_evasion_picker(
+        checker_bb: u64,
+        hash_move: Option<Move>,
+        killers: [Option<Move>; 2],
+    ) -> Self {
+        debug_assert!(checker_bb != 0, "evasion picker requires a checker");
+        let mut picker = Self::new(hash_move, killers, false);
+        picker.checkers = checker_bb;
+        picker
+    }
+
+    /// Start over with new hash and killer moves, as if freshly created,
+    /// without giving up the buffers. The mode is kept: a captures-only
+    /// picker stays captures-only, and an evasion picker keeps its
+    /// checkers, so only reuse that one for the same position.
+    pub fn reset(&mut self, new_hash_move: Option<Move>, new_killers: [Option<Move>; 2]) {
+        self.stage = PickerStage::HashMove;
+        self.good_captures.clear();
+        self.bad_captures.clear();
+        self.quiets.clear();
+        self.good_capture_scores.clear();
+        self.quiet_scores.clear();
+        self.good_cap_idx = 0;
+        self.quiet_idx = 0;
+        self.bad_cap_idx = 0;
+        self.hash_move = new_hash_move;
+        self.killers = new_killers;
+    }
+
+    /// True once `next` has returned `None`: every move has been tried.
+    pub fn is_exhausted(&self) -> bool {
+        self.stage == PickerStage::Done
+    }
+
     /// Check if a move is the hash move.
     #[inline]
     fn is_hash_move(&self, mv: Move) -> bool {
@@ -126,7 +186,7 @@ impl MovePicker {
             let mvv_lva = mvv_lva_score(mv, board);
 
             // Use SEE to classify captures
-            if board.static_exchange_eval(mv, 0, tables) {
+            if board.see_pruning_for_move(mv, 0, tables) {
                 // Good capture (SEE >= 0)
                 self.good_captures.push(mv);
                 self.good_capture_scores.push(mvv_lva);
@@ -174,6 +234,28 @@ impl MovePicker {
         }
     }
 
+    /// Generate check evasions into the quiet buffer.
+    /// Captures first (MVV-LVA), then killers, then history.
+    fn generate_evasions(
+        &mut self,
+        board: &Board,
+        tables: &MagicTables,
+        history: &[[i32; 64]; 64],
+    ) {
+        generate_pseudo_legal_evasions(board, tables, self.checkers, &mut self.quiets);
+
+        for mv in &self.quiets {
+            let score = if mv.is_capture() {
+                1_000_000 + mvv_lva_score(*mv, board)
+            } else if self.is_killer(*mv) {
+                900_000
+            } else {
+                history[mv.from.index() as usize][mv.to.index() as usize]
+            };
+            self.quiet_scores.push(score);
+        }
+    }
+
     /// Pick the best capture from the remaining good captures using selection sort.
     /// Returns None if no captures remain.
     fn pick_best_capture(&mut self) -> Option<Move> {
@@ -240,7 +322,11 @@ impl MovePicker {
         loop {
             match self.stage {
                 PickerStage::HashMove => {
-                    self.stage = PickerStage::GenerateCaptures;
+                    self.stage = if self.checkers != 0 {
+                        PickerStage::GenerateEvasions
+                    } else {
+                        PickerStage::GenerateCaptures
+                    };
                     if let Some(hm) = self.hash_move {
                         // Validate hash move is pseudo-legal and legal
                         if is_pseudo_legal(board, hm, tables) && is_legal_move(board, hm, tables) {
@@ -341,176 +427,49 @@ impl MovePicker {
                     self.stage = PickerStage::Done;
                 }
 
-                PickerStage::Done => return None,
-            }
-        }
-    }
-}
-
-/// Check if a move is pseudo-legal (valid move for the current position).
-/// This validates that the move could have been generated by the move generator.
-fn is_pseudo_legal(board: &Board, mv: Move, tables: &MagicTables) -> bool {
-    use crate::board::{Color, Piece};
-    use crate::moves::king::KING_ATTACKS;
-    use crate::moves::knight::KNIGHT_ATTACKS;
-    use crate::moves::pawn::{BLACK_PAWN_ATTACKS, WHITE_PAWN_ATTACKS};
-
-    let color = board.side_to_move;
-    let from_idx = mv.from.index() as usize;
-    let to_idx = mv.to.index() as usize;
-    let from_bb = 1u64 << from_idx;
-    let to_bb = 1u64 << to_idx;
-
-    // Check that the piece exists on the from square
-    if board.pieces(mv.piece, color) & from_bb == 0 {
-        return false;
-    }
-
-    // Check that the destination isn't occupied by a friendly piece
-    let friendly = board.occupancy(color);
-    if friendly & to_bb != 0 {
-        return false;
-    }
-
-    // For captures, check there's an enemy piece (or it's en passant)
-    let enemy = board.opponent_occupancy(color);
-    if mv.is_capture() && !mv.is_en_passant() && enemy & to_bb == 0 {
-        return false;
-    }
-
-    // Don't allow capturing the king
-    let enemy_king = board.pieces(Piece::King, color.opposite());
-    if to_bb & enemy_king != 0 {
-        return false;
-    }
-
-    // Piece-specific validation
-    match mv.piece {
-        Piece::Pawn => {
-            let pawn_attacks = match color {
-                Color::White => WHITE_PAWN_ATTACKS[from_idx],
-                Color::Black => BLACK_PAWN_ATTACKS[from_idx],
-            };
-
-            if mv.is_en_passant() {
-                // En passant must target the EP square and be a pawn attack
-                if let Some(ep_sq) = board.en_passant {
-                    if to_idx != ep_sq.index() as usize {
-                        return false;
-                    }
-                    if pawn_attacks & to_bb == 0 {
-                        return false;
-                    }
-                } else {
-                    return false;
+                PickerStage::GenerateEvasions => {
+                    self.generate_evasions(board, tables, history);
+                    self.stage = PickerStage::Evasions;
                 }
-            } else if mv.is_capture() {
-                // Normal capture - must be a pawn attack
-                if pawn_attacks & to_bb == 0 {
-                    return false;
-                }
-            } else {
-                // Pawn push - check direction and blockers
-                let empty = !board.occupied();
-                let (push_delta, double_rank, double_delta): (i32, u64, i32) = match color {
-                    Color::White => (8, 0x0000_0000_0000_FF00, 16),
-                    Color::Black => (-8, 0x00FF_0000_0000_0000, -16),
-                };
 
-                if mv.is_double_pawn_push() {
-                    // Double push from starting rank
-                    if from_bb & double_rank == 0 {
-                        return false;
-                    }
-                    let expected_to = (from_idx as i32 + double_delta) as usize;
-                    if to_idx != expected_to {
-                        return false;
-                    }
-                    // Check both squares are empty
-                    let middle = (from_idx as i32 + push_delta) as usize;
-                    if empty & (1u64 << middle) == 0 || empty & to_bb == 0 {
-                        return false;
-                    }
-                } else {
-                    // Single push
-                    let expected_to = (from_idx as i32 + push_delta) as usize;
-                    if to_idx != expected_to {
-                        return false;
-                    }
-                    if empty & to_bb == 0 {
-                        return false;
+                PickerStage::Evasions => {
+                    while let Some(mv) = self.pick_best_quiet() {
+                        // Skip hash move (already tried)
+                        if self.is_hash_move(mv) {
+                            continue;
+                        }
+                        if is_legal_move(board, mv, tables) {
+                            return Some(mv);
+                        }
                     }
+                    self.stage = PickerStage::Done;
                 }
-            }
 
-            // Promotion validation
-            if mv.is_promotion() {
-                let promo_rank = match color {
-                    Color::White => 7,
-                    Color::Black => 0,
-                };
-                if to_idx / 8 != promo_rank {
-                    return false;
-                }
-            }
-        }
-        Piece::Knight => {
-            if KNIGHT_ATTACKS[from_idx] & to_bb == 0 {
-                return false;
-            }
-        }
-        Piece::Bishop => {
-            let attacks = tables.bishop.get_attacks(from_idx, board.occupied());
-            if attacks & to_bb == 0 {
-                return false;
-            }
-        }
-        Piece::Rook => {
-            let attacks = tables.rook.get_attacks(from_idx, board.occupied());
-            if attacks & to_bb == 0 {
-                return false;
-            }
-        }
-        Piece::Queen => {
-            let attacks = tables.queen_attacks(from_idx, board.occupied());
-            if attacks & to_bb == 0 {
-                return false;
-            }
-        }
-        Piece::King => {
-            if mv.is_castling() {
-                // Castling validation - check rights and path
-                let occ = board.occupied();
-                if mv.is_kingside_castle() {
-                    if !board.has_kingside_castle(color) {
-                        return false;
-                    }
-                    let between = match color {
-                        Color::White => 0x0000_0000_0000_0060,
-                        Color::Black => 0x6000_0000_0000_0000,
-                    };
-                    if occ & between != 0 {
-                        return false;
-                    }
-                } else {
-                    if !board.has_queenside_castle(color) {
-                        return false;
-                    }
-                    let between = match color {
-                        Color::White => 0x0000_0000_0000_000E,
-                        Color::Black => 0x0E00_0000_0000_0000,
-                    };
-                    if occ & between != 0 {
-                        return false;
-                    }
-                }
-            } else if KING_ATTACKS[from_idx] & to_bb == 0 {
-                return false;
+                PickerStage::Done => return None,
             }
         }
     }
+}
 
-    true
+impl Board {
+    /// True if the side to move has a legal move, asking a `MovePicker`
+    /// for one move instead of generating the full list. The picker tests
+    /// legality by playing and taking back moves, so the board is left as
+    /// it was.
+    ///
+    /// `legal_move_exists` answers the same question with cheaper legality
+    /// masks and is the faster of the two, by about 4x on checkmates where
+    /// every candidate has to be rejected; use this one to get the answer
+    /// the search's own move source would give.
+    pub fn has_legal_moves(&mut self, tables: &MagicTables) -> bool {
+        let checker_bb = checkers(self, self.side_to_move, tables);
+        let mut picker = if checker_bb != 0 {
+            MovePicker::new_evasion_picker(checker_bb, None, [None, None])
+        } else {
+            MovePicker::new(None, [None, None], false)
+        };
+        picker.next(self, tables, &EMPTY_HISTORY).is_some()
+    }
 }
 
 #[cfg(test)]
@@ -579,7 +538,7 @@ mod tests {
         // In captures_only mode, all returned moves should be captures or promotions
         for mv in &moves {
             assert!(
-                mv.is_capture() || mv.is_promotion(),
+                mv.is_noisy(),
                 "Captures-only mode returned non-capture: {}",
                 mv.to_uci()
             );
@@ -629,4 +588,128 @@ mod tests {
             moves.push(mv);
         }
     }
+
+    #[test]
+    fn test_picker_exhausted_and_reset() {
+        let tables = tables();
+        let mut board =
+            Board::from_str("r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4")
+                .unwrap();
+        let history = [[0i32; 64]; 64];
+
+        let mut picker = MovePicker::new(None, [None, None], false);
+        assert!(!picker.is_exhausted());
+        let first = collect(&mut picker, &mut board, &tables);
+        assert!(picker.is_exhausted());
+        assert_eq!(picker.next(&mut board, &tables, &history), None);
+
+        // A reset picker yields the same moves in the same order
+        picker.reset(None, [None, None]);
+        assert!(!picker.is_exhausted());
+        assert_eq!(collect(&mut picker, &mut board, &tables), first);
+
+        // New hash and killer moves take effect: Nxe5 first, then O-O
+        let nxe5 = Move::from_uci("f3e5", &board).unwrap();
+        let castle = Move::from_uci("e1g1", &board).unwrap();
+        picker.reset(Some(nxe5), [Some(castle), None]);
+        let again = collect(&mut picker, &mut board, &tables);
+        assert_eq!(again[0], nxe5);
+        assert_eq!(again.len(), first.len());
+        let castle_at = again.iter().position(|&m| m == castle).unwrap();
+        let first_quiet = again.iter().position(|m| !m.is_capture()).unwrap();
+        assert_eq!(castle_at, first_quiet);
+    }
+
+    #[test]
+    fn test_has_legal_moves() {
+        let tables = tables();
+        assert!(Board::new().has_legal_moves(&tables));
+
+        for fen in [
+            // Back-rank mate, fool's mate, stalemate
+            "R5k1/5ppp/8/8/8/8/8/6K1 b - - 1 1",
+            "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3",
+            "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1",
+        ] {
+            let mut board = Board::from_str(fen).unwrap();
+            let before = board.clone();
+            assert!(!board.has_legal_moves(&tables), "{}", fen);
+            assert_eq!(board, before, "board changed in {}", fen);
+        }
+
+        // In check with a way out
+        let mut board = Board::from_str("4k3/8/8/8/8/8/8/4K2r w - - 0 1").unwrap();
+        assert!(board.has_legal_moves(&tables));
+    }
+
+    fn collect(picker: &mut MovePicker, board: &mut Board, tables: &MagicTables) -> Vec<Move> {
+        let history = [[0i32; 64]; 64];
+        let mut moves = Vec::new();
+        while let Some(mv) = picker.next(board, tables, &history) {
+            moves.push(mv);
+        }
+        moves
+    }
+
+    fn assert_matches_legal(board: &mut Board, tables: &MagicTables, moves: &[Move]) {
+        let mut legal: ArrayVec<Move, 256> = ArrayVec::new();
+        let mut scratch: ArrayVec<Move, 256> = ArrayVec::new();
+        generate_legal(board, tables, &mut legal, &mut scratch);
+
+        assert_eq!(legal.len(), moves.len());
+        for mv in &legal {
+            assert!(
+                moves
+                    .iter()
+                    .any(|m| m.from == mv.from && m.to == mv.to && m.promotion == mv.promotion),
+                "Evasion picker missed legal move: {}",
+                mv.to_uci()
+            );
+        }
+    }
+
+    #[test]
+    fn test_evasion_picker_double_check_only_king_moves() {
+        use crate::board::{Color, Piece};
+        use crate::moves::square_control::checkers;
+
+        let tables = tables();
+        // Rook on e8 and knight on d3 both check the white king on e1
+        let mut board = Board::from_str("4r2k/8/8/8/8/3n4/8/R3K3 w - - 0 1").unwrap();
+        let checker_bb = checkers(&board, Color::White, &tables);
+        assert_eq!(checker_bb.count_ones(), 2);
+
+        let mut picker = MovePicker::new_evasion_picker(checker_bb, None, [None, None]);
+        let moves = collect(&mut picker, &mut board, &tables);
+
+        assert!(!moves.is_empty());
+        assert!(moves.iter().all(|m| m.piece == Piece::King));
+        assert_matches_legal(&mut board, &tables, &moves);
+    }
+
+    #[test]
+    fn test_evasion_picker_single_check_captures_and_blocks() {
+        use crate::board::{Color, Piece};
+        use crate::moves::square_control::checkers;
+
+        let tables = tables();
+        // Bishop on b4 checks the king on e1 along b4-c3-d2
+        let mut board = Board::from_str("4k3/8/8/8/1b6/P7/2P5/3QK1N1 w - - 0 1").unwrap();
+        let checker_bb = checkers(&board, Color::White, &tables);
+        assert_eq!(checker_bb.count_ones(), 1);
+
+        let mut picker = MovePicker::new_evasion_picker(checker_bb, None, [None, None]);
+        let moves = collect(&mut picker, &mut board, &tables);
+
+        let has = |uci: &str| moves.iter().any(|m| m.to_uci() == uci);
+        assert!(has("a3b4"), "capture of the checker missing");
+        assert!(has("c2c3"), "pawn block missing");
+        assert!(has("d1d2"), "queen block missing");
+        assert!(!has("c2c4"), "push off the check ray generated");
+        assert!(!has("g1f3"), "non-evasion generated");
+        assert!(moves.iter().any(|m| m.piece == Piece::King));
+        // Captures are ordered ahead of blocks and king moves
+        assert!(moves[0].is_capture());
+        assert_matches_legal(&mut board, &tables, &moves);
+    }
 }
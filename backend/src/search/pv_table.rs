@@ -0,0 +1,95 @@
+//! Triangular principal variation table, filled in by `alpha_beta`.
+//!
+//! Row `ply` holds the best line found from that ply onward. When a move
+//! raises alpha, the child's row is copied in behind it, so row 0 ends up as
+//! the PV of the whole search.
+
+use crate::board::Piece;
+use crate::moves::execute::MAX_PLY;
+use crate::moves::types::{Move, QUIET_MOVE};
+use crate::square::Square;
+
+/// Fills unused slots; never read back since `lengths` bounds every row.
+const EMPTY: Move = Move {
+    from: Square::from_index(0),
+    to: Square::from_index(0),
+    piece: Piece::Pawn,
+    promotion: None,
+    flags: QUIET_MOVE,
+};
+
+pub struct PvTable {
+    table: [[Move; MAX_PLY]; MAX_PLY],
+    lengths: [usize; MAX_PLY],
+}
+
+impl Default for PvTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PvTable {
+    pub fn new() -> Self {
+        Self {
+            table: [[EMPTY; MAX_PLY]; MAX_PLY],
+            lengths: [0; MAX_PLY],
+        }
+    }
+
+    /// Forget the line at `ply`; called on entering a node there.
+    pub fn clear(&mut self, ply: usize) {
+        self.lengths[ply] = 0;
+    }
+
+    /// `mv` raised alpha at `ply`: the line there becomes `mv` followed by
+    /// the line last found at `ply + 1`.
+    pub fn update_pv(&mut self, ply: usize, mv: Move) {
+        let child_len = if ply + 1 < MAX_PLY {
+            self.lengths[ply + 1]
+        } else {
+            0
+        };
+        let (parents, children) = self.table.split_at_mut(ply + 1);
+        let row = &mut parents[ply];
+        row[0] = mv;
+        if child_len > 0 {
+            row[1..=child_len].copy_from_slice(&children[0][..child_len]);
+        }
+        self.lengths[ply] = child_len + 1;
+    }
+
+    /// The line found from `ply` onward; `get_pv(0)` is the root PV.
+    pub fn get_pv(&self, ply: usize) -> &[Move] {
+        &self.table[ply][..self.lengths[ply]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Board;
+
+    #[test]
+    fn test_update_pv_prepends_child_line() {
+        let board = Board::new();
+        let mv = |uci: &str| Move::from_uci(uci, &board).unwrap();
+        let (e4, d4, nf3) = (mv("e2e4"), mv("d2d4"), mv("g1f3"));
+        let mut pv = PvTable::new();
+
+        pv.update_pv(2, nf3);
+        pv.update_pv(1, d4);
+        pv.update_pv(0, e4);
+        assert_eq!(pv.get_pv(0), &[e4, d4, nf3]);
+        assert_eq!(pv.get_pv(1), &[d4, nf3]);
+
+        // A cleared child leaves just the move itself
+        pv.clear(1);
+        pv.update_pv(0, d4);
+        assert_eq!(pv.get_pv(0), &[d4]);
+
+        // The last ply has no child row to copy
+        pv.update_pv(MAX_PLY - 1, e4);
+        assert_eq!(pv.get_pv(MAX_PLY - 1), &[e4]);
+    }
+}
@@ -1,14 +1,22 @@
 use crate::board::Board;
-use crate::moves::execute::{make_move_basic, make_null_move, undo_move_basic, undo_null_move};
+use crate::moves::execute::{
+    MAX_PLY, generate_legal, is_pseudo_legal, make_move_basic, make_null_move, undo_move_basic,
+    undo_null_move,
+};
 use crate::moves::magic::MagicTables;
-use crate::moves::square_control::in_check;
+use crate::moves::square_control::checkers;
 use crate::moves::types::Move;
-use crate::output::engine_println;
 use crate::search::context::SearchContext;
-use crate::search::eval::static_eval;
-use crate::search::picker::MovePicker;
-use crate::search::see::SeeExt;
+use crate::search::eval::static_eval_with;
+use crate::search::extensions::compute_extensions;
+use crate::search::lmr_table::{lmr_reduction, lmr_table};
+use crate::search::picker::{EMPTY_HISTORY, MovePicker};
 use crate::search::tt::{NodeType, TranspositionTable};
+use crate::search::{SearchResult, format_uci_info};
+use crate::status::is_draw_fast;
+use arrayvec::ArrayVec;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -18,34 +26,14 @@ use std::time::Instant;
 use web_time::Instant;
 
 const INF: i32 = 32000;
-const MATE_SCORE: i32 = 31000;
-const MATE_THRESHOLD: i32 = MATE_SCORE - 1000; // 30000 - buffer for mate distance
+pub(crate) const MATE_SCORE: i32 = 31000;
+pub(crate) const MATE_THRESHOLD: i32 = MATE_SCORE - 1000; // 30000 - buffer for mate distance
 const MAX_Q_SEARCH_DEPTH: usize = 100;
-const DRAW_SCORE: i32 = -50;
+/// Score for a drawn position before contempt (see `search::contempt`).
+pub const DRAW_SCORE: i32 = 0;
 
-// --- Tuning Constants ---
-
-// Reverse Futility Pruning (RFP)
-const RFP_DEPTH_LIMIT: i32 = 9;
-const RFP_MARGIN_BASE: i32 = 80;
-const RFP_MARGIN_MULT: i32 = 90;
-
-// Futility Pruning (FP)
-const FP_DEPTH_LIMIT: i32 = 7;
-const FP_MARGIN_BASE: i32 = 100;
-const FP_MARGIN_MULT: i32 = 100;
-const FP_HISTORY_THRESHOLD: i32 = 512;
-
-// Late Move Pruning (LMP)
-const LMP_DEPTH_LIMIT: i32 = 14;
-const LMP_BASE_MOVES: i32 = 3;
-const LMP_MOVE_MULTIPLIER: i32 = 6;
-
-// Late Move Reduction (LMR)
-const LMR_MIN_DEPTH: i32 = 2;
-const LMR_MIN_MOVES: i32 = 4;
-// const LMR_BASE: f64 = 0.75;
-// const LMR_DIVISOR: f64 = 2.5;
+// Pruning and reduction constants live in `search::tuning::TuningParams`
+// (read through `ctx.params`) so they can be tuned without recompiling.
 
 // --- TT Score Adjustment Helpers ---
 fn score_to_tt(score: i32, ply: i32) -> i32 {
@@ -69,27 +57,92 @@ fn score_from_tt(score: i32, ply: i32) -> i32 {
 }
 // -----------------------------------
 
+/// How often (in nodes) the search calls `TimeManager::check_time`. Must be a
+/// power of two; limits are therefore only enforced to this granularity.
+pub const NODE_CHECK_INTERVAL: u64 = 64;
+
+/// Score swing (cp) between two completed depths that marks the position
+/// as unstable and earns extra thinking time.
+const TM_UNSTABLE_SCORE_DELTA: i32 = 50;
+
 pub struct TimeManager {
     pub start_time: Instant,
+    /// Hard limit: `check_time` aborts the search once it is reached.
     pub allotted: Option<Duration>,
+    /// Soft limit: iterative deepening starts no new depth past it.
+    pub soft_limit: Option<Duration>,
+    pub nodes_limit: Option<u64>,
+    /// Set from another thread (UCI `stop`) to abort the search.
+    pub stop_flag: Option<Arc<AtomicBool>>,
     pub stop_signal: bool,
 }
 
 impl TimeManager {
     pub fn new(limit: Option<Duration>) -> Self {
+        Self::with_limits(limit, None)
+    }
+
+    /// Stop on whichever of the time and node limits is hit first (`go nodes N`).
+    /// The time limit is used as both the soft and the hard limit.
+    pub fn with_limits(limit: Option<Duration>, nodes_limit: Option<u64>) -> Self {
         Self {
             start_time: Instant::now(),
             allotted: limit,
+            soft_limit: limit,
+            nodes_limit,
+            stop_flag: None,
             stop_signal: false,
         }
     }
 
+    /// Budget one move from a Fischer clock (`wtime`/`winc`, `movestogo`).
+    ///
+    /// The soft target is `time_left / moves_to_go + 0.8 * increment`, assuming
+    /// 30 moves remain when `moves_to_go` is unknown. The hard limit allows
+    /// unstable positions up to 3x the target, but never more than a fifth of
+    /// the remaining time.
+    pub fn from_game_clock(
+        time_left: Duration,
+        increment: Duration,
+        moves_to_go: Option<u32>,
+    ) -> Self {
+        let moves = moves_to_go.unwrap_or(30).max(1);
+        let target = time_left / moves + increment.mul_f64(0.8);
+        let hard = (target * 3).min(time_left / 5);
+
+        let mut tm = Self::new(Some(hard));
+        tm.soft_limit = Some(target.min(hard));
+        tm
+    }
+
+    /// Also stop once `nodes` have been searched.
+    pub fn with_nodes_limit(mut self, nodes: Option<u64>) -> Self {
+        self.nodes_limit = nodes;
+        self
+    }
+
+    /// Also stop as soon as `flag` is set, e.g. by the UCI thread on `stop`.
+    pub fn with_stop_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.stop_flag = Some(flag);
+        self
+    }
+
     #[inline(always)]
-    pub fn check_time(&mut self) {
+    pub fn check_time(&mut self, nodes: u64) {
         if self.stop_signal {
             return;
         }
 
+        if self.nodes_limit.is_some_and(|limit| nodes >= limit)
+            || self
+                .stop_flag
+                .as_ref()
+                .is_some_and(|flag| flag.load(Ordering::Relaxed))
+        {
+            self.stop_signal = true;
+            return;
+        }
+
         if let Some(limit) = self.allotted {
             let elapsed = self.start_time.elapsed();
 
@@ -100,12 +153,30 @@ impl TimeManager {
         }
     }
 
-    /// Returns the allocated time limit
+    /// Returns the allocated (hard) time limit
     #[inline(always)]
     pub fn allocated_time(&self) -> Option<Duration> {
         self.allotted
     }
 
+    /// Returns the soft time target used between iterations
+    #[inline(always)]
+    pub fn soft_time(&self) -> Option<Duration> {
+        self.soft_limit
+    }
+
+    /// Give an unstable position more time: when the score moved by more than
+    /// `TM_UNSTABLE_SCORE_DELTA` since the previous depth, stretch the soft
+    /// limit by half, up to the hard limit.
+    pub fn update_after_depth(&mut self, score_change: i32) {
+        if score_change.abs() <= TM_UNSTABLE_SCORE_DELTA {
+            return;
+        }
+        if let (Some(soft), Some(hard)) = (self.soft_limit, self.allotted) {
+            self.soft_limit = Some(soft.mul_f64(1.5).min(hard));
+        }
+    }
+
     /// Returns elapsed time since search started
     #[inline(always)]
     pub fn elapsed(&self) -> Duration {
@@ -127,7 +198,13 @@ pub fn quiescence(
 ) -> i32 {
     // SAFETY BRAKE: Prevent Q-search explosions
     if ply > MAX_Q_SEARCH_DEPTH {
-        return static_eval(board, tables, alpha, beta);
+        return static_eval_with(board, tables, alpha, beta, &ctx.params);
+    }
+
+    // Repetitions, fifty moves and dead material are drawn whatever the
+    // captures; score them before stand-pat or a TT entry can say otherwise.
+    if is_draw_fast(board, tables) {
+        return ctx.contempt.draw_score(ply);
     }
 
     let original_alpha = alpha;
@@ -144,9 +221,9 @@ pub fn quiescence(
         }
     }
 
-    let stand_pat = static_eval(board, tables, alpha, beta);
+    let stand_pat = static_eval_with(board, tables, alpha, beta, &ctx.params);
 
-    if stand_pat >= beta {
+    if stand_pat - ctx.params.qs_futility_margin >= beta {
         return beta;
     }
 
@@ -159,38 +236,21 @@ pub fn quiescence(
 
     // Use MovePicker in captures-only mode for quiescence
     let empty_killers = [None, None];
-    let empty_history = [[0i32; 64]; 64];
     let mut picker = MovePicker::new(None, empty_killers, true);
 
-    while let Some(mv) = picker.next(board, tables, &empty_history) {
+    while let Some(mv) = picker.next(board, tables, &EMPTY_HISTORY) {
         *nodes += 1;
-        if *nodes & 63 == 0 {
-            time.check_time();
+        if *nodes & (NODE_CHECK_INTERVAL - 1) == 0 {
+            time.check_time(*nodes);
         }
         if time.stop_signal {
             return stand_pat;
         }
 
-        let mut captured_value = 0;
-        if let Some(piece) = board.piece_type_at(mv.to) {
-            captured_value = piece.value();
-        }
-
-        // DELTA PRUNING SAFETY
-        // Don't prune if it's a promotion (potentially huge value)
-        // Don't prune if it's En Passant (captured_value is 0, but it captures a pawn)
-        let is_prom = mv.is_promotion();
-        let is_ep = mv.is_en_passant();
-
-        // "Blindness" Fix: Only prune standard captures.
-        if !is_prom && !is_ep && stand_pat + captured_value + 200 < alpha {
-            continue;
-        }
-
-        // SEE Pruning: Skip captures that lose material
-        // Note: MovePicker already filters bad captures for us, but we keep this
-        // for promotions and en passant which bypass SEE classification
-        if !is_prom && !is_ep && !board.static_exchange_eval(mv, 0, tables) {
+        // SEE guard: skip captures that lose more than qs_see_threshold.
+        // Slightly losing captures are still searched so exchanges near
+        // material equality are resolved rather than cut at the horizon.
+        if !board.see_pruning_for_move(mv, ctx.params.qs_see_threshold, tables) {
             continue;
         }
 
@@ -246,9 +306,9 @@ pub fn alpha_beta(
     nodes: &mut u64,
     time: &mut TimeManager,
 ) -> (i32, Option<Move>) {
-    // Check every 1024 nodes instead of 2047 for tighter control
-    if *nodes & 63 == 0 {
-        time.check_time();
+    // Check every NODE_CHECK_INTERVAL nodes for tight control
+    if *nodes & (NODE_CHECK_INTERVAL - 1) == 0 {
+        time.check_time(*nodes);
     }
 
     if time.stop_signal {
@@ -256,9 +316,32 @@ pub fn alpha_beta(
     }
     *nodes += 1;
 
+    // SAFETY BRAKE: per-ply tables (killers) are sized for MAX_PLY, and check
+    // extensions can push ply past the nominal depth
+    if ply >= MAX_PLY {
+        return (
+            static_eval_with(board, tables, alpha, beta, &ctx.params),
+            None,
+        );
+    }
+    ctx.pv_table.clear(ply);
+    #[cfg(feature = "debug_registry")]
+    ctx.registry.record(board);
+
     // 2. Repetition & TT Probing (Standard)
-    if ply > 0 && board.is_repetition() {
-        return (DRAW_SCORE, None);
+    // The first repetition is already scored as a draw: whoever benefits can
+    // force the threefold. A cycle needs at least 4 plies of history. This
+    // returns before any tt.save, since the draw comes from the path rather
+    // than the position and must not leak into other lines. The fifty-move
+    // rule is treated the same way, except that a mate delivered on the
+    // hundredth half-move still stands.
+    if ply > 0
+        && ((board.halfmove_clock >= 100
+            && (checkers(board, board.side_to_move, tables) == 0
+                || board.legal_move_exists(tables)))
+            || (board.history.len() >= 4 && board.repetition_count() >= 2))
+    {
+        return (ctx.contempt.draw_score(ply), None);
     }
 
     if time.stop_signal {
@@ -271,10 +354,17 @@ pub fn alpha_beta(
     // TT PROBE WITH MATE SCORE ADJUSTMENT
     if let Some((tt_move, raw_score, tt_depth, tt_bound)) =
         tt.probe(hash, depth as u8, alpha, beta, ply as i32)
+        // A stored move that can't be played here means the entry belongs to
+        // another position with the same key: ignore its move and its score
+        && tt_move.is_none_or(|tm| {
+            let valid = is_pseudo_legal(board, tm, tables);
+            if !valid {
+                tt.record_collision();
+            }
+            valid
+        })
     {
-        if let Some(tm) = tt_move {
-            hash_move = Some(tm);
-        }
+        hash_move = tt_move;
 
         if tt_depth >= depth as u8 {
             // Convert the stored independent score back to relative score
@@ -291,12 +381,8 @@ pub fn alpha_beta(
         }
     }
 
-    let in_check_now = in_check(board, board.side_to_move, tables);
-
-    // FIX 6: CHECK EXTENSION
-    // If we are in check, extend the search by 1 ply.
-    // This resolves forced mates and prevents the horizon effect.
-    let extension = if in_check_now { 1 } else { 0 };
+    let checker_bb = checkers(board, board.side_to_move, tables);
+    let in_check_now = checker_bb != 0;
 
     if depth <= 0 && !in_check_now {
         let score = quiescence(board, tables, ctx, tt, ply, alpha, beta, nodes, time);
@@ -306,14 +392,14 @@ pub fn alpha_beta(
     // [STEP 1] Calculate Eval Early
     // We lift this out so both RFP and SFP can share it.
     let static_eval_val = if !in_check_now {
-        static_eval(board, tables, alpha, beta)
+        static_eval_with(board, tables, alpha, beta, &ctx.params)
     } else {
         0 // Dummy value, we won't use it if in check
     };
 
     // [STEP 2] Update Reverse Futility Pruning (RFP) to use the variable
-    if depth < RFP_DEPTH_LIMIT && !in_check_now && ply > 0 {
-        let margin = RFP_MARGIN_BASE + RFP_MARGIN_MULT * depth;
+    if depth < ctx.params.rfp_depth_limit && !in_check_now && ply > 0 {
+        let margin = ctx.params.rfp_margin_base + ctx.params.rfp_margin_mult * depth;
         if static_eval_val - margin >= beta {
             return (beta, None);
         }
@@ -326,7 +412,7 @@ pub fn alpha_beta(
     if depth >= 4
         && !in_check_now
         // REMOVED: && (beta - alpha == 1) <--- Unlocks NMP for PV nodes (Massive speedup)
-        && board.has_major_pieces(board.side_to_move)
+        && !board.is_zugzwang_candidate()
         && static_eval_val >= beta
     // Only null move if we are already winning statically
     {
@@ -334,6 +420,7 @@ pub fn alpha_beta(
         let r = if depth > 6 { 3 } else { 2 };
 
         let undo = make_null_move(board);
+        ctx.move_stack[ply] = None;
 
         // Scout search with Null Window
         let (val, _) = alpha_beta(
@@ -362,23 +449,24 @@ pub fn alpha_beta(
     }
 
     // Use MovePicker for staged move generation
-    let mut picker = MovePicker::new(hash_move, ctx.killer_moves[ply], false);
+    // In check, only evasions need to be generated
+    let mut picker = if in_check_now {
+        MovePicker::new_evasion_picker(checker_bb, hash_move, ctx.killer_moves[ply])
+    } else {
+        MovePicker::new(hash_move, ctx.killer_moves[ply], false)
+    };
 
     let mut best_move = None;
     let mut best_score = -INF;
     let original_alpha = alpha;
     let mut move_count = 0;
+    let mut quiets_tried: ArrayVec<Move, 64> = ArrayVec::new();
 
     while let Some(mv) = picker.next(board, tables, &ctx.history) {
         // [STEP 3] OPTIMIZED FUTILITY PRUNING
         // Logic: If the move is quiet and our position is hopelessly below Alpha, skip it.
-        if depth < FP_DEPTH_LIMIT
-            && !in_check_now
-            && !mv.is_capture()
-            && !mv.is_promotion()
-            && move_count > 0
-        {
-            let margin = FP_MARGIN_BASE + FP_MARGIN_MULT * depth;
+        if depth < ctx.params.fp_depth_limit && !in_check_now && mv.is_quiet() && move_count > 0 {
+            let margin = ctx.params.fp_margin_base + ctx.params.fp_margin_mult * depth;
 
             // HISTORY PROTECTION (The Optimization):
             // We retrieve the history score for this move.
@@ -386,7 +474,7 @@ pub fn alpha_beta(
 
             // If the move has a high history score (> 2000), it has been good in other nodes.
             // We should NOT prune it, even if static eval says it's bad.
-            if history < FP_HISTORY_THRESHOLD && static_eval_val + margin <= alpha {
+            if history < ctx.params.fp_history_threshold && static_eval_val + margin <= alpha {
                 continue; // PRUNE: Skip to next move
             }
         }
@@ -397,13 +485,12 @@ pub fn alpha_beta(
         // Logic: If we have searched many quiet moves and haven't found a
         // good one yet, it's highly unlikely the remaining (unsorted) moves
         // will be any better. Just cut them off.
-        if depth < LMP_DEPTH_LIMIT
+        if depth < ctx.params.lmp_depth_limit
             && !in_check_now
-            && !mv.is_capture()
-            && !mv.is_promotion()
+            && mv.is_quiet()
             && alpha == original_alpha
         {
-            let lmp_threshold = LMP_BASE_MOVES + LMP_MOVE_MULTIPLIER * depth;
+            let lmp_threshold = ctx.params.lmp_base_moves + ctx.params.lmp_move_multiplier * depth;
             if move_count > lmp_threshold as usize {
                 // break is correct: MovePicker stages are HashMove → GoodCaptures →
                 // Killers → Quiets → BadCaptures. Only bad captures remain after
@@ -413,7 +500,16 @@ pub fn alpha_beta(
         }
         // =========================================================
 
+        // Check, recapture and queen promotion extensions (see `extensions`)
+        let prev_move = if ply > 0 {
+            ctx.move_stack[ply - 1]
+        } else {
+            None
+        };
+        let extension = compute_extensions(mv, prev_move, in_check_now, false);
+
         let undo = make_move_basic(board, mv);
+        ctx.move_stack[ply] = Some(mv);
         let mut score;
 
         if move_count == 0 {
@@ -435,22 +531,18 @@ pub fn alpha_beta(
             // 2. LATE MOVE REDUCTION (Tuned: Soft Formula)
             // =========================================================
             let mut r = 0;
-            if depth > LMR_MIN_DEPTH
-                && move_count > LMR_MIN_MOVES as usize
-                && !mv.is_capture()
-                && !mv.is_promotion()
+            if depth > ctx.params.lmr_min_depth
+                && move_count > ctx.params.lmr_min_moves as usize
+                && mv.is_quiet()
                 && !in_check_now
             // Don't reduce if we are escaping check!
             {
-                // OLD (Suicidal): 1 + (depth / 3) + (move_count / 10)
-
-                // NEW (Standard):
-                // 1. Base reduction
-                r = 1 + (depth / 8) + (move_count as i32 / 20);
+                // 1. Base reduction, from the precomputed log table
+                r = lmr_reduction(depth, move_count);
 
                 // 2. History Safety (Keep this, it's good)
                 let history = ctx.history[mv.from.index() as usize][mv.to.index() as usize];
-                if history > FP_HISTORY_THRESHOLD {
+                if history > ctx.params.fp_history_threshold {
                     r -= 1; // Trust history
                 }
 
@@ -469,13 +561,15 @@ pub fn alpha_beta(
                 } // Leave at least depth 1
             }
 
-            // Perform the Reduced Search (Zero Window)
+            // Perform the Reduced Search (Zero Window). Every search of this
+            // move keeps its extension, or only the first move of a node
+            // would ever be extended.
             let (val, _) = alpha_beta(
                 board,
                 tables,
                 ctx,
                 tt,
-                depth - 1 - r,
+                depth - 1 + extension - r,
                 ply + 1,
                 -alpha - 1,
                 -alpha,
@@ -491,7 +585,7 @@ pub fn alpha_beta(
                     tables,
                     ctx,
                     tt,
-                    depth - 1,
+                    depth - 1 + extension,
                     ply + 1,
                     -alpha - 1,
                     -alpha,
@@ -520,6 +614,9 @@ pub fn alpha_beta(
 
         undo_move_basic(board, undo);
         move_count += 1;
+        if mv.is_quiet() {
+            let _ = quiets_tried.try_push(mv);
+        }
 
         if time.stop_signal {
             return (0, None);
@@ -530,6 +627,7 @@ pub fn alpha_beta(
             if score > alpha {
                 alpha = score;
                 best_move = Some(mv);
+                ctx.pv_table.update_pv(ply, mv);
             }
             if score >= beta {
                 // TT SAVE WITH MATE SCORE ADJUSTMENT (LowerBound/Beta Cutoff)
@@ -544,10 +642,12 @@ pub fn alpha_beta(
                 );
 
                 if !mv.is_capture() {
-                    ctx.update_killer(ply, mv);
-
-                    let bonus = depth * depth;
-                    ctx.update_history(mv, bonus);
+                    let prev_move = if ply > 0 {
+                        ctx.move_stack[ply - 1]
+                    } else {
+                        None
+                    };
+                    ctx.update_all_heuristics(mv, &quiets_tried, depth, ply, prev_move);
                 }
 
                 return (beta, Some(mv));
@@ -590,20 +690,82 @@ pub fn alpha_beta(
     (best_score, best_move)
 }
 
+/// Walk the TT from the root following hash moves to rebuild the principal
+/// variation. Stops at the first missing/illegal move or repeated position.
+fn extract_pv(
+    board: &mut Board,
+    tables: &MagicTables,
+    tt: &TranspositionTable,
+    first: Move,
+    max_len: usize,
+) -> Vec<Move> {
+    let mut pv = Vec::with_capacity(max_len);
+    let mut undos = Vec::with_capacity(max_len);
+    let mut legal = Vec::with_capacity(64);
+    let mut scratch = Vec::with_capacity(256);
+    let mut next = Some(first);
+
+    while let Some(mv) = next {
+        if pv.len() >= max_len {
+            break;
+        }
+        generate_legal(board, tables, &mut legal, &mut scratch);
+        if !legal.contains(&mv) {
+            break;
+        }
+        pv.push(mv);
+        undos.push(make_move_basic(board, mv));
+        if board.is_repetition() {
+            break;
+        }
+        next = tt
+            .probe(board.zobrist, 0, -INF, INF, 0)
+            .and_then(|(m, ..)| m);
+    }
+
+    while let Some(undo) = undos.pop() {
+        undo_move_basic(board, undo);
+    }
+    pv
+}
+
 pub fn search(
     board: &mut Board,
     tables: &MagicTables,
     tt: &mut TranspositionTable,
     max_depth: i32,
     time_limit: Option<Duration>,
-) -> (i32, Option<Move>) {
+) -> SearchResult {
+    let mut ctx = SearchContext::new();
+    search_with_context(
+        board,
+        tables,
+        tt,
+        &mut ctx,
+        max_depth,
+        TimeManager::new(time_limit),
+    )
+}
+
+/// Like `search`, but reuses the caller's killer and history tables so they
+/// carry over between searches in the same game, and takes a prepared
+/// `TimeManager` so node limits can be applied.
+pub fn search_with_context(
+    board: &mut Board,
+    tables: &MagicTables,
+    tt: &mut TranspositionTable,
+    ctx: &mut SearchContext,
+    max_depth: i32,
+    mut time: TimeManager,
+) -> SearchResult {
     let mut last_completed_best_move = None;
     let mut last_completed_best_score = 0;
+    let mut depth_reached = 0;
     let mut nodes = 0;
-    let mut ctx = SearchContext::new();
-    let mut time = TimeManager::new(time_limit);
     let mut last_iter_duration = Duration::from_millis(0);
-
+    tt.reset_stats();
+    // Build the LMR table now rather than on the first reduced move
+    lmr_table();
     for depth in 1..=max_depth {
         let iter_start = Instant::now();
 
@@ -612,23 +774,19 @@ pub fn search(
         // Conservative estimate: Next depth takes ~3x longer than previous.
         // (Using 3x instead of 2x because branching factor can spike in tactical positions)
         if depth > 1
-            && let Some(limit) = time.allocated_time()
+            && let Some(limit) = time.soft_time()
         {
             let total_elapsed = time.elapsed();
             let predicted_next = last_iter_duration * 3;
 
-            // If predicting the next depth would push us over the limit: STOP.
+            // If predicting the next depth would push us over the soft limit: STOP.
             if total_elapsed + predicted_next > limit {
                 break;
             }
         }
         // -----------------------------------------
 
-        for from in 0..64 {
-            for to in 0..64 {
-                ctx.history[from][to] /= 8;
-            }
-        }
+        ctx.decay_history();
 
         // --- Aspiration Window Logic ---
         let mut alpha = -INF;
@@ -647,7 +805,7 @@ pub fn search(
         loop {
             // Perform the search with the current window
             let result = alpha_beta(
-                board, tables, &mut ctx, tt, depth, 0, alpha, beta, &mut nodes, &mut time,
+                board, tables, ctx, tt, depth, 0, alpha, beta, &mut nodes, &mut time,
             );
 
             score = result.0;
@@ -687,30 +845,23 @@ pub fn search(
         }
 
         // Only update if the depth actually finished
+        if depth > 1 {
+            time.update_after_depth(score - last_completed_best_score);
+        }
         last_completed_best_score = score;
         last_completed_best_move = mv;
-
-        // Output info for GUI (standard UCI)
-        if let Some(valid_mv) = last_completed_best_move {
-            let score_str = if last_completed_best_score.abs() >= MATE_THRESHOLD {
-                let moves = (MATE_SCORE - last_completed_best_score.abs() + 1) / 2;
-                if last_completed_best_score > 0 {
-                    format!("mate {}", moves)
-                } else {
-                    format!("mate -{}", moves)
-                }
-            } else {
-                format!("cp {}", last_completed_best_score)
-            };
-
-            engine_println!(
-                "info depth {} score {} nodes {} time {} pv {}",
-                depth,
-                score_str,
+        depth_reached = depth;
+
+        if mv.is_some() && ctx.logger.enabled() {
+            ctx.logger.log(&format_uci_info(&SearchResult {
+                score,
+                best_move: mv,
+                pv: ctx.pv_table.get_pv(0).to_vec(),
+                depth_reached: depth,
                 nodes,
-                time.start_time.elapsed().as_millis(),
-                valid_mv.to_uci()
-            );
+                elapsed: time.elapsed(),
+                tt_hit_rate: tt.hit_rate(),
+            }));
         }
 
         // Optimization: If we found a mate, stop searching deeper
@@ -719,5 +870,28 @@ pub fn search(
         }
     }
 
-    (last_completed_best_score, last_completed_best_move)
+    // Stopped before depth 1 finished (e.g. an immediate UCI `stop`): still
+    // answer with a legal move rather than a null move
+    if last_completed_best_move.is_none() {
+        let mut moves = Vec::with_capacity(64);
+        let mut scratch = Vec::with_capacity(256);
+        generate_legal(board, tables, &mut moves, &mut scratch);
+        last_completed_best_move = moves.first().copied();
+    }
+
+    // Before extract_pv, whose own probes would count towards the rate
+    let tt_hit_rate = tt.hit_rate();
+    let pv = last_completed_best_move
+        .map(|best| extract_pv(board, tables, tt, best, depth_reached.max(1) as usize))
+        .unwrap_or_default();
+
+    SearchResult {
+        score: last_completed_best_score,
+        best_move: last_completed_best_move,
+        pv,
+        depth_reached,
+        nodes,
+        elapsed: time.elapsed(),
+        tt_hit_rate,
+    }
 }
@@ -1,50 +1,44 @@
 use crate::bitboard::BitboardExt;
 use crate::board::{Board, Color, Piece};
 use crate::moves::magic::MagicTables;
+use crate::moves::square_control::attackers_to;
 use crate::moves::types::Move;
 
 pub trait SeeExt {
     fn static_exchange_eval(&self, m: Move, threshold: i32, tables: &MagicTables) -> bool;
+    fn static_exchange_eval_score(&self, m: Move, tables: &MagicTables) -> i32;
     fn get_attackers_to_square_see(&self, square: u8, occupancy: u64, tables: &MagicTables) -> u64;
     fn get_lva_square(&self, attackers: u64, side: Color, occ: u64) -> u8;
 }
 
+impl Board {
+    /// True if `mv` passes SEE pruning: its exchange score is at least
+    /// `threshold`. The search's one entry point for SEE-based pruning.
+    #[inline]
+    pub fn see_pruning_for_move(&self, mv: Move, threshold: i32, tables: &MagicTables) -> bool {
+        self.see_score(mv, tables) >= threshold
+    }
+
+    /// Centipawn result of the exchange `mv` starts, for the moving side.
+    #[inline]
+    pub fn see_score(&self, mv: Move, tables: &MagicTables) -> i32 {
+        self.static_exchange_eval_score(mv, tables)
+    }
+}
+
 impl SeeExt for Board {
     fn get_attackers_to_square_see(&self, square: u8, occupancy: u64, tables: &MagicTables) -> u64 {
-        let sq_usize = square as usize;
-
-        // 1. Pawns
-        let white_pawns = crate::moves::pawn::pawn_attacks(square, Color::Black)
-            & self.pieces(Piece::Pawn, Color::White);
-        let black_pawns = crate::moves::pawn::pawn_attacks(square, Color::White)
-            & self.pieces(Piece::Pawn, Color::Black);
-
-        // 2. Knights
-        let knights =
-            self.pieces(Piece::Knight, Color::White) | self.pieces(Piece::Knight, Color::Black);
-        let knight_attacks = crate::moves::knight::KNIGHT_ATTACKS[sq_usize] & knights;
-
-        // 3. Kings
-        let kings = self.pieces(Piece::King, Color::White) | self.pieces(Piece::King, Color::Black);
-        let king_attacks = crate::moves::king::KING_ATTACKS[sq_usize] & kings;
-
-        // 4. Sliders
-        let bishop_queens = self.pieces(Piece::Bishop, Color::White)
-            | self.pieces(Piece::Bishop, Color::Black)
-            | self.pieces(Piece::Queen, Color::White)
-            | self.pieces(Piece::Queen, Color::Black);
-        let rook_queens = self.pieces(Piece::Rook, Color::White)
-            | self.pieces(Piece::Rook, Color::Black)
-            | self.pieces(Piece::Queen, Color::White)
-            | self.pieces(Piece::Queen, Color::Black);
-
-        let diag = tables.bishop.get_attacks(sq_usize, occupancy) & bishop_queens;
-        let orth = tables.rook.get_attacks(sq_usize, occupancy) & rook_queens;
-
-        (white_pawns | black_pawns | knight_attacks | king_attacks | diag | orth) & occupancy
+        attackers_to(self, square, Color::White, occupancy, tables)
+            | attackers_to(self, square, Color::Black, occupancy, tables)
     }
 
     fn static_exchange_eval(&self, m: Move, threshold: i32, tables: &MagicTables) -> bool {
+        self.static_exchange_eval_score(m, tables) >= threshold
+    }
+
+    /// Material balance (centipawns) of the full exchange sequence on `m.to`,
+    /// from the moving side's perspective.
+    fn static_exchange_eval_score(&self, m: Move, tables: &MagicTables) -> i32 {
         let to_sq = m.to.index();
         let from_sq = m.from.index();
 
@@ -63,73 +57,64 @@ impl SeeExt for Board {
         } else {
             match self.piece_type_at(m.to) {
                 Some(p) => p,
-                None => return threshold <= 0,
+                None => return 0,
             }
         };
 
-        let mut value = piece_value(next_victim_piece);
-
-        // FIX 1: Add promotion value (e.g., +800 if promoting to Queen)
+        // gain[d]: material balance for the side making capture d, assuming
+        // the exchange stops right after it
+        let mut gain = [0i32; 32];
+        gain[0] = piece_value(next_victim_piece);
         if let Some(p) = m.promotion {
-            value += piece_value(p) - piece_value(Piece::Pawn);
+            gain[0] += piece_value(p) - piece_value(Piece::Pawn);
         }
 
-        if value < threshold {
-            return false;
+        // Piece standing on `to` after the last capture (the promoted piece
+        // for a promotion), which the next capture wins
+        let mut on_square = m.promotion.unwrap_or(m.piece);
+
+        let mut occupancy = self.occupied() & !(1u64 << from_sq);
+        if m.is_en_passant() {
+            // The captured pawn sits on the same file as `to`, one rank behind it
+            let captured = match self.side_to_move {
+                Color::White => to_sq - 8,
+                Color::Black => to_sq + 8,
+            };
+            occupancy &= !(1u64 << captured);
         }
-
-        // FIX 2: The attacker is now the PROMOTED piece (Queen), not the original Pawn
-        let mut next_victim = if let Some(p) = m.promotion {
-            p
-        } else {
-            self.piece_type_at(m.from).unwrap()
-        };
-
-        let mut gain = [0; 32];
-        let mut d = 0;
-        gain[d] = value;
-
-        // 2. Occupancy Simulation
-        let mut occupancy = self.occupied();
-        occupancy &= !(1u64 << from_sq);
-
         let mut attackers = self.get_attackers_to_square_see(to_sq, occupancy, tables);
         let mut side_to_move = self.side_to_move.opposite();
+        let mut d = 0;
 
-        // 3. Swap Loop
         loop {
-            d += 1;
             let attacker_sq = self.get_lva_square(attackers, side_to_move, occupancy);
-            if attacker_sq == 64 {
+            if attacker_sq == 64 || d + 1 >= gain.len() {
                 break;
             }
+            d += 1;
+            gain[d] = piece_value(on_square) - gain[d - 1];
 
-            attackers &= !(1u64 << attacker_sq);
+            let attacker = self
+                .piece_type_at(crate::square::Square::from_index(attacker_sq))
+                .unwrap();
             occupancy &= !(1u64 << attacker_sq);
-
-            if matches!(next_victim, Piece::Bishop | Piece::Rook | Piece::Queen) {
-                attackers = self.get_attackers_to_square_see(to_sq, occupancy, tables);
-                attackers &= !(1u64 << attacker_sq);
-            }
-
-            if d >= 31 {
-                break;
-            }
-
-            gain[d] = piece_value(next_victim) - gain[d - 1];
-            next_victim = self
-                .piece_at(crate::square::Square::from_index(attacker_sq))
-                .unwrap()
-                .1;
+            // Any piece but a knight can stand on a ray to `to`, so removing
+            // it may uncover an x-ray attacker behind it
+            attackers = if attacker == Piece::Knight {
+                attackers & !(1u64 << attacker_sq)
+            } else {
+                self.get_attackers_to_square_see(to_sq, occupancy, tables)
+            };
+            on_square = attacker;
             side_to_move = side_to_move.opposite();
         }
 
-        // 4. Back-propagate
-        while d > 1 {
-            d -= 1;
+        // Each side may decline to continue the exchange
+        while d > 0 {
             gain[d - 1] = -std::cmp::max(-gain[d - 1], gain[d]);
+            d -= 1;
         }
-        gain[0] >= threshold
+        gain[0]
     }
 
     fn get_lva_square(&self, attackers: u64, side: Color, _occ: u64) -> u8 {
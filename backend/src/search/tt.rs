@@ -1,4 +1,5 @@
 use crate::moves::types::Move;
+use std::cell::Cell;
 
 // Make sure MATE_THRESHOLD matches what we define in search.rs (30000)
 pub const MATE_THRESHOLD: i32 = 30000;
@@ -20,14 +21,47 @@ pub struct TTEntry {
     pub generation: u8,
 }
 
+const EMPTY_ENTRY: TTEntry = TTEntry {
+    key: 0,
+    best_move: None,
+    score: 0,
+    depth: 0,
+    bound: 0,
+    generation: 0,
+};
+
+/// Probe counters since the last `reset_stats`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TTStats {
+    pub probes: u64,
+    pub hits: u64,
+    /// Hits whose stored move was not pseudo-legal in the probing position,
+    /// i.e. a different position with the same Zobrist key.
+    pub collisions: u64,
+}
+
 pub struct TranspositionTable {
     entries: Vec<TTEntry>,
     pub generation: u8,
+    // Probe statistics (interior mutability so `probe` can stay `&self`)
+    probes: Cell<u64>,
+    hits: Cell<u64>,
+    collisions: Cell<u64>,
 }
 
 impl TranspositionTable {
     pub fn new(size_mb: usize) -> Self {
-        // Allocate TT based on size in MB.
+        Self {
+            entries: vec![EMPTY_ENTRY; Self::capacity_for(size_mb)],
+            generation: 0,
+            probes: Cell::new(0),
+            hits: Cell::new(0),
+            collisions: Cell::new(0),
+        }
+    }
+
+    /// Largest power-of-two entry count that fits in `size_mb` megabytes (at least 1).
+    fn capacity_for(size_mb: usize) -> usize {
         let entry_size = std::mem::size_of::<TTEntry>();
         let num_entries = (size_mb * 1024 * 1024) / entry_size;
 
@@ -36,24 +70,56 @@ impl TranspositionTable {
         while capacity * 2 <= num_entries {
             capacity *= 2;
         }
+        capacity
+    }
 
-        Self {
-            entries: vec![
-                TTEntry {
-                    key: 0,
-                    best_move: None,
-                    score: 0,
-                    depth: 0,
-                    bound: 0,
-                    generation: 0,
-                };
-                capacity
-            ],
+    /// Reallocate the table for `size_mb` megabytes. All entries are discarded
+    /// and the generation is reset, so only call this between searches.
+    pub fn resize(&mut self, size_mb: usize) {
+        self.entries = vec![EMPTY_ENTRY; Self::capacity_for(size_mb)];
+        self.generation = 0;
+        self.reset_stats();
+    }
 
-            generation: 0,
+    /// Number of entry slots in the table.
+    pub fn capacity_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Memory used by the entry slots, in bytes.
+    pub fn capacity_bytes(&self) -> usize {
+        self.entries.len() * std::mem::size_of::<TTEntry>()
+    }
+
+    /// Fraction of probes since the last `reset_stats` that found a matching entry.
+    pub fn hit_rate(&self) -> f64 {
+        let probes = self.probes.get();
+        if probes == 0 {
+            0.0
+        } else {
+            self.hits.get() as f64 / probes as f64
         }
     }
 
+    pub fn stats(&self) -> TTStats {
+        TTStats {
+            probes: self.probes.get(),
+            hits: self.hits.get(),
+            collisions: self.collisions.get(),
+        }
+    }
+
+    /// Count a hit whose move failed validation (see `TTStats::collisions`).
+    pub fn record_collision(&self) {
+        self.collisions.set(self.collisions.get() + 1);
+    }
+
+    pub fn reset_stats(&self) {
+        self.probes.set(0);
+        self.hits.set(0);
+        self.collisions.set(0);
+    }
+
     pub fn new_search(&mut self) {
         self.generation = self.generation.wrapping_add(1);
     }
@@ -109,7 +175,9 @@ impl TranspositionTable {
         let index = (key as usize) & (self.entries.len() - 1);
         let entry = &self.entries[index];
 
+        self.probes.set(self.probes.get() + 1);
         if entry.key == key {
+            self.hits.set(self.hits.get() + 1);
             let score = entry.score as i32;
             return Some((entry.best_move, score, entry.depth, entry.bound));
         }
@@ -6,12 +6,12 @@ use std::str::FromStr;
 pub struct Square(u8);
 
 impl Square {
-    pub fn from_index(idx: u8) -> Self {
+    pub const fn from_index(idx: u8) -> Self {
         assert!(idx < 64, "Square index out of range");
         Square(idx)
     }
 
-    pub fn index(self) -> u8 {
+    pub const fn index(self) -> u8 {
         self.0
     }
 
@@ -27,6 +27,12 @@ impl Square {
         self.0 % 8
     }
 
+    /// True for light squares (h1, a8, ...); a1 is dark
+    #[inline(always)]
+    pub fn is_light(self) -> bool {
+        (self.rank() + self.file()) % 2 == 1
+    }
+
     /// Creates a Square from file and rank indices (both 0-7)
     #[inline(always)]
     pub fn from_file_rank(file: u8, rank: u8) -> Self {
@@ -124,6 +130,25 @@ mod tests {
         assert_eq!(sq.to_string(), "e4");
     }
 
+    #[test]
+    fn test_is_light() {
+        for (name, light) in [
+            ("a1", false),
+            ("h1", true),
+            ("a8", true),
+            ("h8", false),
+            ("e4", true),
+            ("d4", false),
+        ] {
+            assert_eq!(
+                Square::from_str(name).unwrap().is_light(),
+                light,
+                "{}",
+                name
+            );
+        }
+    }
+
     #[test]
     fn test_try_from_invalid_index() {
         let result = Square::try_from(99);
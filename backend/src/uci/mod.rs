@@ -0,0 +1,4 @@
+//! Protocol front ends that live in the library so they can be driven from
+//! tests. The UCI loop itself is in `bin/cli.rs`.
+
+pub mod xboard;
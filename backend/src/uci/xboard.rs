@@ -0,0 +1,245 @@
+//! Minimal XBoard/WinBoard (CECP) front end, for GUIs that don't speak UCI.
+//!
+//! Moves use coordinate notation in both directions, which is the same text
+//! as UCI, so `Move::from_uci`/`to_uci` do the parsing and printing.
+
+use crate::board::{Board, Color};
+use crate::moves::execute::make_move_basic;
+use crate::moves::magic::MagicTables;
+use crate::moves::magic::loader::load_magic_tables;
+use crate::moves::types::Move;
+use crate::search::context::SearchContext;
+use crate::search::search::{TimeManager, search_with_context};
+use crate::search::tt::TranspositionTable;
+use crate::status::{GameStatus, position_status};
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+const XBOARD_HASH_MB: usize = 64;
+const DEFAULT_MOVE_TIME: Duration = Duration::from_secs(5);
+
+/// Engine state between XBoard commands.
+pub struct XBoardEngine {
+    board: Board,
+    tables: MagicTables,
+    tt: TranspositionTable,
+    ctx: SearchContext,
+    /// `force` mode: accept moves for both sides, never reply.
+    force: bool,
+    /// The side the engine plays when not in force mode.
+    engine_color: Color,
+    max_depth: i32,
+    move_time: Duration,
+}
+
+impl XBoardEngine {
+    pub fn new(tables: MagicTables) -> Self {
+        Self {
+            board: Board::new(),
+            tables,
+            tt: TranspositionTable::new(XBOARD_HASH_MB),
+            ctx: SearchContext::new(),
+            force: false,
+            engine_color: Color::Black,
+            max_depth: 64,
+            move_time: DEFAULT_MOVE_TIME,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Handle one line of input, writing any replies to `out`.
+    /// Returns `Ok(false)` once `quit` has been received.
+    pub fn handle_command(&mut self, line: &str, out: &mut impl Write) -> io::Result<bool> {
+        let line = line.trim();
+        let (command, args) = line.split_once(' ').unwrap_or((line, ""));
+        let args = args.trim();
+
+        match command {
+            "" | "xboard" | "accepted" | "rejected" | "random" | "post" | "nopost" | "hard"
+            | "easy" | "computer" | "level" | "time" | "otim" => {}
+            "protover" => writeln!(
+                out,
+                "feature myname=\"Vantage\" usermove=1 setboard=1 ping=1 sigint=0 sigterm=0 done=1"
+            )?,
+            "ping" => writeln!(out, "pong {}", args)?,
+            "new" => {
+                self.board = Board::new();
+                self.tt.clear();
+                self.ctx.reset_for_new_game();
+                self.force = false;
+                self.engine_color = Color::Black;
+            }
+            "force" => self.force = true,
+            "go" => {
+                self.force = false;
+                self.engine_color = self.board.side_to_move;
+                self.think(out)?;
+            }
+            "setboard" => match Board::from_str(args) {
+                Ok(board) => self.board = board,
+                Err(_) => writeln!(out, "tellusererror Illegal position")?,
+            },
+            "usermove" => self.user_move(args, out)?,
+            "sd" => match args.parse::<i32>() {
+                Ok(depth) if depth > 0 => self.max_depth = depth,
+                _ => writeln!(out, "Error (bad depth): {}", line)?,
+            },
+            "st" => match args.parse::<u64>() {
+                Ok(secs) if secs > 0 => self.move_time = Duration::from_secs(secs),
+                _ => writeln!(out, "Error (bad time): {}", line)?,
+            },
+            "quit" => return Ok(false),
+            // Without `usermove=1` accepted, moves arrive bare
+            _ if args.is_empty() && Move::from_uci(command, &self.board).is_ok() => {
+                self.user_move(command, out)?
+            }
+            _ => writeln!(out, "Error (unknown command): {}", command)?,
+        }
+        Ok(true)
+    }
+
+    fn user_move(&mut self, text: &str, out: &mut impl Write) -> io::Result<()> {
+        let mv = match Move::from_uci(text, &self.board) {
+            Ok(mv) if self.board.is_legal_move_for(mv, &self.tables) => mv,
+            _ => return writeln!(out, "Illegal move: {}", text),
+        };
+        if self.status() != GameStatus::InPlay {
+            return writeln!(out, "Illegal move (game is over): {}", text);
+        }
+        make_move_basic(&mut self.board, mv);
+        if self.report_result(out)? {
+            return Ok(());
+        }
+        if !self.force && self.board.side_to_move == self.engine_color {
+            self.think(out)?;
+        }
+        Ok(())
+    }
+
+    /// Search the current position and play the best move.
+    fn think(&mut self, out: &mut impl Write) -> io::Result<()> {
+        if self.report_result(out)? {
+            return Ok(());
+        }
+        let result = search_with_context(
+            &mut self.board,
+            &self.tables,
+            &mut self.tt,
+            &mut self.ctx,
+            self.max_depth,
+            TimeManager::new(Some(self.move_time)),
+        );
+        let Some(mv) = result.best_move else {
+            return Ok(());
+        };
+        make_move_basic(&mut self.board, mv);
+        writeln!(out, "move {}", mv.to_uci())?;
+        self.report_result(out)?;
+        Ok(())
+    }
+
+    fn status(&mut self) -> GameStatus {
+        position_status(&mut self.board, &self.tables)
+    }
+
+    /// Print the result line if the game has ended; returns whether it has.
+    fn report_result(&mut self, out: &mut impl Write) -> io::Result<bool> {
+        let result = match self.status() {
+            GameStatus::InPlay => return Ok(false),
+            GameStatus::Checkmate => match self.board.side_to_move {
+                Color::White => "0-1 {Black mates}",
+                Color::Black => "1-0 {White mates}",
+            },
+            GameStatus::Stalemate => "1/2-1/2 {Stalemate}",
+            GameStatus::DrawThreefold | GameStatus::DrawFivefold => "1/2-1/2 {Draw by repetition}",
+            GameStatus::DrawFiftyMove | GameStatus::DrawSeventyFiveMove => {
+                "1/2-1/2 {Draw by fifty move rule}"
+            }
+            GameStatus::DrawDeadPosition => "1/2-1/2 {Insufficient material}",
+        };
+        writeln!(out, "{}", result)?;
+        Ok(true)
+    }
+}
+
+/// Run the XBoard dialogue over `input`/`out` until `quit` or end of input.
+pub fn run_xboard<R: BufRead, W: Write>(
+    input: R,
+    out: &mut W,
+    tables: MagicTables,
+) -> io::Result<()> {
+    let mut engine = XBoardEngine::new(tables);
+    for line in input.lines() {
+        let keep_going = engine.handle_command(&line?, out)?;
+        out.flush()?;
+        if !keep_going {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Speak XBoard on stdin/stdout.
+pub fn run_xboard_loop() {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    if let Err(e) = run_xboard(stdin.lock(), &mut stdout, load_magic_tables()) {
+        eprintln!("xboard: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dialogue(commands: &str) -> String {
+        let mut out = Vec::new();
+        run_xboard(commands.as_bytes(), &mut out, load_magic_tables()).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_xboard_handshake_and_reply() {
+        let out = dialogue("xboard\nprotover 2\nping 7\nnew\nsd 2\nusermove e2e4\nquit\n");
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].starts_with("feature ") && lines[0].ends_with("done=1"));
+        assert_eq!(lines[1], "pong 7");
+        assert_eq!(lines.len(), 3, "{}", out);
+
+        // The reply must be a legal Black move after 1. e4
+        let reply = lines[2].strip_prefix("move ").unwrap();
+        let mut board = Board::new();
+        let e4 = Move::from_uci("e2e4", &board).unwrap();
+        make_move_basic(&mut board, e4);
+        let mv = Move::from_uci(reply, &board).unwrap();
+        assert!(board.is_legal_move_for(mv, &load_magic_tables()));
+    }
+
+    #[test]
+    fn test_xboard_force_mode_stays_silent() {
+        let out = dialogue("new\nforce\nusermove e2e4\nusermove e7e5\nquit\n");
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn test_xboard_illegal_move() {
+        let out = dialogue("new\nforce\nusermove e2e5\nusermove e7e5\nquit\n");
+        assert_eq!(out, "Illegal move: e2e5\nIllegal move: e7e5\n");
+    }
+
+    #[test]
+    fn test_xboard_setboard_go_mates() {
+        let out = dialogue("setboard 6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1\nsd 3\ngo\nquit\n");
+        assert_eq!(out, "move a1a8\n1-0 {White mates}\n");
+    }
+
+    #[test]
+    fn test_xboard_bad_setboard() {
+        let out = dialogue("setboard not a fen\nquit\n");
+        assert_eq!(out, "tellusererror Illegal position\n");
+    }
+}
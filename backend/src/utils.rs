@@ -18,19 +18,6 @@ pub const fn square_index(rank: usize, file: usize) -> usize {
     rank * 8 + file
 }
 
-/// Converts a bitboard into a printable 8x8 board view for debugging.
-pub fn bitboard_to_string(bb: u64) -> String {
-    let mut s = String::new();
-    for rank in (0..8).rev() {
-        for file in 0..8 {
-            let idx = rank * 8 + file;
-            s.push(if (bb >> idx) & 1 == 1 { 'X' } else { '.' });
-        }
-        s.push('\n');
-    }
-    s
-}
-
 #[inline(always)]
 pub fn pop_lsb(bb: &mut u64) -> u8 {
     debug_assert_ne!(*bb, 0, "Called pop_lsb on empty bitboard");
@@ -38,3 +25,7 @@ pub fn pop_lsb(bb: &mut u64) -> u8 {
     *bb &= *bb - 1;
     idx
 }
+
+pub mod pgn_verify;
+#[cfg(feature = "debug_registry")]
+pub mod registry;
@@ -0,0 +1,251 @@
+//! Replay every game of a PGN collection and report moves that fail.
+//!
+//! Used to exercise the SAN parser and move executor against real game
+//! databases: a correct database should produce no errors at all. The same
+//! replay also backs `parse_annotated_pgn`, which keeps NAGs and comments.
+
+use crate::board::Board;
+use crate::error::VantageError;
+use crate::game::{AnnotatedMove, Nag};
+use crate::moves::execute::make_move_basic;
+use crate::moves::magic::MagicTables;
+use crate::moves::types::Move;
+use crate::status::{GameStatus, position_status};
+use std::str::FromStr;
+
+/// A move in a PGN collection that could not be parsed or played.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgnVerificationError {
+    /// Zero-based index of the game in the file.
+    pub game_index: usize,
+    /// Full-move number of the failing move (0 for errors before the first move).
+    pub move_number: u32,
+    /// The SAN token as written in the file.
+    pub san: String,
+    pub error: VantageError,
+}
+
+/// Read a multi-game PGN file and verify every game in it.
+///
+/// A file that cannot be read is reported as a single `Io` error.
+pub fn verify_pgn_file(path: &str, tables: &MagicTables) -> Vec<PgnVerificationError> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => verify_pgn(&text, tables),
+        Err(e) => vec![PgnVerificationError {
+            game_index: 0,
+            move_number: 0,
+            san: String::new(),
+            error: VantageError::Io(format!("{}: {}", path, e)),
+        }],
+    }
+}
+
+/// Verify every game in `text`, stopping each game at its first bad move.
+pub fn verify_pgn(text: &str, tables: &MagicTables) -> Vec<PgnVerificationError> {
+    let mut errors = Vec::new();
+    for (game_index, game) in split_games(text).iter().enumerate() {
+        if let Err(e) = verify_game(game, tables) {
+            errors.push(PgnVerificationError { game_index, ..e });
+        }
+    }
+    errors
+}
+
+/// Replay every game in `text`, keeping each move's NAGs and comments.
+///
+/// Fails on the first game with a bad move; `game_index` says which.
+pub fn parse_annotated_pgn(
+    text: &str,
+    tables: &MagicTables,
+) -> Result<Vec<Vec<AnnotatedMove>>, PgnVerificationError> {
+    split_games(text)
+        .iter()
+        .enumerate()
+        .map(|(game_index, game)| {
+            replay_game(game, tables).map_err(|e| PgnVerificationError { game_index, ..e })
+        })
+        .collect()
+}
+
+/// One game's raw text: the FEN tag (if any) and the move text.
+struct PgnGame {
+    fen: Option<String>,
+    movetext: String,
+}
+
+/// Split a collection into games. A tag line that follows move text starts
+/// a new game.
+fn split_games(text: &str) -> Vec<PgnGame> {
+    let mut games = Vec::new();
+    let mut fen = None;
+    let mut movetext = String::new();
+    let mut in_tags = false;
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            if !in_tags && !movetext.trim().is_empty() {
+                games.push(PgnGame {
+                    fen: fen.take(),
+                    movetext: std::mem::take(&mut movetext),
+                });
+            }
+            in_tags = true;
+            if let Some(value) = line.strip_prefix("[FEN \"") {
+                fen = value.split('"').next().map(str::to_string);
+            }
+        } else if !line.is_empty() {
+            in_tags = false;
+            movetext.push_str(line);
+            movetext.push('\n');
+        }
+    }
+    if !movetext.trim().is_empty() {
+        games.push(PgnGame { fen, movetext });
+    }
+    games
+}
+
+/// One move of the main line with the annotations that follow it.
+struct PgnToken {
+    san: String,
+    nags: Vec<Nag>,
+    comment: Option<String>,
+}
+
+/// Split move text into main-line moves, skipping variations, move numbers
+/// and results. NAGs (`$n` or `!`/`?` suffixes) and comments (`{...}` or
+/// `; ...`) are attached to the move before them.
+fn tokens(movetext: &str) -> Vec<PgnToken> {
+    let mut out: Vec<PgnToken> = Vec::new();
+    let mut chars = movetext.chars().peekable();
+    let mut variation_depth = 0u32;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | ';' => {
+                let end = if c == '{' { '}' } else { '\n' };
+                let text: String = chars.by_ref().take_while(|&ch| ch != end).collect();
+                let text = text.trim();
+                if variation_depth == 0
+                    && !text.is_empty()
+                    && let Some(last) = out.last_mut()
+                {
+                    last.comment = Some(match last.comment.take() {
+                        Some(prev) => format!("{} {}", prev, text),
+                        None => text.to_string(),
+                    });
+                }
+            }
+            '(' => variation_depth += 1,
+            ')' => variation_depth = variation_depth.saturating_sub(1),
+            _ if c.is_whitespace() => {}
+            _ => {
+                let mut word = String::from(c);
+                while let Some(&ch) = chars.peek() {
+                    if ch == '(' && is_promotion_suffix(chars.clone()) {
+                        // "e8(Q)" is a promotion, not the start of a variation
+                        word.extend(chars.by_ref().take(3));
+                        continue;
+                    }
+                    if ch.is_whitespace() || matches!(ch, '{' | '(' | ')' | ';') {
+                        break;
+                    }
+                    word.push(ch);
+                    chars.next();
+                }
+                if variation_depth == 0 {
+                    push_word(&word, &mut out);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// True if `rest` starts with "(Q)", "(R)", "(B)" or "(N)".
+fn is_promotion_suffix(mut rest: impl Iterator<Item = char>) -> bool {
+    rest.next() == Some('(')
+        && matches!(rest.next(), Some('Q' | 'R' | 'B' | 'N'))
+        && rest.next() == Some(')')
+}
+
+/// The main-line SAN moves of `movetext`, without annotations.
+pub(crate) fn san_moves(movetext: &str) -> Vec<String> {
+    tokens(movetext).into_iter().map(|t| t.san).collect()
+}
+
+fn push_word(word: &str, out: &mut Vec<PgnToken>) {
+    // "12." / "12..." prefixes can be glued to the move ("12.e4")
+    let word = word.rsplit('.').next().unwrap_or(word);
+    if word.is_empty() || matches!(word, "1-0" | "0-1" | "1/2-1/2" | "*") {
+        return;
+    }
+
+    if let Some(code) = word.strip_prefix('$') {
+        if let (Ok(code), Some(last)) = (code.parse::<u8>(), out.last_mut()) {
+            last.nags.push(Nag::from_code(code));
+        }
+        return;
+    }
+
+    // A free-standing glyph ("e4 !?") or one glued to the move ("e4!?")
+    let san = word.trim_end_matches(['!', '?']);
+    let nag = Nag::from_glyph(&word[san.len()..]);
+    if !san.is_empty() {
+        out.push(PgnToken {
+            san: san.to_string(),
+            nags: Vec::new(),
+            comment: None,
+        });
+    }
+    if let (Some(nag), Some(last)) = (nag, out.last_mut()) {
+        last.nags.push(nag);
+    }
+}
+
+fn verify_game(game: &PgnGame, tables: &MagicTables) -> Result<(), PgnVerificationError> {
+    replay_game(game, tables).map(|_| ())
+}
+
+/// Replay one game against `tables`, returning its moves with their
+/// annotations. `from_san` only returns legal moves, so the board is played
+/// on directly rather than through a `Game`.
+fn replay_game(
+    game: &PgnGame,
+    tables: &MagicTables,
+) -> Result<Vec<AnnotatedMove>, PgnVerificationError> {
+    let error = |move_number: u32, san: &str, error: VantageError| PgnVerificationError {
+        game_index: 0,
+        move_number,
+        san: san.to_string(),
+        error,
+    };
+
+    let mut board = match &game.fen {
+        Some(fen) => {
+            Board::from_str(fen).map_err(|e| error(0, "", VantageError::FenParseError(e)))?
+        }
+        None => Board::new(),
+    };
+    let mut status = position_status(&mut board, tables);
+
+    let mut moves = Vec::new();
+    for token in tokens(&game.movetext) {
+        let san = &token.san;
+        let move_number = board.fullmove_number;
+        // Report moves past the end of the game as such, not as unparseable
+        if status != GameStatus::InPlay {
+            return Err(error(move_number, san, VantageError::GameOver(status)));
+        }
+        let mv = Move::from_san(san, &board, tables).map_err(|e| error(move_number, san, e))?;
+        make_move_basic(&mut board, mv);
+        status = position_status(&mut board, tables);
+        moves.push(AnnotatedMove {
+            mv,
+            nags: token.nags,
+            comment: token.comment,
+        });
+    }
+    Ok(moves)
+}
@@ -0,0 +1,168 @@
+//! Zobrist key -> board lookup for debugging the transposition table.
+//!
+//! Only built with the `debug_registry` feature. With it, `alpha_beta`
+//! records every node it visits in `SearchContext::registry`, so a key seen
+//! in a TT entry can be turned back into a position. That costs a `Board`
+//! clone per new key; never enable it for play.
+
+use std::collections::HashMap;
+
+use crate::board::Board;
+use crate::hash::zobrist::ep_file_to_hash;
+
+/// Boards seen during search, keyed by `Board::zobrist`.
+#[derive(Default)]
+pub struct PositionRegistry {
+    positions: HashMap<u64, Board>,
+    collisions: u64,
+}
+
+impl PositionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember `board` under its Zobrist key. Returns `false` if a
+    /// different position already holds that key; the first one is kept
+    /// and the collision counted. Positions that differ only in their
+    /// repetition history are the same position.
+    pub fn record(&mut self, board: &Board) -> bool {
+        match self.positions.get(&board.zobrist) {
+            Some(known) if same_position(known, board) => true,
+            Some(_) => {
+                self.collisions += 1;
+                false
+            }
+            None => {
+                self.positions.insert(board.zobrist, board.clone());
+                true
+            }
+        }
+    }
+
+    /// The board recorded under `key`, if any.
+    pub fn get(&self, key: u64) -> Option<&Board> {
+        self.positions.get(&key)
+    }
+
+    /// Number of times `record` was handed a different position whose key
+    /// was already taken.
+    pub fn collisions(&self) -> u64 {
+        self.collisions
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.collisions = 0;
+    }
+}
+
+/// Same pieces, side to move and castling rights, and the same en passant
+/// file where a capture is possible (the key ignores the rest, see
+/// `ep_file_to_hash`).
+fn same_position(a: &Board, b: &Board) -> bool {
+    a.piece_bb == b.piece_bb
+        && a.side_to_move == b.side_to_move
+        && a.castling_rights == b.castling_rights
+        && ep_file_to_hash(a) == ep_file_to_hash(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::moves::execute::{generate_legal, make_move_basic};
+    use crate::moves::magic::loader::load_magic_tables;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_registry_lookup_by_key() {
+        let tables = load_magic_tables();
+        let mut rng = StdRng::seed_from_u64(0x5EED);
+        let mut moves = Vec::new();
+        let mut scratch = Vec::new();
+
+        let mut board = Board::new();
+        let mut seen = vec![board.clone()];
+        while seen.len() < 50 {
+            generate_legal(&mut board, &tables, &mut moves, &mut scratch);
+            if moves.is_empty() {
+                board = Board::new();
+                continue;
+            }
+            make_move_basic(&mut board, moves[rng.random_range(0..moves.len())]);
+            if seen.iter().all(|b| b.zobrist != board.zobrist) {
+                seen.push(board.clone());
+            }
+        }
+
+        let mut registry = PositionRegistry::new();
+        for b in &seen {
+            assert!(registry.record(b));
+        }
+        assert_eq!(registry.len(), 50);
+        for b in &seen {
+            assert_eq!(registry.get(b.zobrist), Some(b));
+            assert_eq!(Board::from_position_key(b.zobrist, &seen), Some(b));
+        }
+        assert_eq!(registry.get(0xDEAD_BEEF), None);
+
+        // Recording a position again is not a collision
+        assert!(registry.record(&seen[10]));
+        assert_eq!(registry.collisions(), 0);
+    }
+
+    #[test]
+    fn test_registry_keeps_first_board_on_collision() {
+        let mut registry = PositionRegistry::new();
+        let start = Board::new();
+        assert!(registry.record(&start));
+
+        // A different position forced onto the same key
+        let mut other = Board::new();
+        other.castling_rights = 0;
+        other.zobrist = start.zobrist;
+        assert!(!registry.record(&other));
+
+        assert_eq!(registry.collisions(), 1);
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get(start.zobrist), Some(&start));
+    }
+
+    #[test]
+    fn test_search_records_visited_positions() {
+        use crate::search::context::SearchContext;
+        use crate::search::search::{TimeManager, search_with_context};
+        use crate::search::tt::TranspositionTable;
+        use std::str::FromStr;
+
+        let tables = load_magic_tables();
+        let mut board =
+            Board::from_str("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3")
+                .unwrap();
+        let root = board.clone();
+        let mut tt = TranspositionTable::new(1);
+        let mut ctx = SearchContext::new();
+        search_with_context(
+            &mut board,
+            &tables,
+            &mut tt,
+            &mut ctx,
+            3,
+            TimeManager::new(None),
+        );
+
+        assert!(ctx.registry.len() > 20);
+        assert_eq!(ctx.registry.collisions(), 0);
+        let found = ctx.registry.get(root.zobrist).unwrap();
+        assert_eq!(found.to_fen(), root.to_fen());
+    }
+}
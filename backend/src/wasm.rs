@@ -1,3 +1,4 @@
+use js_sys::{Object, Reflect};
 use wasm_bindgen::prelude::*;
 
 use crate::board::{Board, Color, Piece};
@@ -8,7 +9,10 @@ use crate::moves::magic::loader::load_magic_tables;
 use crate::moves::types::Move;
 use crate::search::search::search;
 use crate::search::tt::TranspositionTable;
+use crate::search::{SearchResult, print_uci_info};
+use crate::status::{GameStatus, position_status};
 use std::str::FromStr;
+use std::sync::OnceLock;
 use std::time::Duration;
 
 #[wasm_bindgen]
@@ -141,13 +145,17 @@ impl VantageEngine {
             }
         }
 
-        let (score, best_move) = search(
+        let result = search(
             &mut self.board,
             &self.magic_tables,
             &mut self.tt,
             depth,
             time_limit,
         );
+        print_uci_info(&result);
+        let SearchResult {
+            score, best_move, ..
+        } = result;
 
         let move_str = best_move
             .map(|m| m.to_uci())
@@ -160,54 +168,160 @@ impl VantageEngine {
     }
 
     fn parse_uci_move(&self, move_str: &str) -> Option<Move> {
-        if move_str.len() < 4 {
-            return None;
+        let mut board_copy = self.board.clone();
+        find_uci_move(&mut board_copy, &self.magic_tables, move_str)
+    }
+}
+
+/// Magic tables shared by every `WasmBoard` and `wasm_search` call.
+static MAGIC_TABLES: OnceLock<MagicTables> = OnceLock::new();
+
+fn shared_tables() -> &'static MagicTables {
+    MAGIC_TABLES.get_or_init(load_magic_tables)
+}
+
+/// Build the shared magic tables up front. Optional: they are otherwise built
+/// lazily by the first `WasmBoard` or `wasm_search` call that needs them.
+#[wasm_bindgen]
+pub fn wasm_init() {
+    console_error_panic_hook::set_once();
+    shared_tables();
+}
+
+/// Like `wasm_init`, but returns a Promise and builds the tables from a
+/// `setTimeout(0)` callback, so the page can paint before the work starts.
+#[wasm_bindgen]
+pub fn wasm_init_async() -> js_sys::Promise {
+    console_error_panic_hook::set_once();
+    js_sys::Promise::new(&mut |resolve, _reject| {
+        let init = move || {
+            shared_tables().precompute_all_attacks();
+            let _ = resolve.call0(&JsValue::NULL);
+        };
+
+        let set_timeout = Reflect::get(&js_sys::global(), &JsValue::from_str("setTimeout"))
+            .ok()
+            .and_then(|f| f.dyn_into::<js_sys::Function>().ok());
+        match set_timeout {
+            Some(set_timeout) => {
+                let callback = Closure::once_into_js(init);
+                let _ = set_timeout.call2(&JsValue::NULL, &callback, &JsValue::from(0));
+            }
+            // No timer available (unusual host): initialize synchronously
+            None => init(),
         }
-        let chars: Vec<char> = move_str.chars().collect();
-        let from_file = (chars[0] as u8).wrapping_sub(b'a');
-        let from_rank = (chars[1] as u8).wrapping_sub(b'1');
-        let to_file = (chars[2] as u8).wrapping_sub(b'a');
-        let to_rank = (chars[3] as u8).wrapping_sub(b'1');
-
-        if from_file > 7 || from_rank > 7 || to_file > 7 || to_rank > 7 {
-            return None;
+    })
+}
+
+/// A lightweight board handle for JS. Unlike `VantageEngine` it carries no
+/// transposition table or book, and all instances share one set of magic tables.
+#[wasm_bindgen]
+pub struct WasmBoard {
+    board: Board,
+}
+
+#[wasm_bindgen]
+impl WasmBoard {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmBoard {
+        WasmBoard {
+            board: Board::new(),
         }
-        let from_square = (from_rank * 8 + from_file) as usize;
-        let to_square = (to_rank * 8 + to_file) as usize;
-
-        let promo_piece = if move_str.len() >= 5 {
-            match chars[4] {
-                'q' => Some(Piece::Queen),
-                'r' => Some(Piece::Rook),
-                'b' => Some(Piece::Bishop),
-                'n' => Some(Piece::Knight),
-                _ => None,
-            }
-        } else {
-            None
-        };
+    }
+
+    pub fn from_fen(fen: &str) -> Result<WasmBoard, JsValue> {
+        Board::from_str(fen)
+            .map(|board| WasmBoard { board })
+            .map_err(|e| JsValue::from_str(&e))
+    }
+
+    pub fn to_fen(&self) -> String {
+        self.board.to_fen()
+    }
 
+    /// Legal moves as `{ from: "e2", to: "e4", promotion: "q" | null }` objects.
+    pub fn legal_moves(&self) -> Vec<JsValue> {
+        let mut board = self.board.clone();
         let mut moves: Vec<Move> = Vec::with_capacity(256);
         let mut scratch: Vec<Move> = Vec::with_capacity(256);
-        let mut board_copy = self.board.clone();
-        generate_legal(
-            &mut board_copy,
-            &self.magic_tables,
-            &mut moves,
-            &mut scratch,
-        );
+        generate_legal(&mut board, shared_tables(), &mut moves, &mut scratch);
 
-        for mv in moves {
-            if (mv.from.index() as usize) == from_square && (mv.to.index() as usize) == to_square {
-                if promo_piece.is_some() {
-                    if mv.promotion == promo_piece {
-                        return Some(mv);
-                    }
-                } else if mv.promotion.is_none() {
-                    return Some(mv);
-                }
+        moves.iter().map(move_to_js).collect()
+    }
+
+    /// Apply a UCI move (e.g. "e2e4", "e7e8q"). Errors if it is not legal here.
+    pub fn push_uci(&mut self, uci: &str) -> Result<(), JsValue> {
+        match find_uci_move(&mut self.board, shared_tables(), uci) {
+            Some(mv) => {
+                make_move_basic(&mut self.board, mv);
+                Ok(())
             }
+            None => Err(JsValue::from_str(&format!("Illegal move: {}", uci))),
         }
-        None
     }
+
+    /// Game status: "in_play", "checkmate", "stalemate", or one of the "draw_*" values.
+    pub fn status(&self) -> String {
+        let mut board = self.board.clone();
+        let status = match position_status(&mut board, shared_tables()) {
+            GameStatus::InPlay => "in_play",
+            GameStatus::Checkmate => "checkmate",
+            GameStatus::Stalemate => "stalemate",
+            GameStatus::DrawThreefold => "draw_threefold",
+            GameStatus::DrawFivefold => "draw_fivefold",
+            GameStatus::DrawFiftyMove => "draw_fifty_move",
+            GameStatus::DrawSeventyFiveMove => "draw_seventy_five_move",
+            GameStatus::DrawDeadPosition => "draw_dead_position",
+        };
+        status.to_string()
+    }
+}
+
+impl Default for WasmBoard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Search `fen` to a fixed depth and return the best move in UCI notation,
+/// "0000" if there is none, or an empty string if the FEN is invalid.
+#[wasm_bindgen]
+pub fn wasm_search(fen: &str, depth: u32) -> String {
+    let Ok(mut board) = Board::from_str(fen) else {
+        return String::new();
+    };
+    let mut tt = TranspositionTable::new(16);
+    let SearchResult { best_move, .. } =
+        search(&mut board, shared_tables(), &mut tt, depth as i32, None);
+
+    best_move
+        .map(|m| m.to_uci())
+        .unwrap_or_else(|| "0000".to_string())
+}
+
+fn move_to_js(mv: &Move) -> JsValue {
+    let obj = Object::new();
+    let promotion = match mv.promotion {
+        Some(Piece::Queen) => JsValue::from_str("q"),
+        Some(Piece::Rook) => JsValue::from_str("r"),
+        Some(Piece::Bishop) => JsValue::from_str("b"),
+        Some(Piece::Knight) => JsValue::from_str("n"),
+        _ => JsValue::NULL,
+    };
+    // Reflect::set only fails on non-objects and frozen objects
+    let _ = Reflect::set(&obj, &"from".into(), &mv.from.to_string().into());
+    let _ = Reflect::set(&obj, &"to".into(), &mv.to.to_string().into());
+    let _ = Reflect::set(&obj, &"promotion".into(), &promotion);
+    obj.into()
+}
+
+/// Find the legal move matching a UCI string in `board`.
+fn find_uci_move(board: &mut Board, tables: &MagicTables, move_str: &str) -> Option<Move> {
+    let mv = Move::from_uci(move_str, board).ok()?;
+
+    let mut moves: Vec<Move> = Vec::with_capacity(256);
+    let mut scratch: Vec<Move> = Vec::with_capacity(256);
+    generate_legal(board, tables, &mut moves, &mut scratch);
+
+    moves.contains(&mv).then_some(mv)
 }
@@ -1,6 +1,7 @@
 use std::str::FromStr;
 use vantage::board::Board;
 use vantage::moves::magic::loader::load_magic_tables;
+use vantage::search::SearchResult;
 use vantage::search::search::search;
 use vantage::search::tt::TranspositionTable;
 
@@ -11,7 +12,9 @@ fn test_aspiration_finds_correct_move() {
     let tables = load_magic_tables();
 
     // Search depth 6 (triggers aspiration windows which start > depth 4)
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -31,7 +34,9 @@ fn test_aspiration_handles_score_drop() {
             .unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -51,7 +56,9 @@ fn test_aspiration_handles_score_jump() {
             .unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -72,7 +79,7 @@ fn test_aspiration_performance() {
     let tables = load_magic_tables();
 
     let start = Instant::now();
-    let (_score, best_move) = search(
+    let SearchResult { best_move, .. } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -0,0 +1,85 @@
+use std::str::FromStr;
+use vantage::board::Board;
+use vantage::book::polyglot_entry::PolyglotEntry;
+use vantage::book::{GameResult, LEARN_STEP, LearnableBook, PolyglotBook};
+use vantage::hash::polyglot::polyglot_hash;
+use vantage::moves::types::Move;
+
+/// A book holding 1.e4 (weight 100) and 1.d4 (weight 50) for the start position.
+fn start_book(board: &Board) -> PolyglotBook {
+    let key = polyglot_hash(board);
+    let data: Vec<u8> = [("e2e4", 100), ("d2d4", 50)]
+        .iter()
+        .flat_map(|&(uci, weight)| {
+            PolyglotEntry {
+                key,
+                move_poly: PolyglotEntry::encode_move(Move::from_uci(uci, board).unwrap()),
+                weight,
+                learn: 0,
+            }
+            .to_bytes()
+        })
+        .collect();
+    PolyglotBook::from_bytes(&data)
+}
+
+#[test]
+fn test_learn_win_increases_weight() {
+    let board = Board::new();
+    let mut book = start_book(&board);
+    let e4 = Move::from_uci("e2e4", &board).unwrap();
+    let d4 = Move::from_uci("d2d4", &board).unwrap();
+
+    book.learn(&board, GameResult::Win, e4);
+    assert_eq!(book.weight(&board, e4), Some(100 + LEARN_STEP));
+    assert_eq!(book.weight(&board, d4), Some(50));
+
+    book.learn(&board, GameResult::Draw, e4);
+    assert_eq!(book.weight(&board, e4), Some(100 + LEARN_STEP));
+
+    for _ in 0..20 {
+        book.learn(&board, GameResult::Loss, d4);
+    }
+    assert_eq!(book.weight(&board, d4), Some(1), "weight floors at 1");
+
+    // Moves outside the book are not learned
+    let nf3 = Move::from_uci("g1f3", &board).unwrap();
+    book.learn(&board, GameResult::Win, nf3);
+    assert_eq!(book.weight(&board, nf3), None);
+}
+
+#[test]
+fn test_learned_weights_round_trip_through_file() {
+    let board = Board::new();
+    let e4 = Move::from_uci("e2e4", &board).unwrap();
+    let mut book = start_book(&board);
+    book.learn(&board, GameResult::Win, e4);
+
+    let path = std::env::temp_dir().join(format!("vantage_{}.learn", std::process::id()));
+    let path = path.to_str().unwrap();
+    book.save_learned(path).unwrap();
+
+    let mut fresh = start_book(&board);
+    assert_eq!(fresh.weight(&board, e4), Some(100));
+    fresh.load_learned(path).unwrap();
+    assert_eq!(fresh.weight(&board, e4), Some(100 + LEARN_STEP));
+    std::fs::remove_file(path).unwrap();
+
+    // No learn file yet is fine
+    fresh.load_learned(format!("{}.missing", path)).unwrap();
+}
+
+#[test]
+fn test_encode_move_round_trips_castling_and_promotion() {
+    let board = Board::from_str("r3k2r/1P6/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+    for uci in ["e1g1", "e1c1", "b7b8n", "b7a8q", "a1a7"] {
+        let mv = Move::from_uci(uci, &board).unwrap();
+        let entry = PolyglotEntry {
+            key: 0,
+            move_poly: PolyglotEntry::encode_move(mv),
+            weight: 1,
+            learn: 0,
+        };
+        assert_eq!(entry.decode_move(&board), Some(mv), "{}", uci);
+    }
+}
@@ -1,7 +1,10 @@
+#![allow(clippy::manual_range_contains)]
+
 use std::str::FromStr;
 use vantage::board::Board;
 use vantage::moves::magic::loader::load_magic_tables;
-use vantage::search::eval::{eval_material, static_eval};
+use vantage::search::eval::{eval_material, static_eval, static_eval_with};
+use vantage::search::tuning::TuningParams;
 
 #[test]
 fn startpos_material_is_zero() {
@@ -201,3 +204,23 @@ fn mirror_vert_basic_checks() {
     assert_eq!(mirror_vert(h1), h8 as usize);
     assert_eq!(mirror_vert(h8), h1 as usize);
 }
+
+#[test]
+fn spsa_file_changes_eval() {
+    let tables = load_magic_tables();
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/tuned.spsa");
+    let params = TuningParams::from_spsa_file(path).unwrap();
+    assert_eq!(params.mobility_weight, 9);
+    assert_eq!(params.bishop_pair_bonus_mg, 56);
+    assert_eq!(params.passed_pawn_bonus[5], 120);
+
+    // White has the bishop pair and more mobility: both tuned terms favour White
+    let b = Board::from_str("4k3/pp3ppp/2n5/8/8/8/PPB2PPP/2B1K3 w - - 0 1").unwrap();
+    let default = static_eval(&b, &tables, -32000, 32000);
+    assert_eq!(
+        default,
+        static_eval_with(&b, &tables, -32000, 32000, &TuningParams::DEFAULT)
+    );
+    let tuned = static_eval_with(&b, &tables, -32000, 32000, &params);
+    assert!(tuned > default, "tuned {} <= default {}", tuned, default);
+}
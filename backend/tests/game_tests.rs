@@ -0,0 +1,96 @@
+use vantage::error::VantageError;
+use vantage::game::Game;
+use vantage::moves::types::Move;
+use vantage::status::GameStatus;
+
+fn find(game: &Game, uci: &str) -> Move {
+    game.legal_moves()
+        .into_iter()
+        .find(|m| m.to_uci() == uci)
+        .unwrap_or_else(|| panic!("{} is not legal in {}", uci, game.board().to_fen()))
+}
+
+#[test]
+fn test_scholars_mate() {
+    let mut game = Game::new();
+    assert_eq!(game.status(), GameStatus::InPlay);
+    assert_eq!(game.legal_moves().len(), 20);
+
+    for uci in ["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6"] {
+        let mv = find(&game, uci);
+        assert_eq!(game.push_move(mv), Ok(GameStatus::InPlay));
+    }
+
+    let mate = find(&game, "h5f7");
+    assert_eq!(game.push_move(mate), Ok(GameStatus::Checkmate));
+    assert_eq!(game.status(), GameStatus::Checkmate);
+    assert!(game.legal_moves().is_empty());
+}
+
+#[test]
+fn test_push_move_after_game_over() {
+    // Fool's mate: Black has just delivered mate
+    let mut game =
+        Game::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").unwrap();
+    assert_eq!(game.status(), GameStatus::Checkmate);
+
+    let any = Game::new().legal_moves()[0];
+    assert_eq!(
+        game.push_move(any),
+        Err(VantageError::GameOver(GameStatus::Checkmate))
+    );
+}
+
+#[test]
+fn test_push_illegal_move() {
+    let mut game = Game::new();
+    let mut mv = find(&game, "e2e4");
+    mv.to = "e5".parse().unwrap();
+
+    let before = game.board().to_fen();
+    assert!(matches!(
+        game.push_move(mv),
+        Err(VantageError::InvalidMove(_))
+    ));
+    assert_eq!(game.board().to_fen(), before);
+    assert_eq!(game.status(), GameStatus::InPlay);
+}
+
+#[test]
+fn test_push_uci() {
+    let mut game = Game::new();
+    let before = game.board().to_fen();
+    match game.push_uci("e2e5") {
+        Err(err @ VantageError::InvalidMove(_)) => {
+            assert_eq!(
+                err.to_string(),
+                format!("Invalid move: e2e5: not legal in position {}", before)
+            )
+        }
+        other => panic!("expected InvalidMove, got {:?}", other),
+    }
+    assert_eq!(game.board().to_fen(), before);
+    assert_eq!(game.status(), GameStatus::InPlay);
+
+    assert!(matches!(
+        game.push_uci("zz99"),
+        Err(VantageError::InvalidMove(_))
+    ));
+    assert_eq!(game.push_uci("e2e4"), Ok(GameStatus::InPlay));
+    assert_eq!(game.push_uci("e7e5"), Ok(GameStatus::InPlay));
+}
+
+#[test]
+fn test_from_fen_invalid() {
+    assert!(matches!(
+        Game::from_fen("not a fen"),
+        Err(VantageError::FenParseError(_))
+    ));
+}
+
+#[test]
+fn test_games_share_magic_tables() {
+    let a = Game::new();
+    let b = Game::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    assert!(std::ptr::eq(a.tables(), b.tables()));
+}
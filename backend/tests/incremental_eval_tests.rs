@@ -0,0 +1,50 @@
+//! Incremental PeSTO accumulator stays in sync with a full `pesto_eval`.
+#![cfg(feature = "incremental_eval")]
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::str::FromStr;
+use vantage::board::Board;
+use vantage::moves::execute::{generate_legal, make_move_basic, undo_move_basic};
+use vantage::moves::magic::loader::load_magic_tables;
+use vantage::search::eval::pesto_eval;
+
+#[test]
+fn startpos_accumulator_matches_pesto_eval() {
+    let board = Board::new();
+    assert_eq!(board.incremental_eval(), pesto_eval(&board));
+
+    let kiwipete =
+        Board::from_str("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+    assert_eq!(kiwipete.incremental_eval(), pesto_eval(&kiwipete));
+}
+
+#[test]
+fn accumulator_survives_random_make_undo() {
+    let tables = load_magic_tables();
+    let mut rng = StdRng::seed_from_u64(0x1579);
+    let mut board =
+        Board::from_str("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+    let mut moves = Vec::new();
+    let mut scratch = Vec::new();
+    let mut undos = Vec::new();
+
+    for _ in 0..100 {
+        // Walk a random line forward, then unwind it
+        for _ in 0..rng.random_range(1..12) {
+            generate_legal(&mut board, &tables, &mut moves, &mut scratch);
+            if moves.is_empty() {
+                break;
+            }
+            let mv = moves[rng.random_range(0..moves.len())];
+            undos.push(make_move_basic(&mut board, mv));
+            assert_eq!(board.incremental_eval(), pesto_eval(&board), "after {}", mv);
+        }
+        while let Some(undo) = undos.pop() {
+            undo_move_basic(&mut board, undo);
+            assert_eq!(board.incremental_eval(), pesto_eval(&board));
+        }
+    }
+}
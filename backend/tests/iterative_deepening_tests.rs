@@ -4,6 +4,7 @@
 use std::str::FromStr;
 use vantage::board::Board;
 use vantage::moves::magic::loader::load_magic_tables;
+use vantage::search::SearchResult;
 use vantage::search::context::SearchContext;
 use vantage::search::search::{TimeManager, alpha_beta, search}; // Added TimeManager
 use vantage::search::tt::TranspositionTable;
@@ -20,7 +21,9 @@ fn test_id_returns_move() {
         Board::from_str("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -52,7 +55,9 @@ fn test_id_matches_fixed_depth() {
     let mut ctx = SearchContext::new();
     let mut tt = TranspositionTable::new(512); // Match size used in search()
 
-    let (score_id, _move_id) = search(
+    let SearchResult {
+        score: score_id, ..
+    } = search(
         &mut board1,
         &tables,
         &mut TranspositionTable::new(512),
@@ -101,7 +106,9 @@ fn test_id_multiple_depths() {
 
     // Test depths 1 through 5
     for depth in 1..=5 {
-        let (score, best_move) = search(
+        let SearchResult {
+            score, best_move, ..
+        } = search(
             &mut board,
             &tables,
             &mut TranspositionTable::new(512),
@@ -131,7 +138,9 @@ fn test_id_finds_capture() {
         Board::from_str("rnb1kbnr/pppppppp/8/8/8/3q4/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -159,7 +168,9 @@ fn test_id_finds_mate_in_1() {
     let mut board = Board::from_str("6k1/5ppp/8/8/8/8/5PPP/3Q2K1 w - - 0 1").unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -251,7 +262,9 @@ fn test_id_limited_moves() {
     let mut board = Board::from_str("8/8/8/8/8/3k4/8/3K4 w - - 0 1").unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -283,14 +296,22 @@ fn test_id_deterministic() {
     let mut board2 = board1.clone();
     let tables = load_magic_tables();
 
-    let (score1, move1) = search(
+    let SearchResult {
+        score: score1,
+        best_move: move1,
+        ..
+    } = search(
         &mut board1,
         &tables,
         &mut TranspositionTable::new(512),
         4,
         None,
     );
-    let (score2, move2) = search(
+    let SearchResult {
+        score: score2,
+        best_move: move2,
+        ..
+    } = search(
         &mut board2,
         &tables,
         &mut TranspositionTable::new(512),
@@ -312,7 +333,9 @@ fn test_id_depth_1() {
         Board::from_str("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -341,7 +364,9 @@ fn test_id_complex_position() {
     let tables = load_magic_tables();
 
     // Should complete without crashing
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -368,14 +393,18 @@ fn test_id_score_stability() {
         Board::from_str("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1").unwrap();
     let tables = load_magic_tables();
 
-    let (score_d2, _) = search(
+    let SearchResult {
+        score: score_d2, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
         2,
         None,
     );
-    let (score_d4, _) = search(
+    let SearchResult {
+        score: score_d4, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -86,3 +86,41 @@ fn en_passant_is_illegal_when_pawn_is_pinned_opening_file_on_own_king() {
         "EP capture that exposes own king must be filtered out by the legality checker"
     );
 }
+
+#[test]
+fn try_move_accepts_every_startpos_move_and_leaves_board_alone() {
+    let t = tables();
+    let mut b = Board::new();
+    let mut legal = Vec::new();
+    let mut scratch = Vec::new();
+    generate_legal(&mut b, &t, &mut legal, &mut scratch);
+    assert_eq!(legal.len(), 20);
+    for &mv in &legal {
+        assert!(b.try_move(mv, &t), "{}", mv.to_uci());
+        assert!(b.is_legal_move_for(mv, &t), "{}", mv.to_uci());
+    }
+    assert_eq!(b, Board::new());
+}
+
+#[test]
+fn try_move_rejects_pinned_and_missing_pieces() {
+    let t = tables();
+    // Knight on e2 is pinned to the king by the rook on e8
+    let mut b = Board::from_str("4r1k1/8/8/8/8/8/4N3/4K3 w - - 0 1").unwrap();
+    let before = b.clone();
+    let pinned = Move::from_uci("e2c3", &b).unwrap();
+    assert!(!b.try_move(pinned, &t));
+    assert!(!b.is_legal_move_for(pinned, &t));
+    assert_eq!(b, before);
+
+    // No white bishop on c1 in this position
+    let mut ghost = Move::from_uci("e1d1", &b).unwrap();
+    ghost.from = Square::from_str("c1").unwrap();
+    ghost.to = Square::from_str("d2").unwrap();
+    ghost.piece = vantage::board::Piece::Bishop;
+    assert!(!b.try_move(ghost, &t));
+    assert!(!b.is_legal_move_for(ghost, &t));
+
+    let king_step = Move::from_uci("e1d1", &b).unwrap();
+    assert!(b.try_move(king_step, &t));
+}
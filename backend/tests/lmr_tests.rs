@@ -1,6 +1,7 @@
 use std::str::FromStr;
 use vantage::board::Board;
 use vantage::moves::magic::loader::load_magic_tables;
+use vantage::search::SearchResult;
 use vantage::search::search::search;
 use vantage::search::tt::TranspositionTable;
 use vantage::square::Square;
@@ -13,7 +14,11 @@ fn test_scholar_mate_position_analysis() {
             Board::from_str("r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 0 1")
                 .unwrap();
         let tables = load_magic_tables();
-        let (score, mv) = search(
+        let SearchResult {
+            score,
+            best_move: mv,
+            ..
+        } = search(
             &mut board,
             &tables,
             &mut TranspositionTable::new(512),
@@ -33,7 +38,9 @@ fn test_simple_capture_is_best() {
     let mut board = Board::from_str("6k1/8/8/2q5/3P4/8/8/6K1 w - - 0 1").unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -57,7 +64,11 @@ fn test_lmr_finds_tactical_move() {
     let tables = load_magic_tables();
 
     // LMR should NOT prune mate
-    let (best_score, best_move) = search(
+    let SearchResult {
+        score: best_score,
+        best_move,
+        ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -82,7 +93,7 @@ fn test_lmr_performance_improvement() {
     let tables = load_magic_tables();
 
     let start = Instant::now();
-    let (_score, _mv) = search(
+    let _ = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -104,7 +115,11 @@ fn test_lmr_research_accuracy() {
     let mut board = Board::from_str("8/8/8/4k3/8/3K4/4P3/8 w - - 0 1").unwrap();
     let tables = load_magic_tables();
 
-    let (best_score, best_move) = search(
+    let SearchResult {
+        score: best_score,
+        best_move,
+        ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
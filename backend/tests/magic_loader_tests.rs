@@ -0,0 +1,77 @@
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use vantage::board::{Color, Piece};
+use vantage::moves::magic::MagicTables;
+use vantage::moves::magic::loader::{
+    load_magic_tables, load_magic_tables_async, load_magic_tables_threaded,
+};
+use vantage::moves::square_control::attacks_from;
+
+/// Minimal executor: park the thread until the future wakes it.
+fn block_on<F: Future>(fut: F) -> F::Output {
+    struct ThreadWaker(Thread);
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = pin!(fut);
+    loop {
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(out) => return out,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+fn assert_tables_usable(tables: &MagicTables) {
+    // Knight attacks are static, so they must match whichever tables are passed
+    let d4 = 27;
+    let knight = attacks_from(Piece::Knight, Color::White, d4, 0, tables);
+    assert_eq!(knight, 0x0000_1422_0022_1400);
+
+    let reference = load_magic_tables();
+    for sq in 0..64 {
+        assert_eq!(
+            tables.rook.get_attacks(sq, 0),
+            reference.rook.get_attacks(sq, 0)
+        );
+        assert_eq!(
+            tables.bishop.get_attacks(sq, 0),
+            reference.bishop.get_attacks(sq, 0)
+        );
+    }
+}
+
+#[test]
+fn attack_cache_matches_empty_board_lookups() {
+    let tables = load_magic_tables();
+    let cache = tables.precompute_all_attacks();
+
+    for sq in 0..64 {
+        assert_eq!(cache.rook[sq], tables.rook.get_attacks(sq, 0));
+        assert_eq!(cache.bishop[sq], tables.bishop.get_attacks(sq, 0));
+        assert_eq!(cache.queen(sq), tables.queen_attacks(sq, 0));
+        assert_eq!(cache.rook[sq].count_ones(), 14);
+    }
+    // Corner bishop sees the whole long diagonal
+    assert_eq!(cache.bishop[0], 0x8040_2010_0804_0200);
+}
+
+#[test]
+fn threaded_loader_returns_usable_tables() {
+    let tables = load_magic_tables_threaded().join().unwrap();
+    assert_tables_usable(&tables);
+}
+
+#[test]
+fn async_loader_returns_usable_tables() {
+    let tables = block_on(load_magic_tables_async());
+    assert_tables_usable(&tables);
+}
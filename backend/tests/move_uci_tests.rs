@@ -0,0 +1,232 @@
+use std::str::FromStr;
+use vantage::board::{Board, Piece};
+use vantage::error::VantageError;
+use vantage::moves::execute::generate_legal;
+use vantage::moves::magic::loader::load_magic_tables;
+use vantage::moves::types::{
+    CAPTURE, DOUBLE_PAWN_PUSH, EN_PASSANT, KINGSIDE_CASTLE, Move, PROMOTION, PROMOTION_CAPTURE,
+    QUEENSIDE_CASTLE, QUIET_MOVE,
+};
+use vantage::square::Square;
+
+const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+fn legal_moves(board: &mut Board) -> Vec<Move> {
+    let tables = load_magic_tables();
+    let mut moves = Vec::new();
+    let mut scratch = Vec::new();
+    generate_legal(board, &tables, &mut moves, &mut scratch);
+    moves
+}
+
+fn assert_all_legal_moves_roundtrip(fen: &str) {
+    let mut board = Board::from_str(fen).unwrap();
+    for mv in legal_moves(&mut board) {
+        let uci = mv.to_uci();
+        let parsed = Move::from_uci(&uci, &board)
+            .unwrap_or_else(|e| panic!("{} failed to parse in {}: {}", uci, fen, e));
+        assert_eq!(parsed, mv, "{} parsed differently in {}", uci, fen);
+        assert_eq!(parsed.to_uci(), uci);
+    }
+}
+
+#[test]
+fn test_from_uci_startpos_roundtrip() {
+    let mut board = Board::from_str(START_FEN).unwrap();
+    assert_eq!(legal_moves(&mut board).len(), 20);
+    assert_all_legal_moves_roundtrip(START_FEN);
+}
+
+#[test]
+fn test_from_uci_special_moves_roundtrip() {
+    for fen in [
+        // Kiwipete: both castles, captures, double pushes
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        // Black to move with both castles available
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+        // En passant available: e5xd6
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 3",
+        // Promotions with and without capture
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N w - - 0 1",
+    ] {
+        assert_all_legal_moves_roundtrip(fen);
+    }
+}
+
+#[test]
+fn test_from_uci_infers_flags() {
+    let board =
+        Board::from_str("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+    assert_eq!(
+        Move::from_uci("e1g1", &board).unwrap().flags,
+        KINGSIDE_CASTLE
+    );
+    assert_eq!(
+        Move::from_uci("e1c1", &board).unwrap().flags,
+        QUEENSIDE_CASTLE
+    );
+
+    let board =
+        Board::from_str("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 3").unwrap();
+    assert_eq!(Move::from_uci("e5d6", &board).unwrap().flags, EN_PASSANT);
+
+    let board = Board::from_str("n1n5/PPPk4/8/8/8/8/4Kppp/5N1N w - - 0 1").unwrap();
+    let mv = Move::from_uci("b7a8n", &board).unwrap();
+    assert_eq!(mv.flags, PROMOTION_CAPTURE);
+    assert!(mv.is_valid_promotion());
+}
+
+#[test]
+fn test_from_uci_rejects_bad_input() {
+    let board = Board::from_str(START_FEN).unwrap();
+    for bad in [
+        "", "e2", "e2e", "e2e4qq", "i2i4", "e9e4", "e2e2",  // malformed
+        "e4e5",  // empty from square
+        "e7e5",  // opponent's piece
+        "e1e2",  // friendly piece on destination
+        "e2e4q", // promotion off the last rank
+    ] {
+        assert!(
+            matches!(
+                Move::from_uci(bad, &board),
+                Err(VantageError::InvalidMove(_))
+            ),
+            "{:?} should be rejected",
+            bad
+        );
+    }
+
+    let board = Board::from_str("n1n5/PPPk4/8/8/8/8/4Kppp/5N1N w - - 0 1").unwrap();
+    assert!(
+        Move::from_uci("b7b8", &board).is_err(),
+        "missing promotion piece"
+    );
+    assert!(
+        Move::from_uci("b7b8k", &board).is_err(),
+        "king is not a promotion piece"
+    );
+    assert!(
+        Move::from_uci("b7b8p", &board).is_err(),
+        "pawn is not a promotion piece"
+    );
+}
+
+#[test]
+fn test_decode_move_detects_every_flag() {
+    let sq = |s: &str| Square::from_str(s).unwrap();
+    // (fen, from, to, promotion, expected flags)
+    let cases = [
+        (START_FEN, "g1", "f3", None, QUIET_MOVE),
+        (START_FEN, "e2", "e4", None, DOUBLE_PAWN_PUSH),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "e1",
+            "g1",
+            None,
+            KINGSIDE_CASTLE,
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+            "e8",
+            "c8",
+            None,
+            QUEENSIDE_CASTLE,
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "e5",
+            "f7",
+            None,
+            CAPTURE,
+        ),
+        (
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 3",
+            "e5",
+            "d6",
+            None,
+            EN_PASSANT,
+        ),
+        (
+            "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N w - - 0 1",
+            "b7",
+            "b8",
+            Some(Piece::Queen),
+            PROMOTION,
+        ),
+        (
+            "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+            "g2",
+            "h1",
+            Some(Piece::Knight),
+            PROMOTION_CAPTURE,
+        ),
+    ];
+
+    for (fen, from, to, promotion, flags) in cases {
+        let board = Board::from_str(fen).unwrap();
+        let mv = board.decode_move(sq(from), sq(to), promotion).unwrap();
+        assert_eq!(mv.flags, flags, "{}{} in {}", from, to, fen);
+        assert_eq!(mv, Move::from_uci(&mv.to_uci(), &board).unwrap());
+    }
+
+    let board = Board::from_str(START_FEN).unwrap();
+    for (from, to, promotion) in [
+        ("e4", "e5", None),               // empty from square
+        ("e7", "e5", None),               // opponent's piece
+        ("d1", "d2", None),               // friendly piece on destination
+        ("e2", "e2", None),               // no movement
+        ("e2", "e4", Some(Piece::Queen)), // promotion off the last rank
+    ] {
+        assert!(
+            matches!(
+                board.decode_move(sq(from), sq(to), promotion),
+                Err(VantageError::InvalidMove(_))
+            ),
+            "{}{} should be rejected",
+            from,
+            to
+        );
+    }
+    let board = Board::from_str("n1n5/PPPk4/8/8/8/8/4Kppp/5N1N w - - 0 1").unwrap();
+    assert!(board.decode_move(sq("b7"), sq("b8"), None).is_err());
+    assert!(
+        board
+            .decode_move(sq("b7"), sq("b8"), Some(Piece::King))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_move_class_predicates_cover_all_flags() {
+    // (flags, is_quiet, is_noisy, is_tactical)
+    let cases = [
+        (QUIET_MOVE, true, false, false),
+        (DOUBLE_PAWN_PUSH, true, false, false),
+        (KINGSIDE_CASTLE, true, false, false),
+        (QUEENSIDE_CASTLE, true, false, false),
+        (CAPTURE, false, true, true),
+        (EN_PASSANT, false, true, true),
+        (PROMOTION, false, true, true),
+        (PROMOTION_CAPTURE, false, true, true),
+    ];
+    for (flags, quiet, noisy, tactical) in cases {
+        let mv = Move {
+            from: Square::from_index(12),
+            to: Square::from_index(28),
+            piece: Piece::Pawn,
+            promotion: None,
+            flags,
+        };
+        assert_eq!(mv.is_quiet(), quiet, "is_quiet for flags {:#06b}", flags);
+        assert_eq!(mv.is_noisy(), noisy, "is_noisy for flags {:#06b}", flags);
+        assert_eq!(
+            mv.is_tactical(),
+            tactical,
+            "is_tactical for flags {:#06b}",
+            flags
+        );
+        assert_eq!(mv.is_quiet(), !mv.is_noisy());
+    }
+}
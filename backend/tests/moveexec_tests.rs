@@ -781,3 +781,52 @@ fn promotion_castling_rights_effects() {
     undo_move_basic(&mut board2, u2);
     assert!(board2.has_queenside_castle(Color::Black));
 }
+
+#[test]
+fn undo_stack_roundtrip_100_plies() {
+    use vantage::moves::execute::{UndoStack, generate_legal};
+    use vantage::moves::magic::loader::load_magic_tables;
+
+    let tables = load_magic_tables();
+    let mut board = Board::new();
+    let original = board.clone();
+    let mut stack = UndoStack::new();
+    let mut moves = Vec::new();
+    let mut scratch = Vec::new();
+
+    for ply in 0..100 {
+        generate_legal(&mut board, &tables, &mut moves, &mut scratch);
+        assert!(!moves.is_empty(), "game ended at ply {}", ply);
+        let mv = moves[(ply * 7) % moves.len()];
+        assert!(stack.make(&mut board, &tables, mv));
+    }
+    assert_eq!(stack.len(), 100);
+
+    while stack.undo(&mut board) {}
+    assert!(stack.is_empty());
+    assert!(stack.peek().is_none());
+    assert_eq!(board, original);
+}
+
+#[test]
+fn undo_stack_rejects_illegal_move() {
+    use vantage::moves::execute::UndoStack;
+    use vantage::moves::magic::loader::load_magic_tables;
+
+    let tables = load_magic_tables();
+    // The bishop on e2 is pinned to the e1 king by the rook on e8
+    let mut board = Board::from_str("4r1k1/8/8/8/8/8/4B3/4K3 w - - 0 1").unwrap();
+    let before = board.clone();
+    let mut stack = UndoStack::new();
+
+    let pinned = Move {
+        from: Square::from_index(12), // e2
+        to: Square::from_index(21),   // f3
+        piece: Piece::Bishop,
+        promotion: None,
+        flags: QUIET_MOVE,
+    };
+    assert!(!stack.make(&mut board, &tables, pinned));
+    assert!(stack.is_empty());
+    assert_eq!(board, before);
+}
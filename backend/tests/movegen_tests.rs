@@ -14,18 +14,18 @@ use vantage::square::Square;
 /// Set the bitboard for (color, piece) to exactly `mask`, then recompute occupancies.
 fn set_piece_mask(board: &mut Board, color: Color, piece: Piece, mask: u64) {
     // 1) Overwrite that piece’s bitboard with the given mask:
-    board.piece_bb[color as usize][piece as usize] = mask;
+    board.piece_bb[color.index()][piece.index()] = mask;
 
     // 2) Recompute White’s occupancy by OR‐ing together all White piece bitboards:
     let mut white_occ = 0u64;
-    for &wbb in &board.piece_bb[Color::White as usize] {
+    for &wbb in &board.piece_bb[Color::White.index()] {
         white_occ |= wbb;
     }
     board.occ_white = white_occ;
 
     // 3) Recompute Black’s occupancy in the same way:
     let mut black_occ = 0u64;
-    for &bbb in &board.piece_bb[Color::Black as usize] {
+    for &bbb in &board.piece_bb[Color::Black.index()] {
         black_occ |= bbb;
     }
     board.occ_black = black_occ;
@@ -1115,3 +1115,140 @@ fn black_queenside_castle_forbidden_if_d8_or_c8_attacked() {
         "Black cannot castle through check when d8 or c8 is attacked"
     );
 }
+
+/// Walk the tree and, at every node where the side to move is in check,
+/// compare the legal subset of the evasion generator against `generate_legal`.
+fn check_evasions(
+    board: &mut Board,
+    tables: &vantage::moves::magic::MagicTables,
+    depth: u32,
+    checked: &mut u32,
+) {
+    use vantage::moves::execute::{is_legal_move, make_move_basic, undo_move_basic};
+    use vantage::moves::movegen::generate_pseudo_legal_evasions;
+    use vantage::moves::square_control::checkers;
+
+    let mut legal = Vec::new();
+    let mut scratch = Vec::new();
+    generate_legal(board, tables, &mut legal, &mut scratch);
+
+    let checker_bb = checkers(board, board.side_to_move, tables);
+    if checker_bb != 0 {
+        *checked += 1;
+        let mut evasions: Vec<Move> = Vec::new();
+        generate_pseudo_legal_evasions(board, tables, checker_bb, &mut evasions);
+        evasions.retain(|&mv| is_legal_move(board, mv, tables));
+
+        let key = |m: &Move| m.to_uci();
+        let mut a: Vec<String> = legal.iter().map(key).collect();
+        let mut b: Vec<String> = evasions.iter().map(key).collect();
+        a.sort();
+        b.sort();
+        assert_eq!(a, b, "evasions differ in {}", board.to_fen());
+    }
+
+    if depth == 0 {
+        return;
+    }
+    for mv in legal {
+        let undo = make_move_basic(board, mv);
+        check_evasions(board, tables, depth - 1, checked);
+        undo_move_basic(board, undo);
+    }
+}
+
+#[test]
+fn evasions_match_legal_moves_in_check() {
+    let tables = load_magic_tables();
+    let fens = [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    ];
+    let mut checked = 0;
+    for fen in fens {
+        let mut board = Board::from_str(fen).unwrap();
+        check_evasions(&mut board, &tables, 3, &mut checked);
+    }
+    assert!(checked > 100, "only {checked} in-check nodes visited");
+}
+
+#[test]
+fn checks_only_knight_check() {
+    use vantage::moves::movegen::generate_pseudo_legal_checks;
+
+    let tables = load_magic_tables();
+    // Only Na8-c7 checks; the bishop and pawn can't reach a checking square
+    let board = Board::from_str("N3k3/8/8/8/8/8/7P/6BK w - - 0 1").unwrap();
+    let mut moves: Vec<Move> = Vec::new();
+    generate_pseudo_legal_checks(&board, &tables, &mut moves);
+    let ucis: Vec<String> = moves.iter().map(|m| m.to_uci()).collect();
+    assert_eq!(ucis, ["a8c7"]);
+}
+
+/// Walk the tree and compare the check generator against pseudo-legal moves
+/// whose moved piece attacks the enemy king afterwards.
+fn check_direct_checks(
+    board: &mut Board,
+    tables: &vantage::moves::magic::MagicTables,
+    depth: u32,
+    found: &mut usize,
+) {
+    use vantage::moves::execute::{make_move_basic, undo_move_basic};
+    use vantage::moves::movegen::{generate_pseudo_legal, generate_pseudo_legal_checks};
+    use vantage::moves::square_control::attackers_to;
+
+    let mover = board.side_to_move;
+    let mut all: Vec<Move> = Vec::new();
+    generate_pseudo_legal(board, tables, &mut all);
+
+    let mut expected: Vec<String> = Vec::new();
+    for &mv in &all {
+        if mv.piece == Piece::King {
+            continue;
+        }
+        let undo = make_move_basic(board, mv);
+        let king = board.king_square(mover.opposite()).index();
+        let attackers = attackers_to(board, king, mover, board.occupied(), tables);
+        if attackers & (1u64 << mv.to.index()) != 0 {
+            expected.push(mv.to_uci());
+        }
+        undo_move_basic(board, undo);
+    }
+
+    let mut checks: Vec<Move> = Vec::new();
+    generate_pseudo_legal_checks(board, tables, &mut checks);
+    let mut got: Vec<String> = checks.iter().map(|m| m.to_uci()).collect();
+    got.sort();
+    expected.sort();
+    assert_eq!(got, expected, "checks differ in {}", board.to_fen());
+    *found += got.len();
+
+    if depth == 0 {
+        return;
+    }
+    let mut legal = Vec::new();
+    let mut scratch = Vec::new();
+    generate_legal(board, tables, &mut legal, &mut scratch);
+    for mv in legal {
+        let undo = make_move_basic(board, mv);
+        check_direct_checks(board, tables, depth - 1, found);
+        undo_move_basic(board, undo);
+    }
+}
+
+#[test]
+fn checks_match_filtered_pseudo_legal_moves() {
+    let tables = load_magic_tables();
+    let fens = [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    ];
+    let mut found = 0;
+    for fen in fens {
+        let mut board = Board::from_str(fen).unwrap();
+        check_direct_checks(&mut board, &tables, 2, &mut found);
+    }
+    assert!(found > 100, "only {found} checking moves seen");
+}
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 use vantage::board::{Board, Color};
+use vantage::moves::magic::loader::load_magic_tables;
 
 #[test]
 fn test_has_major_pieces_logic() {
@@ -41,3 +42,64 @@ fn test_has_major_pieces_logic() {
         "Rook is a major piece"
     );
 }
+
+#[test]
+fn test_null_move_roundtrip_restores_board() {
+    let tables = load_magic_tables();
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        // Hashed en passant square (d5 pawn can take on e6)
+        "rnbqkbnr/pppp1ppp/8/3Pp3/8/8/PPP1PPPP/RNBQKBNR w KQkq e6 0 3",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 7 12",
+    ] {
+        let original = Board::from_str(fen).unwrap();
+        let mut board = original.clone();
+
+        let undo = board.apply_null_move(&tables);
+        assert_eq!(board.side_to_move, !original.side_to_move);
+        assert_eq!(board.en_passant, None);
+        assert_eq!(board.zobrist, board.compute_zobrist_full());
+        assert_eq!(undo.prev_en_passant, original.en_passant);
+        assert_eq!(undo.prev_zobrist, original.zobrist);
+
+        board.undo_null_move(undo);
+        assert_eq!(board, original, "{}", fen);
+        assert_eq!(board.zobrist, original.zobrist);
+    }
+}
+
+#[test]
+fn test_can_null_move() {
+    let tables = load_magic_tables();
+    assert!(Board::new().can_null_move(&tables));
+
+    let in_check = Board::from_str("4k3/8/8/8/8/8/4r3/R3K3 w - - 0 1").unwrap();
+    assert!(!in_check.can_null_move(&tables));
+
+    let pawns_only = Board::from_str("4k3/pp6/8/8/8/8/6PP/4K3 w - - 0 1").unwrap();
+    assert!(!pawns_only.can_null_move(&tables));
+
+    let fifty = Board::from_str("r3k3/8/8/8/8/8/8/R2QK3 w - - 99 80").unwrap();
+    assert!(!fifty.can_null_move(&tables));
+    let fifty_minus_one = Board::from_str("r3k3/8/8/8/8/8/8/R2QK3 w - - 98 80").unwrap();
+    assert!(fifty_minus_one.can_null_move(&tables));
+}
+
+#[test]
+fn test_is_zugzwang_candidate() {
+    assert!(!Board::new().is_zugzwang_candidate());
+
+    let king_and_pawn = Board::from_str("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap();
+    assert!(king_and_pawn.is_zugzwang_candidate());
+
+    // Black to move has only pawns even though White has pieces
+    let pawns_to_move = Board::from_str("4k3/pp6/8/8/8/8/8/RN2K3 b - - 0 1").unwrap();
+    assert!(pawns_to_move.is_zugzwang_candidate());
+
+    // Rook endgame: two pieces in total
+    let rooks = Board::from_str("r3k3/pp6/8/8/8/8/PP6/R3K3 w - - 0 1").unwrap();
+    assert!(rooks.is_zugzwang_candidate());
+
+    let three_pieces = Board::from_str("r3k3/pp6/8/8/8/8/PP6/R2NK3 w - - 0 1").unwrap();
+    assert!(!three_pieces.is_zugzwang_candidate());
+}
@@ -2,7 +2,7 @@ use std::str::FromStr;
 use vantage::board::{Board, Piece};
 use vantage::moves::magic::loader::load_magic_tables;
 use vantage::moves::types::{CAPTURE, KINGSIDE_CASTLE, Move, PROMOTION_CAPTURE, QUIET_MOVE};
-use vantage::search::ordering::order_moves;
+use vantage::search::ordering::{mvv_lva_fast, mvv_lva_table, order_moves};
 use vantage::square::Square;
 
 fn make_move(from: &str, to: &str, flags: u8, promo: Option<Piece>, piece: Piece) -> Move {
@@ -274,3 +274,100 @@ fn test_hash_vs_promo() {
 
     assert_eq!(moves[0], mv_hash, "PV/Hash move must override Promotions");
 }
+
+#[test]
+fn test_score_move_orders_all_moves() {
+    use vantage::moves::execute::generate_legal;
+    use vantage::search::ordering::{score_move, sort_moves};
+
+    let tables = load_magic_tables();
+    // e4xd5 wins the queen with a pawn; Rd1xd5 also wins it, but with a rook.
+    // e4xf5 wins a knight; Qa4xa7 loses the queen to the rook on a8.
+    let mut b = Board::from_str("r5k1/p7/8/3q1n2/Q3P3/8/8/3R2K1 w - - 0 1").unwrap();
+    let mut moves = Vec::new();
+    let mut scratch = Vec::new();
+    generate_legal(&mut b, &tables, &mut moves, &mut scratch);
+
+    let history = [[0; 64]; 64];
+    let killers = [None, None];
+    sort_moves(&mut moves, &b, &history, &killers, None, &tables);
+
+    assert_eq!(moves[0].to_uci(), "e4d5");
+    assert_eq!(moves[1].to_uci(), "d1d5");
+    assert_eq!(moves[2].to_uci(), "e4f5");
+    assert_eq!(moves.last().unwrap().to_uci(), "a4a7");
+
+    let scores: Vec<i32> = moves
+        .iter()
+        .map(|&m| score_move(m, &b, &history, &killers, None, &tables))
+        .collect();
+    assert!(scores.windows(2).all(|w| w[0] >= w[1]));
+}
+
+#[test]
+fn test_score_move_priorities() {
+    use vantage::search::ordering::score_move;
+
+    let tables = load_magic_tables();
+    let b = Board::from_str("q6k/P7/8/3p4/4P3/8/7P/R3K2R w KQ - 0 1").unwrap();
+    let capture = make_move("e4", "d5", CAPTURE, None, Piece::Pawn);
+    let quiet = make_move("h2", "h3", QUIET_MOVE, None, Piece::Pawn);
+    let killer = make_move("e1", "g1", KINGSIDE_CASTLE, None, Piece::King);
+
+    let mut history = [[0; 64]; 64];
+    history[Square::from_str("h2").unwrap().index() as usize]
+        [Square::from_str("h3").unwrap().index() as usize] = 1_000_000;
+    let killers = [Some(killer), None];
+
+    let score = |mv, tt| score_move(mv, &b, &history, &killers, tt, &tables);
+
+    // The TT move beats everything, even a winning capture
+    assert!(score(quiet, Some(quiet)) > score(capture, Some(quiet)));
+    assert!(score(capture, None) > score(killer, None));
+    // History never lifts a quiet move above a killer
+    assert!(score(killer, None) > score(quiet, None));
+}
+
+#[test]
+fn test_mvv_lva_table() {
+    const PIECES: [Piece; 6] = [
+        Piece::Pawn,
+        Piece::Knight,
+        Piece::Bishop,
+        Piece::Rook,
+        Piece::Queen,
+        Piece::King,
+    ];
+    // Kings are never captured, so only the other five are real victims
+    let victims = &PIECES[..5];
+
+    let scores: Vec<i32> = victims
+        .iter()
+        .flat_map(|&v| PIECES.iter().map(move |&a| mvv_lva_fast(v, a)))
+        .collect();
+    assert_eq!(
+        mvv_lva_fast(Piece::Queen, Piece::Pawn),
+        *scores.iter().max().unwrap()
+    );
+    // King takes pawn is the worst capture of a real victim, and queen
+    // takes pawn the worst one by any other piece
+    assert_eq!(
+        mvv_lva_fast(Piece::Pawn, Piece::King),
+        *scores.iter().min().unwrap()
+    );
+    assert_eq!(mvv_lva_fast(Piece::Pawn, Piece::King), 994);
+    assert_eq!(mvv_lva_fast(Piece::Pawn, Piece::Queen), 995);
+    assert!(mvv_lva_fast(Piece::Pawn, Piece::Queen) < mvv_lva_fast(Piece::Pawn, Piece::Rook));
+
+    let table = mvv_lva_table();
+    for (v, &victim) in PIECES.iter().enumerate() {
+        for (a, &attacker) in PIECES.iter().enumerate() {
+            assert_eq!(table[v][a], victim.value() * 10 - attacker.attacker_value());
+            assert_eq!(table[v][a], mvv_lva_fast(victim, attacker));
+        }
+    }
+    // Any capture of a more valuable victim outranks every capture of a cheaper one
+    for pair in victims.windows(2) {
+        assert!(mvv_lva_fast(pair[1], Piece::King) > mvv_lva_fast(pair[0], Piece::Pawn));
+    }
+}
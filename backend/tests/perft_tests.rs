@@ -1,12 +1,20 @@
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
     use vantage::board::Board;
     use vantage::logger::init_logging;
-    use vantage::moves::perft::{perft, perft_divide, perft_divide_with_breakdown};
+    use vantage::moves::perft::{
+        PerftDiscrepancy, perft, perft_debug, perft_debug_tree, perft_divide, perft_divide_counts,
+        perft_divide_with_breakdown,
+    };
     use vantage::moves::{
-        execute::{generate_legal, make_move_basic, undo_move_basic},
-        magic::loader::load_magic_tables,
+        execute::{
+            generate_legal, generate_legal_make_undo, generate_legal_optimized, make_move_basic,
+            undo_move_basic,
+        },
+        magic::{MagicTables, loader::load_magic_tables},
         square_control::in_check,
+        types::Move,
     };
 
     const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
@@ -79,6 +87,18 @@ mod tests {
         let _ = run_startpos_depth(5, 4_865_609);
     }
 
+    #[test]
+    fn perft_divide_counts_kiwipete_d2() {
+        use std::str::FromStr;
+        let tables = load_magic_tables();
+        let mut board = Board::from_str(KIWI_FEN).unwrap();
+        let counts = perft_divide_counts(&mut board, &tables, 2);
+
+        assert_eq!(counts.len(), 48);
+        assert_eq!(counts.iter().map(|(_, n)| n).sum::<u64>(), 2_039);
+        assert_eq!(board.to_fen(), KIWI_FEN);
+    }
+
     // Deep nodes — opt-in on CI
     #[test]
     #[ignore]
@@ -274,6 +294,115 @@ mod tests {
         let total: u64 = rows.iter().map(|(_, pc)| pc.nodes).sum();
         assert_eq!(total, 400);
     }
+
+    fn divide_map(board: &Board, depth: u32) -> BTreeMap<String, u64> {
+        let tables = load_magic_tables();
+        let mut b = board.clone();
+        perft_divide_counts(&mut b, &tables, depth)
+            .into_iter()
+            .map(|(mv, n)| (mv.to_uci(), n))
+            .collect()
+    }
+
+    #[test]
+    fn perft_debug_reports_only_diverging_moves() {
+        let tables = load_magic_tables();
+        let mut b = Board::new();
+
+        let mut expected = divide_map(&b, 2);
+        assert!(perft_debug(&mut b, &tables, 2, &expected).is_empty());
+
+        *expected.get_mut("e2e4").unwrap() += 1;
+        expected.remove("a2a3");
+        expected.insert("e1e2".to_string(), 7);
+
+        let diffs = perft_debug(&mut b, &tables, 2, &expected);
+        let as_tuples: Vec<(&str, u64, u64)> = diffs
+            .iter()
+            .map(|d| (d.move_uci.as_str(), d.expected, d.got))
+            .collect();
+        assert_eq!(
+            as_tuples,
+            [("a2a3", 0, 20), ("e1e2", 7, 0), ("e2e4", 21, 20)]
+        );
+        assert_eq!(b.to_fen(), START_FEN);
+    }
+
+    #[test]
+    fn perft_debug_tree_finds_missing_move() {
+        const AFTER_E4: &str = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1";
+        let tables = load_magic_tables();
+        let mut b = Board::new();
+
+        // A reference that knows one extra black reply after 1. e4
+        let mut reference = |board: &Board, depth: u32| {
+            let mut map = divide_map(board, depth);
+            let fen = board.to_fen();
+            if fen == START_FEN && depth == 2 {
+                *map.get_mut("e2e4").unwrap() += 1;
+            } else if fen == AFTER_E4 && depth == 1 {
+                map.insert("e8e7".to_string(), 1);
+            }
+            map
+        };
+
+        let result = perft_debug_tree(&mut b, &tables, 2, &mut reference);
+        assert_eq!(result.discrepancies.len(), 1);
+        assert_eq!(result.discrepancies[0].move_uci, "e2e4");
+        assert_eq!(result.children.len(), 1);
+
+        let (uci, child) = &result.children[0];
+        assert_eq!(uci, "e2e4");
+        assert_eq!(child.fen, AFTER_E4);
+        assert_eq!(
+            child.discrepancies,
+            [PerftDiscrepancy {
+                move_uci: "e8e7".to_string(),
+                expected: 1,
+                got: 0,
+            }]
+        );
+        assert!(child.children.is_empty());
+    }
+
+    /// Walk the tree below `board`, checking both legal generators agree at
+    /// every node.
+    fn assert_generators_agree(board: &mut Board, tables: &MagicTables, depth: u32) {
+        let (mut fast, mut slow, mut scratch) = (Vec::new(), Vec::new(), Vec::new());
+        generate_legal_optimized(board, tables, &mut fast, &mut scratch);
+        generate_legal_make_undo(board, tables, &mut slow, &mut scratch);
+
+        let sorted = |moves: &[Move]| {
+            let mut uci: Vec<String> = moves.iter().map(|m| m.to_uci()).collect();
+            uci.sort();
+            uci
+        };
+        assert_eq!(sorted(&fast), sorted(&slow), "{}", board.to_fen());
+
+        if depth > 1 {
+            for mv in slow {
+                let undo = make_move_basic(board, mv);
+                assert_generators_agree(board, tables, depth - 1);
+                undo_move_basic(board, undo);
+            }
+        }
+    }
+
+    #[test]
+    fn optimized_legal_gen_matches_make_undo() {
+        let tables = load_magic_tables();
+        let positions = [
+            START_FEN,
+            KIWI_FEN,
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        ];
+        for fen in positions.into_iter().chain(FENS.iter().copied()) {
+            let mut board: Board = fen.parse().unwrap();
+            assert_generators_agree(&mut board, &tables, 3);
+        }
+    }
 }
 
 #[cfg(debug_assertions)]
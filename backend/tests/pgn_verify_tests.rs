@@ -0,0 +1,137 @@
+use std::str::FromStr;
+use vantage::board::{Board, Piece};
+use vantage::error::VantageError;
+use vantage::game::{Game, Nag};
+use vantage::moves::magic::loader::load_magic_tables;
+use vantage::moves::types::Move;
+use vantage::utils::pgn_verify::{parse_annotated_pgn, verify_pgn, verify_pgn_file};
+
+const TEN_GAMES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/ten_games.pgn");
+
+#[test]
+fn ten_known_good_games_have_no_errors() {
+    let tables = load_magic_tables();
+    let errors = verify_pgn_file(TEN_GAMES, &tables);
+    assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+}
+
+#[test]
+fn bad_move_is_reported_with_game_and_move_number() {
+    let tables = load_magic_tables();
+    let pgn = "[Event \"a\"]\n\n1. e4 e5 2. Nf3 *\n\n[Event \"b\"]\n\n1. d4 d5 2. Ke3 Nf6 *\n";
+    let errors = verify_pgn(pgn, &tables);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].game_index, 1);
+    assert_eq!(errors[0].move_number, 2);
+    assert_eq!(errors[0].san, "Ke3");
+    assert!(matches!(errors[0].error, VantageError::InvalidMove(_)));
+}
+
+#[test]
+fn moves_after_mate_are_reported_as_game_over() {
+    let tables = load_magic_tables();
+    let errors = verify_pgn("1. f3 e5 2. g4 Qh4# 3. a3 *", &tables);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].san, "a3");
+    assert!(matches!(errors[0].error, VantageError::GameOver(_)));
+}
+
+#[test]
+fn missing_file_is_an_io_error() {
+    let tables = load_magic_tables();
+    let errors = verify_pgn_file("does/not/exist.pgn", &tables);
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0].error, VantageError::Io(_)));
+}
+
+#[test]
+fn san_requires_disambiguation() {
+    let tables = load_magic_tables();
+    let board = Board::from_str("4k3/8/8/8/8/8/K7/R6R w - - 0 1").unwrap();
+
+    assert!(Move::from_san("Rd1", &board, &tables).is_err());
+    let mv = Move::from_san("Rhd1", &board, &tables).unwrap();
+    assert_eq!(mv.to_uci(), "h1d1");
+}
+
+#[test]
+fn san_promotion_variants() {
+    let tables = load_magic_tables();
+    let board = Board::from_str("8/1P6/8/8/8/8/6pk/K7 w - - 0 1").unwrap();
+
+    for san in ["b8=Q", "b8Q", "b8(Q)", "b8=Q+"] {
+        let mv = Move::from_san(san, &board, &tables).unwrap();
+        assert_eq!(mv.promotion, Some(Piece::Queen), "{}", san);
+    }
+    assert_eq!(
+        Move::from_san("b8=N", &board, &tables).unwrap().promotion,
+        Some(Piece::Knight)
+    );
+    assert!(Move::from_san("b8", &board, &tables).is_err());
+}
+
+#[test]
+fn annotations_attach_to_the_right_move() {
+    let tables = load_magic_tables();
+    let fen = "r1bqk2r/pppp1ppp/2n2n2/2b1p1N1/2B1P3/8/PPPP1PPP/RNBQK2R w KQkq - 0 14";
+    let pgn = format!(
+        "[FEN \"{}\"]\n\n14. Nxf7!! {{ Stunning sacrifice }} Qe7 ?! ; line note\n15. Nxh8 $1 $146 (15. Nd6+ Kd8) *\n",
+        fen
+    );
+    let games = parse_annotated_pgn(&pgn, &tables).unwrap();
+    assert_eq!(games.len(), 1);
+    let moves = &games[0];
+    assert_eq!(moves.len(), 3);
+
+    assert_eq!(moves[0].mv.to_uci(), "g5f7");
+    assert_eq!(moves[0].nags, vec![Nag::BrilliantMove]);
+    assert_eq!(moves[0].comment.as_deref(), Some("Stunning sacrifice"));
+
+    assert_eq!(moves[1].nags, vec![Nag::Dubious]);
+    assert_eq!(moves[1].comment.as_deref(), Some("line note"));
+
+    assert_eq!(moves[2].nags, vec![Nag::BestMove, Nag::Other(146)]);
+    assert_eq!(moves[2].comment, None);
+
+    // And back out again
+    let game = Game::from_fen(fen).unwrap();
+    assert_eq!(
+        moves[0].to_pgn_fragment(game.board(), &game.legal_moves()),
+        "Nxf7!! { Stunning sacrifice }"
+    );
+}
+
+#[test]
+fn to_san_roundtrips_through_from_san() {
+    let tables = load_magic_tables();
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        // Three queens reaching b3: needs file, rank and full-square disambiguation
+        "7k/8/8/8/Q1Q5/8/Q7/4K3 w - - 0 1",
+        "4k3/8/8/8/8/8/K7/R6R w - - 0 1",
+    ] {
+        let game = Game::from_fen(fen).unwrap();
+        let legal = game.legal_moves();
+        for &mv in &legal {
+            let san = mv.to_san(game.board(), &legal);
+            let parsed = Move::from_san(&san, game.board(), &tables)
+                .unwrap_or_else(|e| panic!("{} ({}) in {}: {}", san, mv, fen, e));
+            assert_eq!(parsed, mv, "{} in {}", san, fen);
+        }
+    }
+
+    let game = Game::from_fen("7k/8/8/8/Q1Q5/8/Q7/4K3 w - - 0 1").unwrap();
+    let legal = game.legal_moves();
+    let san = |uci: &str| {
+        let mv = legal.iter().find(|m| m.to_uci() == uci).unwrap();
+        mv.to_san(game.board(), &legal)
+    };
+    assert_eq!(san("a4b3"), "Qa4b3");
+    assert_eq!(san("c4b3"), "Qcb3");
+    assert_eq!(san("a2b3"), "Q2b3");
+}
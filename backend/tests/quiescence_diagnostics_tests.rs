@@ -340,3 +340,37 @@ fn diagnostic_quiescence_includes_promotions() {
         "Quiescence search MUST generate promotions, even if they aren't captures!"
     );
 }
+
+fn qsearch_nodes(fen: &str) -> (i32, u64) {
+    use vantage::search::search::quiescence;
+
+    let mut board = Board::from_str(fen).unwrap();
+    let tables = load_magic_tables();
+    let mut ctx = SearchContext::new();
+    let mut tt = TranspositionTable::new(1);
+    let mut nodes = 0;
+    let mut time = TimeManager::new(None);
+    let score = quiescence(
+        &mut board, &tables, &mut ctx, &mut tt, 0, -INF, INF, &mut nodes, &mut time,
+    );
+    (score, nodes)
+}
+
+#[test]
+fn qsearch_prunes_clearly_losing_capture() {
+    // Qxd5 wins a rook but loses the queen to Nxd5: SEE -400, never searched
+    let fen = "4k3/2n5/8/3r4/8/8/8/3QK3 w - - 0 1";
+    let board = Board::from_str(fen).unwrap();
+    let stand_pat = static_eval(&board, &load_magic_tables(), -INF, INF);
+
+    let (score, nodes) = qsearch_nodes(fen);
+    assert_eq!(nodes, 1, "losing capture was searched");
+    assert_eq!(score, stand_pat);
+}
+
+#[test]
+fn qsearch_searches_equal_capture() {
+    // Rxd5 Nxd5 trades rooks evenly (SEE 0), so the reply must be searched too
+    let (_, nodes) = qsearch_nodes("4k3/2n5/8/3r4/8/8/8/3RK3 w - - 0 1");
+    assert!(nodes > 1, "equal capture was pruned");
+}
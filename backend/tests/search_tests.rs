@@ -1,12 +1,15 @@
 //! Minimal search wiring tests: depth-0 behavior, stalemate/checkmate behavior,
 //! and a simple "free capture at depth=1" sanity check.
 use std::str::FromStr;
+use std::time::Duration;
 use vantage::board::Board;
 use vantage::moves::magic::loader::load_magic_tables;
 use vantage::moves::types::Move;
 use vantage::search::context::SearchContext;
 use vantage::search::eval::static_eval;
-use vantage::search::search::{TimeManager, alpha_beta};
+use vantage::search::search::{
+    DRAW_SCORE, NODE_CHECK_INTERVAL, TimeManager, alpha_beta, search, search_with_context,
+};
 use vantage::search::tt::TranspositionTable;
 
 const INF: i32 = 32000;
@@ -65,6 +68,20 @@ fn stalemate_returns_zero_any_depth() {
     }
 }
 
+#[test]
+fn mate_on_the_hundredth_halfmove_is_not_a_draw() {
+    // Rd8# is a quiet rook move: the clock reaches 100 as the mate lands,
+    // and the mate takes precedence over the fifty-move rule
+    let mut b = fen("6k1/5ppp/8/8/8/8/8/3R2K1 w - - 99 80");
+    let tables = load_magic_tables();
+    let mut tt = TranspositionTable::new(64);
+    let mut ctx = SearchContext::new();
+
+    let (score, best_move) = search_fixed_depth(&mut b, &tables, 2, &mut tt, &mut ctx, -INF, INF);
+    assert_eq!(best_move.map(|m| m.to_uci()).as_deref(), Some("d1d8"));
+    assert!(score > 30000, "expected a mate score, got {}", score);
+}
+
 #[test]
 fn depth1_prefers_free_capture_white() {
     let mut b = fen("k7/8/8/3p4/4P3/8/8/6K1 w - - 0 1");
@@ -205,3 +222,362 @@ fn test_tactical_win_material() {
     let (score, _) = search_position(fen, 5);
     assert!(score > -50);
 }
+
+#[test]
+fn test_search_result_fields() {
+    let tables = load_magic_tables();
+    let max_depth = 4;
+
+    for f in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    ] {
+        let mut board = fen(f);
+        let mut tt = TranspositionTable::new(16);
+        let result = search(&mut board, &tables, &mut tt, max_depth, None);
+
+        assert!(result.best_move.is_some(), "No best move for {}", f);
+        assert!(result.nodes > 0);
+        assert!(result.depth_reached >= 1 && result.depth_reached <= max_depth);
+        assert!((0.0..=1.0).contains(&result.tt_hit_rate));
+
+        // PV starts with the best move and never exceeds the completed depth
+        assert_eq!(result.pv.first().copied(), result.best_move);
+        assert!(result.pv.len() <= result.depth_reached as usize);
+
+        // The board is left untouched
+        assert_eq!(board.to_fen(), fen(f).to_fen());
+    }
+}
+
+#[test]
+fn test_pv_table_holds_forced_mate_line() {
+    // Rd8+ Rxd8 Rxd8#: Black's recapture is the only legal reply
+    let tables = load_magic_tables();
+    let mut board = fen("r5k1/5ppp/8/8/8/8/3R4/3R2K1 w - - 0 1");
+    let mut tt = TranspositionTable::new(16);
+    let mut ctx = SearchContext::new();
+
+    let result = search_with_context(
+        &mut board,
+        &tables,
+        &mut tt,
+        &mut ctx,
+        6,
+        TimeManager::new(None),
+    );
+    assert_eq!(
+        result.best_move.map(|m| m.to_uci()).as_deref(),
+        Some("d2d8")
+    );
+
+    let pv: Vec<String> = ctx.pv_table.get_pv(0).iter().map(|m| m.to_uci()).collect();
+    assert_eq!(pv, ["d2d8", "a8d8", "d1d8"]);
+}
+
+#[test]
+fn test_context_resets_between_searches() {
+    let tables = load_magic_tables();
+    let mut ctx = SearchContext::new();
+    let has_history = |ctx: &SearchContext| ctx.history.iter().flatten().any(|&h| h != 0);
+
+    let mut tt = TranspositionTable::new(16);
+    let mut board = fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1");
+    search_with_context(
+        &mut board,
+        &tables,
+        &mut tt,
+        &mut ctx,
+        6,
+        TimeManager::new(None),
+    );
+    assert!(has_history(&ctx));
+
+    // A new search in the same game keeps (decayed) history but drops killers
+    ctx.reset_for_new_search();
+    assert!(has_history(&ctx));
+    assert!(ctx.killer_moves.iter().all(|k| *k == [None, None]));
+
+    let mut board = fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+    tt.clear();
+    search_with_context(
+        &mut board,
+        &tables,
+        &mut tt,
+        &mut ctx,
+        4,
+        TimeManager::new(None),
+    );
+    assert!(has_history(&ctx));
+
+    ctx.reset_for_new_game();
+    assert!(!has_history(&ctx));
+    assert!(ctx.killer_moves.iter().all(|k| *k == [None, None]));
+}
+
+#[test]
+fn test_search_respects_node_limit() {
+    let tables = load_magic_tables();
+    let mut board = fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1");
+    let mut tt = TranspositionTable::new(16);
+    let mut ctx = SearchContext::new();
+
+    let time = TimeManager::with_limits(None, Some(1000));
+    let result = search_with_context(&mut board, &tables, &mut tt, &mut ctx, 64, time);
+
+    assert!(
+        result.nodes <= 1000 + NODE_CHECK_INTERVAL,
+        "searched {} nodes",
+        result.nodes
+    );
+    assert!(result.depth_reached < 64);
+
+    let best = result
+        .best_move
+        .expect("node-limited search must return a move");
+    let legal = vantage::moves::execute::is_legal_move(&mut board, best, &tables);
+    assert!(legal, "{} is not legal", best);
+}
+
+#[test]
+fn test_first_repetition_returns_draw_without_tt_store() {
+    let tables = load_magic_tables();
+    let mut board = Board::new();
+    for uci in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+        let mv = Move::from_uci(uci, &board).unwrap();
+        vantage::moves::execute::make_move_basic(&mut board, mv);
+    }
+    assert_eq!(board.repetition_count(), 2);
+
+    let mut tt = TranspositionTable::new(16);
+    let mut ctx = SearchContext::new();
+    for depth in [1, 4] {
+        let mut nodes = 0;
+        let mut time = TimeManager::new(None);
+        let (score, mv) = alpha_beta(
+            &mut board, &tables, &mut ctx, &mut tt, depth, 1, -INF, INF, &mut nodes, &mut time,
+        );
+        // Ply 1: the side to move is the root's opponent
+        assert_eq!(score, ctx.contempt.draw_score(1), "depth {}", depth);
+        assert_eq!(mv, None);
+        assert_eq!(nodes, 1, "repeated node must not be searched");
+    }
+    assert!(tt.probe(board.zobrist, 0, -INF, INF, 0).is_none());
+}
+
+#[test]
+fn test_time_manager_from_game_clock_allocation() {
+    let ms = Duration::from_millis;
+
+    // (time_left, increment, moves_to_go) -> (soft, hard)
+    let cases = [
+        // Unknown moves-to-go assumes 30: 60000/30 + 0.8*1000
+        ((60_000, 1_000, None), (2_800, 8_400)),
+        // Sudden death, no increment
+        ((30_000, 0, None), (1_000, 3_000)),
+        // Known moves-to-go: 60000/20 + 0.8*500
+        ((60_000, 500, Some(20)), (3_400, 10_200)),
+        // Last move before the control: hard cap at a fifth of the clock
+        ((10_000, 0, Some(1)), (2_000, 2_000)),
+        // Increment-heavy blitz: 3000/30 + 0.8*2000
+        ((3_000, 2_000, None), (600, 600)),
+    ];
+    for ((left, inc, mtg), (soft, hard)) in cases {
+        let tm = TimeManager::from_game_clock(ms(left), ms(inc), mtg);
+        assert_eq!(tm.soft_time(), Some(ms(soft)), "{:?}", (left, inc, mtg));
+        assert_eq!(
+            tm.allocated_time(),
+            Some(ms(hard)),
+            "{:?}",
+            (left, inc, mtg)
+        );
+    }
+}
+
+#[test]
+fn test_time_manager_extends_soft_limit_when_unstable() {
+    let mut tm = TimeManager::from_game_clock(Duration::from_secs(60), Duration::ZERO, None);
+    assert_eq!(tm.soft_time(), Some(Duration::from_secs(2)));
+
+    tm.update_after_depth(10);
+    assert_eq!(tm.soft_time(), Some(Duration::from_secs(2)));
+
+    tm.update_after_depth(-120);
+    assert_eq!(tm.soft_time(), Some(Duration::from_secs(3)));
+
+    // Never past the hard limit
+    for _ in 0..10 {
+        tm.update_after_depth(200);
+    }
+    assert_eq!(tm.soft_time(), tm.allocated_time());
+
+    // Fixed limits have soft == hard, so there is nothing to extend
+    let mut fixed = TimeManager::new(Some(Duration::from_millis(500)));
+    fixed.update_after_depth(500);
+    assert_eq!(fixed.soft_time(), Some(Duration::from_millis(500)));
+}
+
+/// Keeps every logged line so tests can inspect the search output.
+struct MemLogger(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+impl vantage::logger::SearchLogger for MemLogger {
+    fn log(&self, s: &str) {
+        self.0.lock().unwrap().push(s.to_string());
+    }
+}
+
+#[test]
+fn search_logs_one_info_line_per_iteration() {
+    let tables = load_magic_tables();
+    let lines = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut ctx = SearchContext::new();
+    ctx.logger = Box::new(MemLogger(lines.clone()));
+
+    let mut tt = TranspositionTable::new(16);
+    let mut board = fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+    let result = search_with_context(
+        &mut board,
+        &tables,
+        &mut tt,
+        &mut ctx,
+        4,
+        TimeManager::new(None),
+    );
+
+    let lines = lines.lock().unwrap();
+    assert_eq!(lines.len(), result.depth_reached as usize, "{:?}", lines);
+    for (i, line) in lines.iter().enumerate() {
+        assert!(
+            line.starts_with(&format!("info depth {} score ", i + 1)),
+            "{}",
+            line
+        );
+    }
+    let best = result.best_move.unwrap().to_uci();
+    assert!(lines.last().unwrap().contains(&format!(" pv {}", best)));
+}
+
+#[test]
+fn null_logger_writes_nothing_to_stdout() {
+    // The test harness captures println! per test, so run the search in a
+    // child copy of this test binary and read its real stdout instead
+    const CHILD: &str = "VANTAGE_NULL_LOGGER_CHILD";
+    if std::env::var_os(CHILD).is_some() {
+        let tables = load_magic_tables();
+        let mut ctx = SearchContext::new();
+        assert!(!ctx.logger.enabled());
+        let mut tt = TranspositionTable::new(1);
+        let mut board = fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3");
+        search_with_context(
+            &mut board,
+            &tables,
+            &mut tt,
+            &mut ctx,
+            4,
+            TimeManager::new(None),
+        );
+        return;
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([
+            "--exact",
+            "null_logger_writes_nothing_to_stdout",
+            "--nocapture",
+            "--test-threads=1",
+        ])
+        .env(CHILD, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("1 passed"), "{}", stdout);
+    assert!(
+        !stdout.lines().any(|l| l.starts_with("info")),
+        "search wrote to stdout:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_contempt_avoids_repetition_when_worse_off() {
+    use vantage::moves::execute::make_move_basic;
+
+    let tables = load_magic_tables();
+    // Black is a pawn up; after Nf3 Kd8 Ng1 Ke8, Nf3 repeats the position
+    let mut start = Board::from_str("1n2k3/pp6/8/8/8/8/P7/4K1N1 w - - 0 1").unwrap();
+    for uci in ["g1f3", "e8d8", "f3g1", "d8e8"] {
+        let mv = Move::from_uci(uci, &start).unwrap();
+        make_move_basic(&mut start, mv);
+    }
+
+    let best_with_contempt = |contempt: i32| {
+        let mut board = start.clone();
+        let mut tt = TranspositionTable::new(16);
+        let mut ctx = SearchContext::new();
+        ctx.contempt.contempt = contempt;
+        let result = search_with_context(
+            &mut board,
+            &tables,
+            &mut tt,
+            &mut ctx,
+            5,
+            TimeManager::new(None),
+        );
+        (result.best_move.unwrap().to_uci(), result.score)
+    };
+
+    // Without contempt the draw beats playing on a pawn down
+    assert_eq!(best_with_contempt(0), ("g1f3".to_string(), DRAW_SCORE));
+
+    // With contempt a draw is worth less than the pawn-down position
+    let (best, score) = best_with_contempt(200);
+    assert_ne!(best, "g1f3");
+    assert!(score > DRAW_SCORE - 200, "score {}", score);
+}
+
+#[test]
+fn test_recapture_extended_in_zero_window_search() {
+    use vantage::board::Piece;
+    use vantage::moves::types::CAPTURE;
+    use vantage::search::tt::NodeType;
+    use vantage::square::Square;
+
+    let tables = load_magic_tables();
+    // Black has just played ...Nc6xe5. Nxe5 wins the knight back, but it
+    // pulls the f3 knight off h2 and ...Qxh2 is mate. Seen at depth 0
+    // (quiescence) the recapture just wins a piece; only the recapture
+    // extension gives it the ply in which the mate is found.
+    let fen = "6k1/5ppp/8/4n2q/6n1/5N2/P4PPP/5RK1 w - - 0 1";
+    let black_capture = Move {
+        from: Square::from_str("c6").unwrap(),
+        to: Square::from_str("e5").unwrap(),
+        piece: Piece::Knight,
+        promotion: None,
+        flags: CAPTURE,
+    };
+
+    // Search the position as a ply-1 node with a3 as the hash move, so the
+    // recapture is not the first move and goes through the scout search.
+    let run = |alpha: i32, beta: i32| {
+        let mut board = Board::from_str(fen).unwrap();
+        let a3 = Move::from_uci("a2a3", &board).unwrap();
+        let mut tt = TranspositionTable::new(1);
+        tt.save(board.zobrist, Some(a3), 0, 0, NodeType::UpperBound as u8, 1);
+        let mut ctx = SearchContext::new();
+        ctx.move_stack[0] = Some(black_capture);
+        let mut nodes = 0;
+        let mut time = TimeManager::new(None);
+        alpha_beta(
+            &mut board, &tables, &mut ctx, &mut tt, 1, 1, alpha, beta, &mut nodes, &mut time,
+        )
+    };
+
+    let (full, best) = run(-INF, INF);
+    assert_ne!(best.map(|m| m.to_uci()).as_deref(), Some("f3e5"));
+    // A zero window just above the true value must fail low; without the
+    // extension the scout scores Nxe5 as winning a piece and fails high
+    let (scout, _) = run(full, full + 1);
+    assert!(scout <= full, "scout {} vs full-window {}", scout, full);
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison, clippy::expect_fun_call)]
+
 use std::str::FromStr;
 use vantage::board::Board;
 use vantage::moves::magic::loader::load_magic_tables;
@@ -118,3 +120,72 @@ fn test_see_promotion_capture() {
     // Even with a huge threshold, this should pass
     assert_eq!(board.static_exchange_eval(m, 1000, &tables), true);
 }
+
+fn see_score(fen: &str, from: &str, to: &str) -> i32 {
+    let mut board = Board::from_str(fen).unwrap();
+    let (m, tables) = find_move(&mut board, from, to);
+    board.static_exchange_eval_score(m, &tables)
+}
+
+#[test]
+fn test_see_score_equal_trade() {
+    // NxN, pawn recaptures: 320 - 320
+    assert_eq!(
+        see_score("7k/8/4p3/3n4/8/4N3/8/7K w - - 0 1", "e3", "d5"),
+        0
+    );
+}
+
+#[test]
+fn test_see_score_winning_trade() {
+    // PxN, knight defended by a pawn: 320 - 100
+    assert_eq!(
+        see_score("7k/8/4p3/3n4/2P5/8/8/7K w - - 0 1", "c4", "d5"),
+        220
+    );
+}
+
+#[test]
+fn test_see_score_losing_trade() {
+    // QxP, pawn defended by a pawn: 100 - 900
+    assert_eq!(
+        see_score("7k/8/4p3/3p4/8/8/8/3Q3K w - - 0 1", "d1", "d5"),
+        -800
+    );
+}
+
+#[test]
+fn test_see_score_xray_behind_recapturing_pawn() {
+    // cxd5 exd5 Bxd5 Qxd5: the f7 queen only joins once the e6 pawn has
+    // recaptured, so White should stop after exd5 (320 - 100)
+    assert_eq!(
+        see_score("7k/5q2/4p3/3n4/2P5/1B6/8/7K w - - 0 1", "c4", "d5"),
+        220
+    );
+}
+
+#[test]
+fn test_see_score_queen_sacrifice() {
+    // QxR with the rook defended by a pawn: 500 - 900, and the d1 rook
+    // behind the queen only wins the pawn back (+100)
+    assert_eq!(
+        see_score("7k/8/4p3/3r4/8/8/3Q4/3R3K w - - 0 1", "d2", "d5"),
+        -300
+    );
+}
+
+#[test]
+fn test_see_score_and_pruning_api() {
+    // RxQ with nothing defending the queen wins the whole queen
+    let mut board = Board::from_str("3q3k/8/8/8/8/8/8/3RK3 w - - 0 1").unwrap();
+    let (m, tables) = find_move(&mut board, "d1", "d8");
+    assert_eq!(board.see_score(m, &tables), 900);
+    assert!(board.see_pruning_for_move(m, 0, &tables));
+    assert!(!board.see_pruning_for_move(m, 901, &tables));
+
+    // Defended by the king: 900 - 500 once the rook is taken back
+    let mut board = Board::from_str("3qk3/8/8/8/8/8/8/3RK3 w - - 0 1").unwrap();
+    let (m, tables) = find_move(&mut board, "d1", "d8");
+    assert_eq!(board.see_score(m, &tables), 400);
+    assert!(board.see_pruning_for_move(m, 0, &tables));
+}
@@ -1,9 +1,12 @@
 use std::str::FromStr;
+use vantage::bitboard::{bb_from_squares, to_bitboard_string};
 use vantage::board::{Board, Color};
 
+use vantage::moves::execute::make_move_basic;
 use vantage::moves::magic::MagicTables;
 use vantage::moves::magic::loader::load_magic_tables;
-use vantage::moves::square_control::{in_check, is_square_attacked};
+use vantage::moves::square_control::{checkers, in_check, in_double_check, is_square_attacked};
+use vantage::moves::types::Move;
 use vantage::square::Square;
 
 fn tables() -> MagicTables {
@@ -94,3 +97,220 @@ fn castling_attack_check_blocks_through_and_to() {
     // assert!(!is_legal_castling(&b, king_side, &t));
     // assert!(!is_legal_castling(&b, queen_side, &t));
 }
+
+fn bit(sq: &str) -> u64 {
+    1u64 << Square::from_str(sq).unwrap().index()
+}
+
+#[test]
+fn checkers_empty_in_startpos() {
+    let b = Board::new();
+    let t = tables();
+    assert_eq!(checkers(&b, Color::White, &t), 0);
+    assert_eq!(checkers(&b, Color::Black, &t), 0);
+    assert!(!in_double_check(&b, Color::White, &t));
+}
+
+#[test]
+fn checkers_single_check_by_each_piece_type() {
+    let t = tables();
+    for (fen, checker) in [
+        ("4k3/8/8/8/8/8/3p4/4K3 w - - 0 1", "d2"), // pawn
+        ("4k3/8/8/8/8/3n4/8/4K3 w - - 0 1", "d3"), // knight
+        ("4k3/8/8/b7/8/8/8/4K3 w - - 0 1", "a5"),  // bishop
+        ("4k3/8/8/8/4r3/8/8/4K3 w - - 0 1", "e4"), // rook
+        ("4k3/8/8/8/7q/8/8/4K3 w - - 0 1", "h4"),  // queen on the diagonal
+        ("4k3/8/8/8/8/8/8/q3K3 w - - 0 1", "a1"),  // queen on the rank
+    ] {
+        let b = Board::from_str(fen).unwrap();
+        assert_eq!(checkers(&b, Color::White, &t), bit(checker), "{}", fen);
+        assert!(in_check(&b, Color::White, &t));
+        assert!(!in_double_check(&b, Color::White, &t));
+    }
+}
+
+#[test]
+fn checkers_ignores_blocked_and_own_pieces() {
+    let t = tables();
+    // The e4 rook is blocked by the e2 pawn; the white queen on a1 is a friend
+    let b = Board::from_str("4k3/8/8/8/4r3/8/4P3/Q3K3 w - - 0 1").unwrap();
+    assert_eq!(checkers(&b, Color::White, &t), 0);
+}
+
+#[test]
+fn checkers_double_check() {
+    let t = tables();
+    // Discovered double check: rook e8 and knight f3 both hit the e1 king
+    let b = Board::from_str("4r2k/8/8/8/8/5n2/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(checkers(&b, Color::White, &t), bit("e8") | bit("f3"));
+    assert!(in_double_check(&b, Color::White, &t));
+    assert!(!in_check(&b, Color::Black, &t));
+}
+
+#[test]
+fn checkers_includes_adjacent_king() {
+    // Never legal, but the make/undo legality filter relies on kings touching
+    // counting as check
+    let t = tables();
+    let b = Board::from_str("8/8/8/8/8/8/4k3/4K3 w - - 0 1").unwrap();
+    assert_eq!(checkers(&b, Color::White, &t), bit("e2"));
+    assert!(in_check(&b, Color::White, &t));
+}
+
+const RANK_3: u64 = 0x0000_0000_00FF_0000;
+const RANK_4: u64 = 0x0000_0000_FF00_0000;
+
+fn assert_bb_eq(actual: u64, expected: u64) {
+    assert_eq!(
+        actual,
+        expected,
+        "\nExpected:\n{}\nGot:\n{}",
+        to_bitboard_string(expected),
+        to_bitboard_string(actual)
+    );
+}
+
+fn sq_bb(s: &str) -> u64 {
+    1u64 << Square::from_str(s).unwrap().index()
+}
+
+#[test]
+fn startpos_white_attacks_cover_rank_3_only() {
+    let tables = tables();
+    let board = Board::new();
+    let attacks = board.generate_attacks(Color::White, &tables);
+
+    assert_bb_eq(attacks & RANK_3, RANK_3);
+    // Nothing reaches past the third rank before a pawn has moved
+    assert_bb_eq(attacks & !0xFF_FFFF, 0);
+    assert_bb_eq(attacks & RANK_4, 0);
+}
+
+#[test]
+fn attacks_update_after_pawn_move() {
+    let tables = tables();
+    let board =
+        Board::from_str("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1").unwrap();
+    let attacks = board.generate_attacks(Color::White, &tables);
+
+    // e4 pawn hits d5/f5, the freed bishop reaches a6, the queen h5
+    for s in ["d5", "f5", "a6", "h5", "e2"] {
+        assert!(attacks & sq_bb(s) != 0, "{} should be attacked", s);
+    }
+    assert_eq!(attacks & sq_bb("e5"), 0);
+}
+
+#[test]
+fn king_moves_skip_attacked_squares() {
+    use vantage::moves::movegen::generate_king_moves;
+    use vantage::moves::types::Move;
+
+    let tables = tables();
+    // Rook on a1 checks along the first rank; f1 stays on the ray behind the king
+    let board = Board::from_str("4k3/8/8/8/8/8/8/r3K3 w - - 0 1").unwrap();
+    let mut moves: Vec<Move> = Vec::new();
+    generate_king_moves(&board, &tables, &mut moves);
+
+    let mut targets: Vec<String> = moves.iter().map(|m| m.to.to_string()).collect();
+    targets.sort();
+    assert_eq!(targets, ["d2", "e2", "f2"]);
+}
+
+#[test]
+fn attacks_from_startpos_and_empty_square() {
+    let tables = tables();
+    let board = Board::new();
+
+    // Queen d1 is boxed in: it defends its neighbours but cannot see past d2
+    let queen = board.attacks_from(Square::from_str("d1").unwrap(), &tables);
+    assert_bb_eq(
+        queen,
+        bit("c1") | bit("e1") | bit("c2") | bit("d2") | bit("e2"),
+    );
+    assert_eq!(queen & bit("d3"), 0);
+
+    let bishop = board.attacks_from(Square::from_str("c1").unwrap(), &tables);
+    assert_bb_eq(bishop, bit("b2") | bit("d2"));
+
+    let knight = board.attacks_from(Square::from_str("g8").unwrap(), &tables);
+    assert_bb_eq(knight, bit("e7") | bit("f6") | bit("h6"));
+
+    let pawn = board.attacks_from(Square::from_str("e7").unwrap(), &tables);
+    assert_bb_eq(pawn, bit("d6") | bit("f6"));
+
+    assert_bb_eq(
+        board.attacks_from(Square::from_str("e4").unwrap(), &tables),
+        0,
+    );
+}
+
+#[test]
+fn attacks_from_open_queen_matches_rook_plus_bishop() {
+    let tables = tables();
+    let board = Board::from_str("4k3/8/8/8/3Q4/8/8/4K3 w - - 0 1").unwrap();
+    let attacks = board.attacks_from(Square::from_str("d4").unwrap(), &tables);
+    assert_eq!(attacks.count_ones(), 27);
+    assert_ne!(attacks & bit("a7"), 0);
+    assert_ne!(attacks & bit("h8"), 0);
+}
+
+#[test]
+fn count_attacks_on_rook_and_knight() {
+    // d5 is hit by White's rook on d1 and knight on f4, and by Black's d8 rook
+    let b = Board::from_str("3r3k/8/8/8/5N2/8/8/3RK3 w - - 0 1").unwrap();
+    let t = tables();
+    let d5 = Square::from_str("d5").unwrap();
+    assert_eq!(b.count_attacks_on(d5, Color::White, &t), 2);
+    assert_eq!(
+        b.attackers_of_color(d5, Color::White, &t),
+        bb_from_squares(&[
+            Square::from_str("d1").unwrap(),
+            Square::from_str("f4").unwrap()
+        ])
+    );
+    assert_eq!(b.count_attacks_on(d5, Color::Black, &t), 1);
+}
+
+#[test]
+fn count_attacks_on_ignores_xray_attackers() {
+    // Doubled rooks on d1/d2: only the front rook sees d5
+    let b = Board::from_str("7k/8/8/8/8/8/3R4/3RK3 w - - 0 1").unwrap();
+    let t = tables();
+    let d5 = Square::from_str("d5").unwrap();
+    assert_eq!(b.count_attacks_on(d5, Color::White, &t), 1);
+    assert_eq!(
+        b.attackers_of_color(d5, Color::White, &t),
+        bb_from_squares(&[Square::from_str("d2").unwrap()])
+    );
+}
+
+#[test]
+fn threats_from_move_finds_undefended_targets() {
+    let tables = tables();
+
+    // Nf3 eyes e5 and g5, but no black piece stands on them
+    let board = Board::new();
+    let nf3 = Move::from_uci("g1f3", &board).unwrap();
+    assert_eq!(board.threats_from_move(nf3, &tables), 0);
+    let mut after = board.clone();
+    make_move_basic(&mut after, nf3);
+    assert_ne!(
+        after.attacks_from(Square::from_str("f3").unwrap(), &tables) & bit("e5"),
+        0
+    );
+
+    // Ng5 hits f7 (covered by the king) and h7 (no longer covered by the
+    // rook, which has gone to g8)
+    let board =
+        Board::from_str("r1bqkbr1/pppppppp/n1n5/8/8/5N2/PPPPPPPP/RNBQKB1R w KQq - 0 1").unwrap();
+    let ng5 = Move::from_uci("f3g5", &board).unwrap();
+    assert_bb_eq(board.threats_from_move(ng5, &tables), bit("h7"));
+
+    // Rd1 attacks the queen, which counts only while nothing defends it
+    let board = Board::from_str("3q3k/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+    let rd1 = Move::from_uci("a1d1", &board).unwrap();
+    assert_bb_eq(board.threats_from_move(rd1, &tables), bit("d8"));
+    let board = Board::from_str("3q3k/2b5/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+    let rd1 = Move::from_uci("a1d1", &board).unwrap();
+    assert_bb_eq(board.threats_from_move(rd1, &tables), 0);
+}
@@ -7,7 +7,9 @@ use vantage::moves::execute::{make_move_basic, undo_move_basic};
 use vantage::moves::magic::loader::load_magic_tables;
 use vantage::moves::types::{CAPTURE, EN_PASSANT, Move, PROMOTION, QUIET_MOVE};
 use vantage::square::Square;
-use vantage::status::{GameStatus, is_draw_by_fifty_move, is_draw_by_threefold, position_status};
+use vantage::status::{
+    GameStatus, is_draw_by_fifty_move, is_draw_by_threefold, is_draw_fast, position_status,
+};
 
 // ---- Small helpers ----
 
@@ -337,8 +339,21 @@ fn dead_position_kn_vs_kn() {
 fn dead_position_kb_vs_kb() {
     let tables = load_magic_tables();
     let mut b = Board::new();
-    // White B on c3 (18), Black b on g1 (6)
-    b.set_fen("8/8/8/8/8/2B5/4k3/5b1K w - - 0 1").unwrap();
+    // White B on c3 (18), Black b on e1 (4): both on dark squares
+    b.set_fen("8/8/8/8/8/2B5/4k3/4b2K w - - 0 1").unwrap();
+    assert_eq!(
+        position_status(&mut b, &tables),
+        GameStatus::DrawDeadPosition
+    );
+}
+
+#[test]
+fn dead_position_kb_vs_kb_same_color_back_rank() {
+    let tables = load_magic_tables();
+    let mut b = Board::new();
+    // White B on c1, Black b on g1: both dark-squared
+    b.set_fen("4k3/8/8/8/8/8/8/2B1K1b1 w - - 0 1").unwrap();
+    assert!(b.is_draw_by_material());
     assert_eq!(
         position_status(&mut b, &tables),
         GameStatus::DrawDeadPosition
@@ -371,6 +386,89 @@ fn not_dead_kbn_vs_k() {
     assert_eq!(position_status(&mut b, &tables), GameStatus::InPlay);
 }
 
+#[test]
+fn not_dead_kb_vs_kb_opposite_colors() {
+    let tables = load_magic_tables();
+    let mut b = Board::new();
+    // White B on c3 (dark), Black b on f1 (light): a corner mate is still possible
+    b.set_fen("8/8/8/8/8/2B5/4k3/5b1K w - - 0 1").unwrap();
+    assert!(!b.is_draw_by_material());
+    assert_eq!(position_status(&mut b, &tables), GameStatus::InPlay);
+}
+
+#[test]
+fn not_dead_k_vs_kp() {
+    let tables = load_magic_tables();
+    let mut b = Board::new();
+    b.set_fen("4k3/4p3/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    assert!(!b.is_draw_by_material());
+    assert_eq!(position_status(&mut b, &tables), GameStatus::InPlay);
+}
+
+#[test]
+fn insufficient_material_per_side() {
+    use vantage::board::Color;
+
+    for (fen, white, black) in [
+        // KvK
+        ("4k3/8/8/8/8/8/8/4K3 w - - 0 1", true, true),
+        // KBvK
+        ("4k3/8/8/8/8/2B5/8/4K3 w - - 0 1", true, true),
+        // KNvK
+        ("4k3/8/8/8/8/2N5/8/4K3 w - - 0 1", true, true),
+        // KNNvK
+        ("4k3/8/8/8/8/2NN4/8/4K3 w - - 0 1", true, true),
+        // KBBvK
+        ("4k3/8/8/8/8/2BB4/8/4K3 w - - 0 1", false, true),
+        // KBNvK
+        ("4k3/8/8/8/8/2BN4/8/4K3 w - - 0 1", false, true),
+        // KvKP
+        ("4k3/4p3/8/8/8/8/8/4K3 w - - 0 1", true, false),
+        // KRvK
+        ("4k3/8/8/8/8/8/8/R3K3 w - - 0 1", false, true),
+    ] {
+        let b = Board::from_str(fen).unwrap();
+        assert_eq!(
+            b.insufficient_material(Color::White),
+            white,
+            "White in {}",
+            fen
+        );
+        assert_eq!(
+            b.insufficient_material(Color::Black),
+            black,
+            "Black in {}",
+            fen
+        );
+    }
+}
+
+#[test]
+fn insufficient_material_draw_cases() {
+    for (fen, draw) in [
+        ("4k3/8/8/8/8/8/8/4K3 w - - 0 1", true),      // KvK
+        ("4k3/8/8/8/8/2B5/8/4K3 w - - 0 1", true),    // KBvK
+        ("4k3/8/8/8/8/2N5/8/4K3 w - - 0 1", true),    // KNvK
+        ("4k3/8/8/8/8/2NN4/8/4K3 w - - 0 1", true),   // KNNvK
+        ("4k3/8/8/8/8/8/8/3bK3 w - - 0 1", true),     // KvKB
+        ("4kb2/8/8/8/8/2B5/8/4K3 w - - 0 1", true),   // KBvKB, both on dark squares
+        ("4k1b1/8/8/8/8/2B5/8/4K3 w - - 0 1", false), // KBvKB, opposite colors
+        ("4k3/8/8/8/8/2BB4/8/4K3 w - - 0 1", false),  // KBBvK
+        ("4k3/8/8/8/8/2BN4/8/4K3 w - - 0 1", false),  // KBNvK
+        ("4k3/4p3/8/8/8/8/8/4K3 w - - 0 1", false),   // KvKP
+        ("4kn2/8/8/8/8/2NN4/8/4K3 w - - 0 1", false), // KNNvKN
+    ] {
+        let b = Board::from_str(fen).unwrap();
+        assert_eq!(b.is_draw_by_material(), draw, "{}", fen);
+        assert_eq!(
+            vantage::status::is_insufficient_material(&b),
+            draw,
+            "{}",
+            fen
+        );
+    }
+}
+
 #[test]
 fn seventyfive_forced_draw_precedes_threefold() {
     let tables = load_magic_tables();
@@ -1344,3 +1442,98 @@ fn draw_insufficient_kb_vs_kb_same_color() {
         GameStatus::DrawDeadPosition
     );
 }
+
+#[test]
+fn is_draw_fast_agrees_with_position_status_on_draws() {
+    let tables = load_magic_tables();
+    let is_draw = |st: GameStatus| {
+        !matches!(
+            st,
+            GameStatus::InPlay | GameStatus::Stalemate | GameStatus::Checkmate
+        )
+    };
+
+    let draws = [
+        "8/8/8/8/8/8/4k3/4K3 w - - 0 1",     // K vs K
+        "8/8/8/8/8/3N4/4k3/4K3 w - - 0 1",   // KN vs K
+        "8/8/8/8/8/2B5/4k3/4K3 w - - 0 1",   // KB vs K
+        "4k3/8/8/8/8/8/8/R3K3 w - - 100 50", // fifty moves
+        "4k3/8/8/8/8/8/8/R3K3 w - - 150 75", // seventy-five moves
+    ];
+    let unknown = [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "4k3/8/8/8/8/2B5/2B5/4K3 w - - 0 1", // KBB vs K
+        "4k3/4p3/8/8/8/8/8/4K3 w - - 0 1",   // K vs KP
+        "4k3/8/8/8/8/8/8/R3K3 w - - 99 50",  // one short of fifty
+        "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1",    // stalemate needs movegen
+        "7k/6Q1/6K1/8/8/8/8/8 b - - 0 1",    // checkmate
+    ];
+    for (fen, expected) in draws
+        .iter()
+        .map(|f| (f, true))
+        .chain(unknown.iter().map(|f| (f, false)))
+    {
+        let mut b = Board::from_str(fen).unwrap();
+        assert_eq!(is_draw_fast(&b, &tables), expected, "{}", fen);
+        if expected {
+            assert!(is_draw(position_status(&mut b, &tables)), "{}", fen);
+        }
+    }
+
+    // Threefold and fivefold by shuffling kings
+    let mut b = Board::from_str("8/8/8/8/8/8/4k3/R3K3 w - - 0 1").unwrap();
+    for cycle in 1..=4 {
+        let _ = make_move_basic(&mut b, mv_king(4, 3));
+        let _ = make_move_basic(&mut b, mv_king(12, 11));
+        let _ = make_move_basic(&mut b, mv_king(3, 4));
+        let _ = make_move_basic(&mut b, mv_king(11, 12));
+        assert_eq!(is_draw_fast(&b, &tables), cycle >= 2, "cycle {}", cycle);
+        assert_eq!(is_draw(position_status(&mut b, &tables)), cycle >= 2);
+    }
+}
+
+#[test]
+fn legal_move_exists_matches_generate_legal() {
+    use vantage::moves::execute::generate_legal;
+
+    fn walk(board: &mut Board, tables: &vantage::moves::magic::MagicTables, depth: u32) {
+        let mut legal = Vec::new();
+        let mut scratch = Vec::new();
+        generate_legal(board, tables, &mut legal, &mut scratch);
+        assert_eq!(
+            board.legal_move_exists(tables),
+            !legal.is_empty(),
+            "{}",
+            board.to_fen()
+        );
+        let before = board.clone();
+        assert_eq!(
+            board.has_legal_moves(tables),
+            !legal.is_empty(),
+            "{}",
+            board.to_fen()
+        );
+        assert_eq!(*board, before);
+        if depth == 0 {
+            return;
+        }
+        for mv in legal {
+            let undo = make_move_basic(board, mv);
+            walk(board, tables, depth - 1);
+            undo_move_basic(board, undo);
+        }
+    }
+
+    let tables = load_magic_tables();
+    for fen in [
+        // Stalemate, checkmate, and positions a move or two away from them
+        "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1",
+        "7k/6Q1/6K1/8/8/8/8/8 b - - 0 1",
+        "k7/8/1QK5/8/8/8/8/8 w - - 0 1",
+        "6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    ] {
+        let mut board = Board::from_str(fen).unwrap();
+        walk(&mut board, &tables, 2);
+    }
+}
@@ -3,6 +3,7 @@ use std::time::Duration;
 /// Verify the engine finds forced mates and wins material
 use vantage::board::Board;
 use vantage::moves::magic::loader::load_magic_tables;
+use vantage::search::SearchResult;
 use vantage::search::search::search;
 use vantage::search::tt::TranspositionTable;
 
@@ -16,7 +17,7 @@ fn test_mate_in_1_scholars_mate() {
     let tables = load_magic_tables();
 
     // Search should recognize this is a very bad position for Black
-    let (score, _best_move) = search(
+    let SearchResult { score, .. } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -41,7 +42,7 @@ fn test_back_rank_mate() {
     board.set_fen(fen).unwrap();
     let tables = load_magic_tables();
 
-    let (_score, best_move) = search(
+    let SearchResult { best_move, .. } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -64,7 +65,9 @@ fn test_capture_hanging_queen() {
     board.set_fen(fen).unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -96,7 +99,7 @@ fn test_avoid_hanging_piece() {
     board.set_fen(fen).unwrap();
     let tables = load_magic_tables();
 
-    let (_score, best_move) = search(
+    let SearchResult { best_move, .. } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -121,7 +124,7 @@ fn test_fork_opportunity() {
     board.set_fen(fen).unwrap();
     let tables = load_magic_tables();
 
-    let (score, _best_move) = search(
+    let SearchResult { score, .. } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -143,7 +146,9 @@ fn test_starting_position_sanity() {
     board.set_fen(fen).unwrap();
     let tables = load_magic_tables();
 
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -173,7 +178,7 @@ fn test_piece_up_advantage() {
     board.set_fen(fen).unwrap();
     let tables = load_magic_tables();
 
-    let (score, _best_move) = search(
+    let SearchResult { score, .. } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -198,7 +203,7 @@ fn test_piece_down_disadvantage() {
     board.set_fen(fen).unwrap();
     let tables = load_magic_tables();
 
-    let (score, _best_move) = search(
+    let SearchResult { score, .. } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
@@ -1,10 +1,12 @@
 // TT Move Ordering Test Suite
+#![allow(clippy::manual_contains, clippy::useless_vec)]
 
 use std::str::FromStr;
 use std::time::Instant;
 use vantage::board::Board;
 use vantage::moves::magic::loader::load_magic_tables;
 use vantage::moves::types::Move;
+use vantage::search::SearchResult;
 use vantage::search::context::SearchContext;
 use vantage::search::search::{TimeManager, alpha_beta, search};
 use vantage::search::tt::TranspositionTable;
@@ -70,7 +72,9 @@ fn test_iterative_deepening_uses_tt_moves() {
     // because TT moves from depth N-1 help search depth N
 
     // Note: 'search' implements iterative deepening internally
-    let (score, best_move) = search(
+    let SearchResult {
+        score, best_move, ..
+    } = search(
         &mut board,
         &tables,
         &mut TranspositionTable::new(512),
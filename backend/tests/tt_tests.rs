@@ -43,3 +43,76 @@ fn test_tt_mate_score_normalization() {
         panic!("Entry not found at ply 20");
     }
 }
+
+#[test]
+fn test_tt_resize() {
+    let mut tt = TranspositionTable::new(8);
+    let small_entries = tt.capacity_entries();
+    assert!(small_entries.is_power_of_two());
+    assert!(tt.capacity_bytes() <= 8 * 1024 * 1024);
+
+    let key = 0xDEAD_BEEF_u64;
+    tt.save(key, None, 42, 3, 0, 0);
+    assert!(tt.probe(key, 0, -32000, 32000, 0).is_some());
+
+    tt.resize(64);
+    assert!(tt.capacity_entries() > small_entries);
+    assert!(tt.capacity_entries().is_power_of_two());
+    assert!(tt.capacity_bytes() <= 64 * 1024 * 1024);
+    assert_eq!(tt.generation, 0);
+
+    // Nothing survives a resize
+    assert!(tt.probe(key, 0, -32000, 32000, 0).is_none());
+}
+
+#[test]
+fn test_corrupted_tt_move_is_discarded() {
+    use std::str::FromStr;
+    use vantage::board::{Board, Piece};
+    use vantage::moves::execute::{generate_legal, make_move_basic, undo_move_basic};
+    use vantage::moves::magic::loader::load_magic_tables;
+    use vantage::moves::types::{Move, QUIET_MOVE};
+    use vantage::search::search::search;
+    use vantage::search::tt::NodeType;
+    use vantage::square::Square;
+
+    let tables = load_magic_tables();
+    let mut board =
+        Board::from_str("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3")
+            .unwrap();
+    let mut tt = TranspositionTable::new(1);
+
+    // "Queen" from a1 to a8: wrong piece on the from square, and an illegal
+    // capture-free move onto an occupied square in every child position
+    let bogus = Move {
+        from: Square::from_index(0),
+        to: Square::from_index(56),
+        piece: Piece::Queen,
+        promotion: None,
+        flags: QUIET_MOVE,
+    };
+
+    // Plant deep, exact entries with the bogus move for the root and every child
+    let exact = NodeType::Exact as u8;
+    tt.save(board.zobrist, Some(bogus), 0, 100, exact, 0);
+    let mut moves = Vec::new();
+    let mut scratch = Vec::new();
+    generate_legal(&mut board, &tables, &mut moves, &mut scratch);
+    for &mv in &moves {
+        let undo = make_move_basic(&mut board, mv);
+        tt.save(board.zobrist, Some(bogus), 5000, 100, exact, 1);
+        undo_move_basic(&mut board, undo);
+    }
+
+    let result = search(&mut board, &tables, &mut tt, 3, None);
+
+    let best = result.best_move.expect("search returned no move");
+    assert!(moves.contains(&best), "illegal best move {}", best.to_uci());
+    // Poisoned child scores must not have been trusted
+    assert!(
+        result.score.abs() < 1000,
+        "score {} came from a bad entry",
+        result.score
+    );
+    assert!(tt.stats().collisions > 0);
+}
@@ -0,0 +1,102 @@
+//! End-to-end tests that drive the `vantage` UCI binary over stdin/stdout.
+#![cfg(feature = "cli")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+use vantage::board::Board;
+use vantage::moves::execute::generate_legal;
+use vantage::moves::magic::loader::load_magic_tables;
+
+// Out of book, so the engine has to search
+const KIWI_FEN: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+#[test]
+fn test_go_infinite_until_stop() {
+    let mut engine = Command::new(env!("CARGO_BIN_EXE_vantage"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to start engine");
+    let mut stdin = engine.stdin.take().unwrap();
+    let mut stdout = BufReader::new(engine.stdout.take().unwrap()).lines();
+
+    // Wait until startup (magic tables, hash allocation) is over so the 50ms
+    // below is all search time
+    writeln!(stdin, "setoption name Hash value 16").unwrap();
+    writeln!(stdin, "position fen {}", KIWI_FEN).unwrap();
+    writeln!(stdin, "isready").unwrap();
+    stdout
+        .by_ref()
+        .map_while(Result::ok)
+        .find(|l| l == "readyok")
+        .expect("engine never became ready");
+
+    writeln!(stdin, "go infinite").unwrap();
+    thread::sleep(Duration::from_millis(50));
+    writeln!(stdin, "isready").unwrap();
+    writeln!(stdin, "stop").unwrap();
+    writeln!(stdin, "quit").unwrap();
+
+    let lines: Vec<String> = stdout.map_while(Result::ok).collect();
+    assert!(engine.wait().unwrap().success());
+
+    // isready is answered while the search is still running
+    let ready = lines.iter().position(|l| l == "readyok");
+    let best = lines.iter().position(|l| l.starts_with("bestmove "));
+    assert!(ready.is_some() && best.is_some(), "output: {:?}", lines);
+    assert!(ready < best);
+    assert!(
+        lines[..best.unwrap()].iter().any(|l| {
+            l.strip_prefix("info depth ")
+                .and_then(|rest| rest.split_whitespace().next())
+                .and_then(|d| d.parse::<u32>().ok())
+                .is_some_and(|d| d >= 1)
+        }),
+        "no completed iteration before stop: {:?}",
+        lines
+    );
+
+    let uci = lines[best.unwrap()].trim_start_matches("bestmove ");
+    let tables = load_magic_tables();
+    let mut board = Board::from_str(KIWI_FEN).unwrap();
+    let mut moves = Vec::new();
+    let mut scratch = Vec::new();
+    generate_legal(&mut board, &tables, &mut moves, &mut scratch);
+    assert!(
+        moves.iter().any(|m| m.to_uci() == uci),
+        "bestmove {} is not legal",
+        uci
+    );
+}
+
+#[test]
+fn test_position_fen_with_trailing_moves_token() {
+    let mut engine = Command::new(env!("CARGO_BIN_EXE_vantage"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to start engine");
+    let mut stdin = engine.stdin.take().unwrap();
+
+    // `moves` with nothing after it, on a FEN with and without clocks
+    writeln!(stdin, "position fen 4k3/8/8/8/8/8/8/4K2R w K - moves").unwrap();
+    writeln!(stdin, "fen").unwrap();
+    writeln!(stdin, "position fen {} moves", KIWI_FEN).unwrap();
+    writeln!(stdin, "fen").unwrap();
+    writeln!(stdin, "quit").unwrap();
+
+    let stdout = BufReader::new(engine.stdout.take().unwrap());
+    let lines: Vec<String> = stdout.lines().map_while(Result::ok).collect();
+    assert!(engine.wait().unwrap().success());
+
+    let fens: Vec<&String> = lines.iter().filter(|l| l.contains('/')).collect();
+    assert_eq!(
+        fens,
+        ["4k3/8/8/8/8/8/8/4K2R w K - 0 1", KIWI_FEN],
+        "output: {:?}",
+        lines
+    );
+}
@@ -92,6 +92,66 @@ fn zobrist_fen_recompute_stable() {
     }
 }
 
+#[test]
+fn zobrist_keys_are_nonzero() {
+    use vantage::hash::zobrist::verify_zobrist_distinct;
+
+    let keys = zobrist_keys();
+    assert!(verify_zobrist_distinct(keys));
+
+    assert_ne!(keys.side_to_move, 0);
+    assert!(keys.piece.iter().flatten().flatten().all(|&k| k != 0));
+    for (i, a) in keys.castling.iter().enumerate() {
+        assert_ne!(*a, 0);
+        assert!(keys.castling[i + 1..].iter().all(|b| b != a));
+    }
+    for (i, a) in keys.ep_file.iter().enumerate() {
+        assert_ne!(*a, 0);
+        assert!(keys.ep_file[i + 1..].iter().all(|b| b != a));
+    }
+}
+
+#[test]
+fn zobrist_no_collisions_across_1000_positions() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashMap;
+    use vantage::moves::execute::generate_legal;
+    use vantage::moves::magic::loader::load_magic_tables;
+
+    let tables = load_magic_tables();
+    let mut rng = StdRng::seed_from_u64(0x5EED);
+    let mut seen: HashMap<u64, String> = HashMap::new();
+    let mut moves = Vec::new();
+    let mut scratch = Vec::new();
+
+    // Random games from the start position until 1000 distinct positions are seen
+    while seen.len() < 1000 {
+        let mut board = Board::new();
+        for _ in 0..80 {
+            generate_legal(&mut board, &tables, &mut moves, &mut scratch);
+            if moves.is_empty() {
+                break;
+            }
+            let mv = moves[rng.random_range(0..moves.len())];
+            make_move_basic(&mut board, mv);
+
+            // Identify positions by placement, side and castling; skip EP
+            // positions since the hash deliberately ignores uncapturable EP squares
+            if board.en_passant.is_some() {
+                continue;
+            }
+            let fen = board.to_fen();
+            let key: String = fen.split(' ').take(3).collect::<Vec<_>>().join(" ");
+            if let Some(other) = seen.get(&board.zobrist) {
+                assert_eq!(other, &key, "Zobrist collision: {:016x}", board.zobrist);
+            } else {
+                seen.insert(board.zobrist, key);
+            }
+        }
+    }
+}
+
 #[test]
 fn zobrist_castling_rights_toggle_changes_hash() {
     let mut b = Board::new();